@@ -1,6 +1,7 @@
 use reign::{
     model::{diesel, Database},
     prelude::{views, Config},
+    router::ServeError,
     Reign,
 };
 use reign_plugin_redis::RedisPlugin;
@@ -21,7 +22,7 @@ mod error;
 use config::App;
 
 #[tokio::main]
-async fn main() {
+async fn main() -> Result<(), ServeError> {
     let addr = "127.0.0.1:8000";
 
     Reign::build()
@@ -1,8 +1,8 @@
 use reign::{
     prelude::*,
     router::{
-        hyper::Response as Res, middleware::HeadersDefault, path as p, serve, Request, Response,
-        Router,
+        bind, hyper::Response as Res, middleware::HeadersDefault, path as p, serve, Request,
+        Response, Router,
     },
 };
 use serde_json::{from_str, to_string, Value};
@@ -225,18 +225,17 @@ async fn main() {
 mod tests {
     use super::*;
     use reqwest::{Client, StatusCode};
-    use std::time::Duration;
-    use tokio::{select, time::sleep};
+    use tokio::select;
 
     #[tokio::test]
     async fn test_server() {
-        let client = async {
-            sleep(Duration::from_millis(100)).await;
+        let (addr, server) = bind("127.0.0.1:0", router).unwrap();
 
+        let client = async {
             let mut url;
             let client = Client::new();
 
-            url = "http://localhost:8080/str";
+            url = format!("http://{}/str", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -245,7 +244,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "str");
 
-            url = "http://localhost:8080/string";
+            url = format!("http://{}/string", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -254,7 +253,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "string");
 
-            url = "http://localhost:8080/response";
+            url = format!("http://{}/response", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -263,14 +262,14 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "response");
 
-            url = "http://localhost:8080/error";
+            url = format!("http://{}/error", addr);
 
             let res = client.get(url).send().await.unwrap();
 
             assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
             assert_eq!(res.text().await.unwrap(), "");
 
-            url = "http://localhost:8080/param/foobar";
+            url = format!("http://{}/param/foobar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -279,7 +278,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "param foobar");
 
-            url = "http://localhost:8080/param_opt/foobar";
+            url = format!("http://{}/param_opt/foobar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -288,7 +287,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "param_opt foobar");
 
-            url = "http://localhost:8080/param_opt";
+            url = format!("http://{}/param_opt", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -297,7 +296,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "param_opt ");
 
-            url = "http://localhost:8080/param_regex/123";
+            url = format!("http://{}/param_regex/123", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -306,14 +305,14 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "param_regex 123");
 
-            url = "http://localhost:8080/param_regex/foobar";
+            url = format!("http://{}/param_regex/foobar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
             assert_eq!(res.status(), StatusCode::NOT_FOUND);
             assert_eq!(res.text().await.unwrap(), "");
 
-            url = "http://localhost:8080/param_opt_regex/123";
+            url = format!("http://{}/param_opt_regex/123", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -322,7 +321,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "param_opt_regex 123");
 
-            url = "http://localhost:8080/param_opt_regex";
+            url = format!("http://{}/param_opt_regex", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -331,14 +330,14 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "param_opt_regex ");
 
-            url = "http://localhost:8080/param_opt_regex/foobar";
+            url = format!("http://{}/param_opt_regex/foobar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
             assert_eq!(res.status(), StatusCode::NOT_FOUND);
             assert_eq!(res.text().await.unwrap(), "");
 
-            url = "http://localhost:8080/scope_param/foobar/bar";
+            url = format!("http://{}/scope_param/foobar/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -347,7 +346,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "scope_param foobar");
 
-            url = "http://localhost:8080/scope_param_opt/foobar/bar";
+            url = format!("http://{}/scope_param_opt/foobar/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -356,7 +355,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "scope_param_opt foobar");
 
-            url = "http://localhost:8080/scope_param_opt/bar";
+            url = format!("http://{}/scope_param_opt/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -365,7 +364,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "scope_param_opt ");
 
-            url = "http://localhost:8080/scope_param_regex/123/bar";
+            url = format!("http://{}/scope_param_regex/123/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -374,14 +373,14 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "scope_param_regex 123");
 
-            url = "http://localhost:8080/scope_param_regex/foobar/bar";
+            url = format!("http://{}/scope_param_regex/foobar/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
             assert_eq!(res.status(), StatusCode::NOT_FOUND);
             assert_eq!(res.text().await.unwrap(), "");
 
-            url = "http://localhost:8080/scope_param_opt_regex/123/bar";
+            url = format!("http://{}/scope_param_opt_regex/123/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -390,7 +389,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "scope_param_opt_regex 123");
 
-            url = "http://localhost:8080/scope_param_opt_regex/bar";
+            url = format!("http://{}/scope_param_opt_regex/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -399,14 +398,14 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "scope_param_opt_regex ");
 
-            url = "http://localhost:8080/scope_param_opt_regex/foobar/bar";
+            url = format!("http://{}/scope_param_opt_regex/foobar/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
             assert_eq!(res.status(), StatusCode::NOT_FOUND);
             assert_eq!(res.text().await.unwrap(), "");
 
-            url = "http://localhost:8080/nested_scope/123/foo/456/bar";
+            url = format!("http://{}/nested_scope/123/foo/456/bar", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -415,7 +414,7 @@ mod tests {
             assert!(res.headers().contains_key("x-content-type-options"));
             assert_eq!(res.text().await.unwrap(), "nested_scope 123 456");
 
-            url = "http://localhost:8080/multi_params/123/foo/456";
+            url = format!("http://{}/multi_params/123/foo/456", addr);
 
             let res = client.get(url).send().await.unwrap();
 
@@ -426,7 +425,7 @@ mod tests {
         };
 
         select! {
-            _ =  server() => {}
+            _ = server => {}
             _ = client => {}
         }
     }
@@ -2,6 +2,14 @@ use reign::prelude::*;
 
 views!("src", "views");
 
+pub struct User;
+
+impl User {
+    pub async fn name(&self) -> String {
+        "John".to_string()
+    }
+}
+
 fn handler() -> String {
     let page = "Home".to_string();
     let content = "Lorem ipsum";
@@ -17,6 +25,7 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reign::view::AsyncRender;
 
     #[test]
     fn test_handler() {
@@ -26,4 +35,19 @@ mod tests {
             <body>\n    \n  \n  <p>Lorem ipsum</p>\n  <span>8</span>\n  <i></i>\n\n  </body>\n</html>",
         );
     }
+
+    #[tokio::test]
+    async fn test_async_view() {
+        let mut content = String::new();
+
+        views::Profile {
+            _slots: Default::default(),
+            user: User,
+        }
+        .render(&mut content)
+        .await
+        .unwrap();
+
+        assert_eq!(content, "<p>John</p>");
+    }
 }
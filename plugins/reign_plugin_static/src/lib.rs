@@ -2,8 +2,9 @@
 #![doc = include_str!("../README.md")]
 
 use reign_plugin::{
+    prepend,
     reign_router::{hyper::Method, Path, Router},
-    Plugin,
+    Plugin, RouterFn,
 };
 
 mod handlers;
@@ -58,11 +59,11 @@ impl StaticPlugin {
 }
 
 impl Plugin for StaticPlugin {
-    fn router(&self, f: Box<dyn FnOnce(&mut Router)>) -> Box<dyn FnOnce(&mut Router)> {
+    fn router(&self, f: RouterFn) -> RouterFn {
         let prefix = Path::new().path(&*self.prefix);
         let handle = handlers::to_dir(self.dir.clone(), self.cache);
 
-        Box::new(|r| {
+        prepend(f, |r| {
             r.scope(prefix).to(|r: &mut Router| {
                 r.any(
                     &[Method::GET, Method::HEAD],
@@ -70,8 +71,6 @@ impl Plugin for StaticPlugin {
                     handle,
                 );
             });
-
-            f(r);
         })
     }
 }
@@ -1,16 +1,25 @@
-use crate::env::load_env_files;
+use crate::{env::load_env_files, state::StateFn};
 
 use env_logger::{Builder, Env};
+use log::{error, info};
 use reign_plugin::{
-    reign_router::{serve, Router},
-    Plugin,
+    reign_router::{
+        futures::future::{pending, select},
+        serve, serve_many, serve_with_shutdown, service, Router, ServeError, Service,
+    },
+    Plugin, RouterFn,
 };
 
-use std::net::ToSocketAddrs;
+use tokio::signal::ctrl_c;
+#[cfg(unix)]
+use tokio::signal::unix::{signal, SignalKind};
+
+use std::{future::Future, net::ToSocketAddrs};
 
 #[derive(Default)]
 pub struct Reign {
     pub(crate) plugins: Vec<Box<dyn Plugin>>,
+    pub(crate) states: Vec<StateFn>,
 }
 
 impl Reign {
@@ -24,20 +33,157 @@ impl Reign {
         Self::default()
     }
 
-    pub async fn serve<A, R>(self, addr: A, f: R)
+    /// Serve the app until the server fails, which is when the error is returned.
+    pub async fn serve<A, R>(self, addr: A, f: R) -> Result<(), ServeError>
+    where
+        A: ToSocketAddrs + Send + 'static,
+        R: FnOnce(&mut Router) + 'static,
+    {
+        serve(addr, self.router(f).await).await
+    }
+
+    /// Serve the app until it receives `SIGINT` (Ctrl-C) or `SIGTERM`, after which the
+    /// in-flight requests are finished and the plugins are shut down.
+    pub async fn serve_graceful<A, R>(self, addr: A, f: R) -> Result<(), ServeError>
     where
         A: ToSocketAddrs + Send + 'static,
         R: FnOnce(&mut Router) + 'static,
     {
-        let mut router_fn: Box<dyn FnOnce(&mut Router)> = Box::new(f);
+        self.serve_with_shutdown(addr, f, shutdown_signal()).await
+    }
+
+    /// Serve the app until the given `signal` completes, after which the in-flight
+    /// requests are finished and the plugins are shut down.
+    ///
+    /// The plugins are also shut down if the server fails, and its error is returned.
+    pub async fn serve_with_shutdown<A, R, S>(
+        self,
+        addr: A,
+        f: R,
+        signal: S,
+    ) -> Result<(), ServeError>
+    where
+        A: ToSocketAddrs + Send + 'static,
+        R: FnOnce(&mut Router) + 'static,
+        S: Future<Output = ()>,
+    {
+        let result = serve_with_shutdown(addr, self.router(f).await, signal).await;
+
+        self.shutdown().await;
+        result
+    }
+
+    /// Build the app with all the plugins into a service that can be called without
+    /// binding to an address, which is useful for integration tests.
+    pub async fn into_service<R>(self, f: R) -> Service
+    where
+        R: FnOnce(&mut Router) + 'static,
+    {
+        service(self.router(f).await)
+    }
+
+    async fn router<R>(&self, f: R) -> RouterFn
+    where
+        R: FnOnce(&mut Router) + 'static,
+    {
+        let mut router_fn: RouterFn = Box::new(f);
 
-        for plugin in self.plugins {
+        // Plugins are applied in the order they were added, each one wrapping the previous
+        for plugin in &self.plugins {
             // Initialize the plugin
             plugin.init().await;
 
             router_fn = plugin.router(router_fn);
         }
 
-        serve(addr, router_fn).await.unwrap()
+        self.with_state(router_fn)
+    }
+
+    /// Serve the app on multiple addresses, each with their own router definition, until it
+    /// receives `SIGINT` (Ctrl-C) or `SIGTERM`.
+    ///
+    /// The plugins are initialized once and then applied to every router. They are shut down
+    /// once all the servers have stopped, even if one of them failed.
+    pub async fn serve_many<A, R>(self, listeners: Vec<(A, R)>) -> Result<(), ServeError>
+    where
+        A: ToSocketAddrs + Send + 'static,
+        R: FnOnce(&mut Router) + 'static,
+    {
+        self.serve_many_with_shutdown(listeners, shutdown_signal())
+            .await
+    }
+
+    /// Serve the app on multiple addresses, each with their own router definition, until the
+    /// given `signal` completes.
+    pub async fn serve_many_with_shutdown<A, R, S>(
+        self,
+        listeners: Vec<(A, R)>,
+        signal: S,
+    ) -> Result<(), ServeError>
+    where
+        A: ToSocketAddrs + Send + 'static,
+        R: FnOnce(&mut Router) + 'static,
+        S: Future<Output = ()>,
+    {
+        for plugin in &self.plugins {
+            // Initialize the plugin
+            plugin.init().await;
+        }
+
+        let listeners = listeners
+            .into_iter()
+            .map(|(addr, f)| {
+                let mut router_fn: RouterFn = Box::new(f);
+
+                for plugin in &self.plugins {
+                    router_fn = plugin.router(router_fn);
+                }
+
+                (addr, self.with_state(router_fn))
+            })
+            .collect::<Vec<_>>();
+
+        let result = serve_many(listeners, signal).await;
+
+        self.shutdown().await;
+        result
     }
+
+    async fn shutdown(&self) {
+        info!("Shutting down plugins");
+
+        for plugin in self.plugins.iter().rev() {
+            plugin.shutdown().await;
+        }
+    }
+}
+
+// Completes when the process receives `SIGINT` or, on unix, `SIGTERM`
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(err) = ctrl_c().await {
+            error!("Unable to listen for Ctrl-C: {}", err);
+            pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match signal(SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            }
+            Err(err) => {
+                error!("Unable to listen for SIGTERM: {}", err);
+                pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = pending::<()>();
+
+    select(Box::pin(ctrl_c), Box::pin(terminate)).await;
+
+    info!("Received shutdown signal");
 }
@@ -8,6 +8,7 @@ mod boot;
 mod config;
 mod env;
 mod plugin;
+mod state;
 
 pub use boot::Reign;
 pub use config::Config;
@@ -0,0 +1,36 @@
+use crate::boot::Reign;
+
+use reign_plugin::{reign_router::Router, RouterFn};
+
+use std::sync::Arc;
+
+pub(crate) type StateFn = Arc<dyn Fn(&mut Router) + Send + Sync>;
+
+impl Reign {
+    /// Register a value as application state which can be retrieved by the handlers using
+    /// `Request::state`.
+    pub fn state<T>(mut self, value: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        let value = Arc::new(value);
+
+        self.states.push(Arc::new(move |r: &mut Router| {
+            r.state_arc(value.clone());
+        }));
+        self
+    }
+
+    // State needs to be registered on the top level router, so this wraps the plugins
+    pub(crate) fn with_state(&self, f: RouterFn) -> RouterFn {
+        let states = self.states.clone();
+
+        Box::new(move |r| {
+            for state in &states {
+                state(r);
+            }
+
+            f(r);
+        })
+    }
+}
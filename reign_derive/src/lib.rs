@@ -68,6 +68,14 @@ pub fn views(input: TokenStream) -> TokenStream {
 ///
 /// render!(pages::home, status = 201)
 /// ```
+///
+/// Or override the content-type of the response
+///
+/// ```ignore
+/// use reign::{prelude::*, router::hyper::header::HeaderValue};
+///
+/// render!(pages::home, content_type = HeaderValue::from_static("text/html"))
+/// ```
 #[cfg(feature = "view")]
 #[proc_macro]
 #[proc_macro_error]
@@ -89,13 +97,74 @@ pub fn render(input: TokenStream) -> TokenStream {
 ///     Ok(id)
 /// }
 /// ```
+///
+/// The full path of the route, including the paths of any scopes it is in, can be given using
+/// the same syntax as `p!`. The params of the handle are then checked against the dynamic
+/// segments of the path at compile time.
+///
+/// ```
+/// use reign::prelude::*;
+///
+/// #[params("users" / id / "posts" / tags*?)]
+/// async fn posts(
+///     req: &mut Request,
+///     id: String,
+///     tags: Option<Vec<String>>,
+/// ) -> Result<impl Response, Error> {
+///     Ok(id)
+/// }
+/// ```
+#[cfg(feature = "router")]
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn params(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let path = if attr.is_empty() {
+        None
+    } else {
+        Some(parse_macro_input!(attr as router::params::Path))
+    };
+    let input: syn::ItemFn = parse_macro_input!(input);
+
+    router::params::params(path, input).into()
+}
+
+/// Helper for extracting typed arguments in a [reign_router] handle.
+///
+/// Every argument except the request is extracted in order using `FromRequest`. The request
+/// itself can be received by declaring an argument of type `&mut Request`.
+///
+/// The error type of the returned `Result` can be omitted, in which case it defaults to
+/// `reign::router::Error`. Any error that can be converted into it can then be returned
+/// using `?` and responds with the status documented on that error.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::hyper::{body::Bytes, Method}};
+/// use std::str::from_utf8;
+///
+/// #[action]
+/// async fn name(method: Method, body: String) -> Result<impl Response, Error> {
+///     Ok(format!("{} {}", method, body))
+/// }
+///
+/// #[action]
+/// async fn with_request(req: &mut Request, body: String) -> Result<impl Response, Error> {
+///     Ok(format!("{} {}", req.uri(), body))
+/// }
+///
+/// #[action]
+/// async fn upper(body: Bytes) -> Result<impl Response> {
+///     Ok(from_utf8(&body)?.to_uppercase())
+/// }
+/// ```
 #[cfg(feature = "router")]
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn params(_: TokenStream, input: TokenStream) -> TokenStream {
+pub fn action(_: TokenStream, input: TokenStream) -> TokenStream {
     let input: syn::ItemFn = parse_macro_input!(input);
 
-    router::params::params(input).into()
+    router::action::action(input).into()
 }
 
 #[cfg(feature = "framework")]
@@ -107,6 +176,70 @@ pub fn config(input: TokenStream) -> TokenStream {
     framework::config::config(input).into()
 }
 
+/// Generate the query builders for a database table.
+///
+/// Fields can be tagged to generate smaller structs named after the model and the tag.
+/// They only select the tagged columns, which is useful when a couple of columns are
+/// needed from a wide table. These structs support the same filters and pagination
+/// as the model.
+///
+/// # Examples
+///
+/// ```ignore
+/// use reign::prelude::*;
+///
+/// #[derive(Model)]
+/// pub struct User {
+///     #[model(no_write)]
+///     id: i32,
+///     #[model(required, tag(summary))]
+///     name: String,
+///     #[model(tag(summary))]
+///     email: Option<String>,
+///     bio: String,
+/// }
+///
+/// // SELECT name, email FROM users WHERE name = 'John' LIMIT 10 OFFSET 20
+/// let summaries: Vec<UserSummary> = UserSummary::filter()
+///     .name("John")
+///     .all_from(Some(10), Some(20))
+///     .await?;
+///
+/// // Second page of 10 users ordered by the primary key, along with the total count
+/// let page = User::paginate(2, 10).await?;
+///
+/// // Raw SQL for queries which can't be built, with the parameters bound by the database
+/// let users = User::find_by_sql(
+///     RawQuery::new("SELECT * FROM users WHERE name ILIKE $1").bind::<Text, _>("j%"),
+/// )
+/// .await?;
+/// ```
+///
+/// The following attributes are supported on fields:
+///
+/// * `no_write`: Column can't be inserted or updated.
+/// * `required`: Column needs to be given before inserting.
+/// * `tag(...)`: Column is included in the structs of the given tags.
+/// * `column_name = ...`: Name of the column if it differs from the field.
+/// * `belongs_to(Model, primary_key = ...)`: Column references the given model. Generates a
+///   method named after the snake case of the model which loads the referenced record. The
+///   primary key column of the referenced model defaults to `id`.
+/// * `validate(...)`: Validations for the column which are checked by the generated `validate`
+///   method. Supports `presence`, `length(min = ..., max = ...)` and `format = "regex"`.
+///
+/// The following attributes are supported on the struct:
+///
+/// * `table_name = ...`: Name of the table, defaults to the pluralized snake case of the struct.
+/// * `primary_key(...)`: Columns of the primary key, defaults to `id`.
+/// * `has_many(Model, foreign_key = ...)`: Given model references this one. Generates a method
+///   named after the pluralized snake case of the model which loads the referencing records.
+///   The foreign key column defaults to the snake case of the struct followed by `_id`.
+/// * `timestamps`: Sets the `created_at` and `updated_at` columns to the current time when
+///   inserting, and `updated_at` when updating, unless they are given. Use `no_write` on a
+///   column to opt it out.
+/// * `soft_delete = ...`: Nullable timestamp column, defaults to `deleted_at`, which marks a
+///   record as deleted. Generates a `soft_delete` method which sets it, and queries exclude
+///   these records unless `with_deleted` is used.
 #[cfg(feature = "model-postgres")]
 #[proc_macro_derive(Model, attributes(model))]
 #[proc_macro_error]
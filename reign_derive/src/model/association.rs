@@ -0,0 +1,105 @@
+use crate::{
+    model::{attr::Attr, model::Model},
+    INTERNAL_ERR,
+};
+
+use inflector::{cases::snakecase::to_snake_case, string::pluralize::to_plural};
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Ident, Path};
+
+impl Model {
+    pub fn gen_associations(&self) -> TokenStream {
+        let gen_belongs_to = self.gen_belongs_to();
+        let gen_has_many = self.gen_has_many();
+
+        quote! {
+            #(#gen_belongs_to)*
+            #(#gen_has_many)*
+        }
+    }
+
+    // Generates a method loading the referenced record for every `belongs_to` field
+    fn gen_belongs_to(&self) -> Vec<TokenStream> {
+        let ident = &self.ident;
+        let vis = &self.vis;
+
+        self.fields
+            .iter()
+            .flat_map(|f| {
+                f.attrs.iter().filter_map(move |attr| match attr {
+                    Attr::BelongsTo(_, model, key) => Some((f, model, key)),
+                    _ => None,
+                })
+            })
+            .map(|(f, model, key)| {
+                let method = association_ident(model, false);
+                let field_ident = f.field.ident.as_ref().expect(INTERNAL_ERR);
+                let primary_key = key
+                    .clone()
+                    .unwrap_or_else(|| Ident::new("id", Span::call_site()));
+
+                quote! {
+                    #[allow(dead_code, unreachable_code)]
+                    impl #ident {
+                        #vis async fn #method(&self) -> Result<Option<#model>, ::reign::model::Error> {
+                            #model::filter().#primary_key(self.#field_ident.clone()).one().await
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    // Generates a method loading the referencing records for every `has_many` on the struct
+    fn gen_has_many(&self) -> Vec<TokenStream> {
+        let ident = &self.ident;
+        let vis = &self.vis;
+
+        self.attrs
+            .iter()
+            .filter_map(|attr| match attr {
+                Attr::HasMany(name, model, key) => Some((name, model, key)),
+                _ => None,
+            })
+            .map(|(name, model, key)| {
+                let keys = self
+                    .fields
+                    .iter()
+                    .filter(|x| x.primary_key)
+                    .collect::<Vec<_>>();
+
+                if keys.len() != 1 {
+                    abort!(name, "`has_many` needs a primary key with a single column");
+                }
+
+                let method = association_ident(model, true);
+                let field_ident = keys[0].field.ident.as_ref().expect(INTERNAL_ERR);
+                let foreign_key = key.clone().unwrap_or_else(|| {
+                    Ident::new(
+                        &format!("{}_id", to_snake_case(&ident.to_string())),
+                        Span::call_site(),
+                    )
+                });
+
+                quote! {
+                    #[allow(dead_code, unreachable_code)]
+                    impl #ident {
+                        #vis async fn #method(&self) -> Result<Vec<#model>, ::reign::model::Error> {
+                            #model::filter().#foreign_key(self.#field_ident.clone()).all().await
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+// Method name for the association, which is the snake case of the associated model
+fn association_ident(model: &Path, plural: bool) -> Ident {
+    let last = &model.segments.last().expect(INTERNAL_ERR).ident;
+    let name = to_snake_case(&last.to_string());
+
+    Ident::new(&if plural { to_plural(&name) } else { name }, last.span())
+}
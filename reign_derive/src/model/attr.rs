@@ -4,16 +4,29 @@ use syn::{
     parse::{Parse, ParseStream, Result},
     punctuated::Punctuated,
     token::{Comma, Eq},
-    Attribute, Ident,
+    Attribute, Ident, LitInt, LitStr, Path,
 };
 
 #[derive(Clone)]
 pub enum Attr {
     NoWrite(Ident),
+    Required(Ident),
     Tag(Ident, Punctuated<Ident, Comma>),
     ColumnName(Ident, Ident),
     TableName(Ident, Ident),
     PrimaryKey(Ident, Punctuated<Ident, Comma>),
+    BelongsTo(Ident, Path, Option<Ident>),
+    HasMany(Ident, Path, Option<Ident>),
+    Timestamps(Ident),
+    SoftDelete(Ident, Option<Ident>),
+    Validate(Ident, Punctuated<Validation, Comma>),
+}
+
+#[derive(Clone)]
+pub enum Validation {
+    Presence(Ident),
+    Length(Ident, Option<LitInt>, Option<LitInt>),
+    Format(Ident, LitStr),
 }
 
 impl Parse for Attr {
@@ -22,10 +35,29 @@ impl Parse for Attr {
 
         match name.to_string().as_str() {
             "no_write" => Ok(Attr::NoWrite(name)),
+            "required" => Ok(Attr::Required(name)),
             "tag" => Ok(Attr::Tag(name, parenthesized_list(input)?)),
             "column_name" => Ok(Attr::ColumnName(name, eq(input)?)),
             "table_name" => Ok(Attr::TableName(name, eq(input)?)),
             "primary_key" => Ok(Attr::PrimaryKey(name, parenthesized_list(input)?)),
+            "timestamps" => Ok(Attr::Timestamps(name)),
+            "validate" => Ok(Attr::Validate(name, parenthesized_list(input)?)),
+            "soft_delete" => {
+                let column = if input.peek(Eq) {
+                    Some(eq(input)?)
+                } else {
+                    None
+                };
+                Ok(Attr::SoftDelete(name, column))
+            }
+            "belongs_to" => {
+                let (model, key) = association(input, "primary_key")?;
+                Ok(Attr::BelongsTo(name, model, key))
+            }
+            "has_many" => {
+                let (model, key) = association(input, "foreign_key")?;
+                Ok(Attr::HasMany(name, model, key))
+            }
             _ => abort!(name, "unexpected attribute: {}", name),
         }
     }
@@ -47,6 +79,9 @@ impl Attr {
                 Attr::NoWrite(ident) if for_struct => {
                     abort!(ident, "`no_write` is not allowed on struct")
                 }
+                Attr::Required(ident) if for_struct => {
+                    abort!(ident, "`required` is not allowed on struct")
+                }
                 Attr::Tag(ident, _) if for_struct => {
                     abort!(ident, "`tag` is not allowed on struct")
                 }
@@ -59,6 +94,21 @@ impl Attr {
                 Attr::PrimaryKey(ident, _) if !for_struct => {
                     abort!(ident, "`primary_key` is not allowed on field")
                 }
+                Attr::BelongsTo(ident, ..) if for_struct => {
+                    abort!(ident, "`belongs_to` is not allowed on struct")
+                }
+                Attr::HasMany(ident, ..) if !for_struct => {
+                    abort!(ident, "`has_many` is not allowed on field")
+                }
+                Attr::Timestamps(ident) if !for_struct => {
+                    abort!(ident, "`timestamps` is not allowed on field")
+                }
+                Attr::Validate(ident, _) if for_struct => {
+                    abort!(ident, "`validate` is not allowed on struct")
+                }
+                Attr::SoftDelete(ident, _) if !for_struct => {
+                    abort!(ident, "`soft_delete` is not allowed on field")
+                }
                 _ => {}
             }
         }
@@ -67,6 +117,45 @@ impl Attr {
     }
 }
 
+impl Parse for Validation {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+
+        match name.to_string().as_str() {
+            "presence" => Ok(Validation::Presence(name)),
+            "format" => Ok(Validation::Format(name, eq(input)?)),
+            "length" => {
+                let mut min = None;
+                let mut max = None;
+
+                for LengthOption(key, value) in parenthesized_list(input)? {
+                    match key.to_string().as_str() {
+                        "min" => min = Some(value),
+                        "max" => max = Some(value),
+                        _ => abort!(key, "unexpected option: {}", key),
+                    }
+                }
+
+                if min.is_none() && max.is_none() {
+                    abort!(name, "`length` needs `min` or `max`");
+                }
+
+                Ok(Validation::Length(name, min, max))
+            }
+            _ => abort!(name, "unexpected validation: {}", name),
+        }
+    }
+}
+
+// Parses `min = 2` or `max = 50`
+struct LengthOption(Ident, LitInt);
+
+impl Parse for LengthOption {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(LengthOption(input.parse()?, eq(input)?))
+    }
+}
+
 fn eq<T: Parse>(input: ParseStream) -> Result<T> {
     input.parse::<Eq>()?;
     input.parse()
@@ -78,3 +167,25 @@ fn parenthesized_list<T: Parse>(input: ParseStream) -> Result<Punctuated<T, Comm
 
     content.parse_terminated(T::parse)
 }
+
+// Parses `(Model)` or `(Model, key = column)`
+fn association(input: ParseStream, key: &str) -> Result<(Path, Option<Ident>)> {
+    let content;
+    parenthesized!(content in input);
+
+    let model = content.parse()?;
+
+    if content.is_empty() {
+        return Ok((model, None));
+    }
+
+    content.parse::<Comma>()?;
+
+    let name: Ident = content.parse()?;
+
+    if name != key {
+        abort!(name, "unexpected option: {}", name);
+    }
+
+    Ok((model, Some(eq(&content)?)))
+}
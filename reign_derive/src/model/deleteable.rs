@@ -11,10 +11,12 @@ impl Model {
     pub fn gen_deleteable(&self) -> TokenStream {
         let gen_deleteable_methods = self.gen_deleteable_methods(&self.ident, &self.fields);
         let gen_deleteable_actions = self.gen_deleteable_actions(&self.ident, &self.fields);
+        let gen_soft_deleteable = self.gen_soft_deleteable();
 
         quote! {
             #gen_deleteable_methods
             #gen_deleteable_actions
+            #gen_soft_deleteable
         }
     }
 
@@ -61,7 +63,7 @@ impl Model {
         let filterable_ident = self.filterable_ident();
         let table_ident = &self.table_ident;
         let schema = self.schema();
-        let db = self.db();
+        let log_query = self.log_query(quote! { query });
         let vis = &self.vis;
 
         let column_ident = fields.iter().map(|x| &x.column_ident).collect::<Vec<_>>();
@@ -70,31 +72,114 @@ impl Model {
             #[allow(dead_code, unreachable_code)]
             impl #filterable_ident<#ident> {
                 #vis async fn drop(self) -> Result<Vec<#ident>, ::reign::model::Error> {
-                    use ::reign::model::tokio_diesel::AsyncRunQueryDsl;
-                    use ::reign::model::diesel::QueryDsl;
+                    use ::reign::model::diesel::{QueryDsl, RunQueryDsl};
 
-                    Ok(::reign::model::diesel::delete(
+                    let query = ::reign::model::diesel::delete(
                             #schema::#table_ident::table.filter(self.statement),
                         )
                         .returning((
                             #(#schema::#table_ident::#column_ident,)*
-                        ))
-                        .get_results_async::<#ident>(#db)
-                        .await?)
+                        ));
+
+                    #log_query
+
+                    ::reign::model::Database::run(move |conn| query.get_results::<#ident>(conn)).await
                 }
 
                 async fn drop_one(self) -> Result<#ident, ::reign::model::Error> {
-                    use ::reign::model::tokio_diesel::AsyncRunQueryDsl;
-                    use ::reign::model::diesel::QueryDsl;
+                    use ::reign::model::diesel::{QueryDsl, RunQueryDsl};
 
-                    Ok(::reign::model::diesel::delete(
+                    let query = ::reign::model::diesel::delete(
                             #schema::#table_ident::table.filter(self.statement),
                         )
                         .returning((
                             #(#schema::#table_ident::#column_ident,)*
-                        ))
-                        .get_result_async::<#ident>(#db)
-                        .await?)
+                        ));
+
+                    #log_query
+
+                    ::reign::model::Database::run(move |conn| query.get_result::<#ident>(conn)).await
+                }
+            }
+        }
+    }
+
+    // Generates methods which set the soft delete column instead of using `DELETE`
+    fn gen_soft_deleteable(&self) -> TokenStream {
+        let f = match &self.soft_delete {
+            Some(f) => f,
+            None => return quote! {},
+        };
+
+        let ident = &self.ident;
+        let filterable_ident = self.filterable_ident();
+        let table_ident = &self.table_ident;
+        let schema = self.schema();
+        let log_query = self.log_query(quote! { query });
+        let vis = &self.vis;
+
+        let soft_delete_column = &f.column_ident;
+        let soft_delete_ty = &f.field.ty;
+
+        let column_ident = self
+            .fields
+            .iter()
+            .map(|x| &x.column_ident)
+            .collect::<Vec<_>>();
+
+        let keys = self
+            .fields
+            .iter()
+            .filter(|x| x.primary_key)
+            .collect::<Vec<_>>();
+
+        let (key_column_ident, key_field_ident) = keys
+            .iter()
+            .map(|f| (&f.column_ident, f.field.ident.as_ref().expect(INTERNAL_ERR)))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        let gen_query = |method: TokenStream| {
+            quote! {
+                use ::reign::model::diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+
+                let query = ::reign::model::diesel::update(
+                        #schema::#table_ident::table.filter(self.scoped()),
+                    )
+                    .set(#schema::#table_ident::#soft_delete_column.eq(
+                        <#soft_delete_ty as ::reign::model::Timestamp>::now(),
+                    ))
+                    .returning((
+                        #(#schema::#table_ident::#column_ident,)*
+                    ));
+
+                #log_query
+
+                ::reign::model::Database::run(move |conn| query.#method::<#ident>(conn)).await
+            }
+        };
+
+        let gen_all = gen_query(quote! { get_results });
+        let gen_one = gen_query(quote! { get_result });
+
+        quote! {
+            #[allow(dead_code, unreachable_code)]
+            impl #ident {
+                #vis async fn soft_delete(&self) -> Result<#ident, ::reign::model::Error> {
+                    #ident::filter()
+                        #(.#key_column_ident(self.#key_field_ident.clone()))*
+                        .soft_delete_one()
+                        .await
+                }
+            }
+
+            #[allow(dead_code, unreachable_code)]
+            impl #filterable_ident<#ident> {
+                #vis async fn soft_delete(self) -> Result<Vec<#ident>, ::reign::model::Error> {
+                    #gen_all
+                }
+
+                async fn soft_delete_one(self) -> Result<#ident, ::reign::model::Error> {
+                    #gen_one
                 }
             }
         }
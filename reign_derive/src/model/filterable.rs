@@ -8,11 +8,13 @@ impl Model {
     pub fn gen_filterable(&self) -> TokenStream {
         let gen_filterable_struct = self.gen_filterable_struct();
         let gen_filterable_filters = self.gen_filterable_filters();
+        let gen_filterable_scope = self.gen_filterable_scope();
         let gen_filterable_methods = self.gen_filterable_methods(&self.ident);
 
         quote! {
             #gen_filterable_struct
             #gen_filterable_filters
+            #gen_filterable_scope
             #gen_filterable_methods
         }
     }
@@ -46,6 +48,7 @@ impl Model {
                         SqlType = ::reign::model::diesel::sql_types::Bool,
                     >,
                 >,
+                with_deleted: bool,
             }
 
             impl<M> #filterable_ident<M> {
@@ -60,12 +63,64 @@ impl Model {
                     Self {
                         _phantom: std::marker::PhantomData,
                         statement: Box::new(none.into_sql::<Nullable<Bool>>().is_not_distinct_from(none)),
+                        with_deleted: false,
                     }
                 }
             }
         }
     }
 
+    // Generates the default scope which excludes soft deleted rows
+    fn gen_filterable_scope(&self) -> TokenStream {
+        let filterable_ident = self.filterable_ident();
+        let table_ident = &self.table_ident;
+        let schema = self.schema();
+        let backend = self.backend();
+        let vis = &self.vis;
+
+        let (gen_with_deleted, gen_scope) = if let Some(f) = &self.soft_delete {
+            let column_ident = &f.column_ident;
+
+            (
+                quote! {
+                    #vis fn with_deleted(mut self) -> Self {
+                        self.with_deleted = true;
+                        self
+                    }
+                },
+                quote! {
+                    if !self.with_deleted {
+                        use ::reign::model::diesel::{BoolExpressionMethods, ExpressionMethods};
+
+                        return Box::new(self.statement.and(#schema::#table_ident::#column_ident.is_null()));
+                    }
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        quote! {
+            #[allow(dead_code, unreachable_code)]
+            impl<M> #filterable_ident<M> {
+                #gen_with_deleted
+
+                fn scoped(
+                    self,
+                ) -> Box<
+                    dyn ::reign::model::diesel::expression::BoxableExpression<
+                        #schema::#table_ident::table,
+                        #backend,
+                        SqlType = ::reign::model::diesel::sql_types::Bool,
+                    >,
+                > {
+                    #gen_scope
+                    self.statement
+                }
+            }
+        }
+    }
+
     // TODO: model: More filters when both `Bool` and `Nullable<Bool>` can be stored at once
     // Generates individual column filters
     fn gen_filterable_filters(&self) -> TokenStream {
@@ -39,9 +39,20 @@ impl Model {
         format_ident!("Insertable{}", self.ident)
     }
 
+    // Type parameters tracking whether each of the required columns has been given
+    fn insertable_generics(&self) -> Vec<Ident> {
+        self.fields
+            .iter()
+            .filter(|x| !x.no_write && x.required)
+            .enumerate()
+            .map(|(i, _)| format_ident!("R{}", i))
+            .collect()
+    }
+
     // Generates struct & constructor for `INSERT` statements
     fn gen_insertable_struct(&self) -> TokenStream {
         let insertable_ident = self.insertable_ident();
+        let generics = self.insertable_generics();
         let vis = &self.vis;
 
         let (for_struct, for_new) = self
@@ -64,8 +75,8 @@ impl Model {
             .unzip::<_, _, Vec<_>, Vec<_>>();
 
         quote! {
-            #vis struct #insertable_ident<M> {
-                _phantom: std::marker::PhantomData<(M)>,
+            #vis struct #insertable_ident<M #(, #generics = ::reign::model::Unset)*> {
+                _phantom: std::marker::PhantomData<(M #(, #generics)*)>,
                 #(#for_struct,)*
             }
 
@@ -82,6 +93,7 @@ impl Model {
 
     fn gen_insertable_trait(&self) -> TokenStream {
         let insertable_ident = self.insertable_ident();
+        let generics = self.insertable_generics();
         let table_ident = &self.table_ident;
         let schema = self.schema();
 
@@ -90,23 +102,23 @@ impl Model {
             .iter()
             .filter(|x| !x.no_write)
             .map(|f| {
-                let Field { ident, ty, .. } = &f.field;
-                let ident = ident.as_ref().expect(INTERNAL_ERR);
+                let ty = &f.field.ty;
                 let column_ident = &f.column_ident;
+                let value = self.write_value(f, &["created_at", "updated_at"]);
 
                 (
                     quote! {
                         Option<::reign::model::diesel::dsl::Eq<#schema::#table_ident::#column_ident, #ty>>
                     },
                     quote! {
-                        self.#ident.map(|x| #schema::#table_ident::#column_ident.eq(x))
+                        #value.map(|x| #schema::#table_ident::#column_ident.eq(x))
                     },
                 )
             })
             .unzip::<_, _, Vec<_>, Vec<_>>();
 
         quote! {
-            impl<M> ::reign::model::diesel::Insertable<#schema::#table_ident::table> for #insertable_ident<M>
+            impl<M #(, #generics)*> ::reign::model::diesel::Insertable<#schema::#table_ident::table> for #insertable_ident<M #(, #generics)*>
             {
                 type Values = <(#(#val_ty,)*) as ::reign::model::diesel::Insertable<#schema::#table_ident::table>>::Values;
 
@@ -123,6 +135,16 @@ impl Model {
     // TODO: model: Allow `AsExpression<SqlTypeOf>` so that we can take any value
     fn gen_insertable_setters(&self) -> TokenStream {
         let insertable_ident = self.insertable_ident();
+        let generics = self.insertable_generics();
+
+        let writable = self
+            .fields
+            .iter()
+            .filter(|x| !x.no_write)
+            .map(|x| x.field.ident.as_ref().expect(INTERNAL_ERR))
+            .collect::<Vec<_>>();
+
+        let mut required_index = 0;
 
         let setters = self
             .fields
@@ -132,17 +154,46 @@ impl Model {
                 let Field { vis, ident, ty, .. } = &f.field;
                 let ident = ident.as_ref().expect(INTERNAL_ERR);
 
+                if !f.required {
+                    return quote! {
+                        #vis fn #ident(mut self, #ident: #ty) -> Self {
+                            self.#ident = Some(#ident);
+                            self
+                        }
+                    };
+                }
+
+                // Setting a required column moves the builder into a type that marks it as given
+                let output_generics = generics
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| {
+                        if i == required_index {
+                            quote! { ::reign::model::Set }
+                        } else {
+                            quote! { #x }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                let others = writable.iter().filter(|x| **x != ident).collect::<Vec<_>>();
+
+                required_index += 1;
+
                 quote! {
-                    #vis fn #ident(mut self, #ident: #ty) -> Self {
-                        self.#ident = Some(#ident);
-                        self
+                    #vis fn #ident(self, #ident: #ty) -> #insertable_ident<M #(, #output_generics)*> {
+                        #insertable_ident {
+                            _phantom: std::marker::PhantomData,
+                            #ident: Some(#ident),
+                            #(#others: self.#others,)*
+                        }
                     }
                 }
             })
             .collect::<Vec<_>>();
 
         quote! {
-            impl<M> #insertable_ident<M> {
+            impl<M #(, #generics)*> #insertable_ident<M #(, #generics)*> {
                 #(#setters)*
             }
         }
@@ -168,24 +219,34 @@ impl Model {
         let insertable_ident = self.insertable_ident();
         let table_ident = &self.table_ident;
         let schema = self.schema();
-        let db = self.db();
+        let log_query = self.log_query(quote! { query });
         let vis = &self.vis;
 
         let column_ident = fields.iter().map(|x| &x.column_ident).collect::<Vec<_>>();
+        let set = self
+            .insertable_generics()
+            .iter()
+            .map(|_| quote! { ::reign::model::Set })
+            .collect::<Vec<_>>();
 
         quote! {
-            impl #insertable_ident<#ident> {
+            impl #insertable_ident<#ident #(, #set)*> {
                 #vis async fn save(self) -> Result<#ident, ::reign::model::Error> {
-                    use ::reign::model::tokio_diesel::AsyncRunQueryDsl;
-                    use ::reign::model::diesel::ExpressionMethods;
+                    self.insert().await
+                }
+
+                #vis async fn insert(self) -> Result<#ident, ::reign::model::Error> {
+                    use ::reign::model::diesel::{ExpressionMethods, RunQueryDsl};
 
-                    Ok(::reign::model::diesel::insert_into(#schema::#table_ident::table)
+                    let query = ::reign::model::diesel::insert_into(#schema::#table_ident::table)
                         .values(self)
                         .returning((
                             #(#schema::#table_ident::#column_ident,)*
-                        ))
-                        .get_result_async::<#ident>(#db)
-                        .await?)
+                        ));
+
+                    #log_query
+
+                    ::reign::model::Database::run(move |conn| query.get_result::<#ident>(conn)).await
                 }
             }
         }
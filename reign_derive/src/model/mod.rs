@@ -1,6 +1,7 @@
 #[allow(clippy::module_inception)]
 pub mod model;
 
+mod association;
 mod attr;
 mod deleteable;
 mod filterable;
@@ -9,3 +10,4 @@ mod insertable;
 mod selectable;
 mod tag;
 mod updateable;
+mod validate;
@@ -36,6 +36,8 @@ fn gen_for_struct(model: Model) -> TokenStream {
     let gen_updateable = model.gen_updateable();
     let gen_deleteable = model.gen_deleteable();
     let gen_tags = model.gen_tags();
+    let gen_associations = model.gen_associations();
+    let gen_validate = model.gen_validate();
 
     quote! {
         #gen_id
@@ -45,6 +47,8 @@ fn gen_for_struct(model: Model) -> TokenStream {
         #gen_updateable
         #gen_deleteable
         #(#gen_tags)*
+        #gen_associations
+        #gen_validate
     }
 }
 
@@ -54,6 +58,7 @@ pub struct ModelField {
     pub attrs: Vec<Attr>,
     pub column_ident: Ident,
     pub no_write: bool,
+    pub required: bool,
     pub primary_key: bool,
     pub tags: Vec<Ident>,
 }
@@ -64,17 +69,25 @@ impl ModelField {
 
         let mut column_ident = field.ident.as_ref().expect(INTERNAL_ERR).clone();
         let mut no_write = false;
+        let mut required = None;
         let mut tags = vec![];
 
         for attr in &attrs {
             match attr {
                 Attr::ColumnName(_, value) => column_ident = value.clone(),
                 Attr::NoWrite(_) => no_write = true,
+                Attr::Required(ident) => required = Some(ident),
                 Attr::Tag(_, value) => value.iter().for_each(|i| tags.push(i.clone())),
                 _ => {}
             }
         }
 
+        if let Some(ident) = required {
+            if no_write {
+                abort!(ident, "`required` is not allowed along with `no_write`");
+            }
+        }
+
         let primary_key = (primary_keys.is_empty() && column_ident == "id")
             || primary_keys.iter().find(|x| **x == column_ident).is_some();
 
@@ -83,6 +96,7 @@ impl ModelField {
             attrs,
             column_ident,
             no_write,
+            required: required.is_some(),
             primary_key,
             tags,
         }
@@ -97,6 +111,8 @@ pub struct Model {
     pub fields: Vec<ModelField>,
     pub table_ident: Ident,
     pub primary_keys_size: usize,
+    pub timestamps: bool,
+    pub soft_delete: Option<ModelField>,
 }
 
 impl Model {
@@ -111,11 +127,21 @@ impl Model {
         let mut table_ident =
             Ident::new(&to_plural(&to_snake_case(&ident.to_string())), ident.span());
         let mut primary_keys = vec![];
+        let mut timestamps = None;
+        let mut soft_delete = None;
 
         for attr in &attrs {
             match attr {
                 Attr::TableName(_, value) => table_ident = value.clone(),
                 Attr::PrimaryKey(_, value) => primary_keys = value.iter().cloned().collect(),
+                Attr::Timestamps(ident) => timestamps = Some(ident),
+                Attr::SoftDelete(ident, column) => {
+                    soft_delete = Some(
+                        column
+                            .clone()
+                            .unwrap_or_else(|| Ident::new("deleted_at", ident.span())),
+                    )
+                }
                 _ => {}
             }
         }
@@ -139,13 +165,38 @@ impl Model {
             }
         }
 
+        if let Some(ident) = timestamps {
+            if !fields
+                .iter()
+                .any(|x| x.column_ident == "created_at" || x.column_ident == "updated_at")
+            {
+                abort!(
+                    ident,
+                    "unable to find columns `created_at` or `updated_at` used for timestamps"
+                );
+            }
+        }
+
+        let soft_delete =
+            soft_delete.map(
+                |column| match fields.iter().find(|x| x.column_ident == column) {
+                    Some(field) => field.clone(),
+                    None => abort!(
+                        column,
+                        format!("unable to find column `{}` used for soft delete", column)
+                    ),
+                },
+            );
+
         Self {
             vis: vis.clone(),
             ident: ident.clone(),
-            attrs,
             fields,
             table_ident,
             primary_keys_size: primary_keys.len(),
+            timestamps: timestamps.is_some(),
+            soft_delete,
+            attrs,
         }
     }
 
@@ -155,15 +206,34 @@ impl Model {
         }
     }
 
-    pub fn db(&self) -> TokenStream {
+    pub fn backend(&self) -> TokenStream {
         quote! {
-            ::reign::model::Database::get()
+            ::reign::model::diesel::pg::Pg
         }
     }
 
-    pub fn backend(&self) -> TokenStream {
+    // Value of the field to write, which defaults to the current time for the given
+    // timestamp columns when the model has `timestamps`
+    pub fn write_value(&self, f: &ModelField, timestamps: &[&str]) -> TokenStream {
+        let Field { ident, ty, .. } = &f.field;
+
+        if self.timestamps && timestamps.iter().any(|x| f.column_ident == x) {
+            quote! {
+                self.#ident.or_else(|| Some(<#ty as ::reign::model::Timestamp>::now()))
+            }
+        } else {
+            quote! {
+                self.#ident
+            }
+        }
+    }
+
+    // Logs the given query along with the request id that made it
+    pub fn log_query(&self, query: TokenStream) -> TokenStream {
+        let backend = self.backend();
+
         quote! {
-            ::reign::model::diesel::pg::Pg
+            ::reign::model::log_query(::reign::model::diesel::debug_query::<#backend, _>(&#query));
         }
     }
 }
@@ -29,7 +29,7 @@ impl Model {
         }
     }
 
-    // Generates Queryable
+    // Generates Queryable & QueryableByName
     fn gen_queryable_trait(&self, ident: &Ident, fields: &[ModelField]) -> TokenStream {
         let table_ident = &self.table_ident;
         let schema = self.schema();
@@ -56,6 +56,16 @@ impl Model {
             }
         });
 
+        let build_by_name_expr = fields.iter().zip(&field_sql_ty).map(|(f, sql_ty)| {
+            let ident = &f.field.ident;
+            let ty = &f.field.ty;
+            let column = f.column_ident.to_string();
+
+            quote! {
+                #ident: ::reign::model::diesel::row::NamedRow::get::<#sql_ty, #ty>(row, #column)?
+            }
+        });
+
         quote! {
             impl<B> ::reign::model::diesel::deserialize::Queryable<(#(#field_sql_ty,)*), B> for #ident
             where
@@ -70,6 +80,18 @@ impl Model {
                     })
                 }
             }
+
+            impl<B> ::reign::model::diesel::deserialize::QueryableByName<B> for #ident
+            where
+                B: ::reign::model::diesel::backend::Backend,
+                #(#field_ty: ::reign::model::diesel::deserialize::FromSql<#field_sql_ty, B>,)*
+            {
+                fn build<'a>(row: &impl ::reign::model::diesel::row::NamedRow<'a, B>) -> ::reign::model::diesel::deserialize::Result<Self> {
+                    Ok(Self {
+                        #(#build_by_name_expr,)*
+                    })
+                }
+            }
         }
     }
 
@@ -87,6 +109,14 @@ impl Model {
                 #vis async fn one() -> Result<Option<#ident>, ::reign::model::Error> {
                     #ident::filter().one().await
                 }
+
+                #vis async fn find_by_sql(query: ::reign::model::RawQuery) -> Result<Vec<#ident>, ::reign::model::Error> {
+                    ::reign::model::Database::query::<#ident>(query).await
+                }
+
+                #vis async fn paginate(page: i64, per_page: i64) -> Result<::reign::model::Page<#ident>, ::reign::model::Error> {
+                    #ident::filter().paginate(page, per_page).await
+                }
             }
         }
     }
@@ -96,10 +126,17 @@ impl Model {
         let filterable_ident = self.filterable_ident();
         let table_ident = &self.table_ident;
         let schema = self.schema();
-        let db = self.db();
+        let log_query = self.log_query(quote! { select });
+        let log_count = self.log_query(quote! { count });
         let vis = &self.vis;
 
         let column_ident = fields.iter().map(|x| &x.column_ident).collect::<Vec<_>>();
+        let key_column_ident = self
+            .fields
+            .iter()
+            .filter(|x| x.primary_key)
+            .map(|x| &x.column_ident)
+            .collect::<Vec<_>>();
 
         quote! {
             #[allow(dead_code, unreachable_code)]
@@ -109,10 +146,9 @@ impl Model {
                 }
 
                 #vis async fn all_from(self, limit: Option<i64>, offset: Option<i64>) -> Result<Vec<#ident>, ::reign::model::Error> {
-                    use ::reign::model::tokio_diesel::AsyncRunQueryDsl;
-                    use ::reign::model::diesel::QueryDsl;
+                    use ::reign::model::diesel::{QueryDsl, RunQueryDsl};
 
-                    let mut select = #schema::#table_ident::table.filter(self.statement)
+                    let mut select = #schema::#table_ident::table.filter(self.scoped())
                         .select((
                             #(#schema::#table_ident::#column_ident,)*
                         ))
@@ -126,9 +162,9 @@ impl Model {
                         select = select.limit(limit);
                     }
 
-                    Ok(select
-                        .load_async::<#ident>(#db)
-                        .await?)
+                    #log_query
+
+                    ::reign::model::Database::run(move |conn| select.load::<#ident>(conn)).await
                 }
 
                 #vis async fn one(self) -> Result<Option<#ident>, ::reign::model::Error> {
@@ -136,10 +172,9 @@ impl Model {
                 }
 
                 #vis async fn one_from(self, offset: Option<i64>) -> Result<Option<#ident>, ::reign::model::Error> {
-                    use ::reign::model::tokio_diesel::{AsyncRunQueryDsl, OptionalExtension};
-                    use ::reign::model::diesel::QueryDsl;
+                    use ::reign::model::diesel::{OptionalExtension, QueryDsl, RunQueryDsl};
 
-                    let mut select = #schema::#table_ident::table.filter(self.statement)
+                    let mut select = #schema::#table_ident::table.filter(self.scoped())
                         .select((
                             #(#schema::#table_ident::#column_ident,)*
                         ))
@@ -150,10 +185,47 @@ impl Model {
                         select = select.offset(offset);
                     }
 
-                    Ok(select
-                        .get_result_async::<#ident>(#db)
-                        .await
-                        .optional()?)
+                    #log_query
+
+                    ::reign::model::Database::run(move |conn| select.get_result::<#ident>(conn).optional()).await
+                }
+
+                #vis async fn paginate(self, page: i64, per_page: i64) -> Result<::reign::model::Page<#ident>, ::reign::model::Error> {
+                    let (page, per_page) = ::reign::model::Page::<#ident>::clamp(page, per_page);
+                    let statement = self.scoped();
+
+                    let (rows, total_rows) = ::reign::model::Database::run(move |conn| {
+                        use ::reign::model::diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+
+                        let count = #schema::#table_ident::table.filter(&statement).count();
+
+                        #log_count
+
+                        let total_rows = count.get_result::<i64>(conn)?;
+
+                        // Pages too far to have an offset don't have any rows
+                        let offset = match ::reign::model::Page::<#ident>::offset(page, per_page) {
+                            Some(offset) => offset,
+                            None => return Ok((vec![], total_rows)),
+                        };
+
+                        let select = #schema::#table_ident::table.filter(&statement)
+                            .select((
+                                #(#schema::#table_ident::#column_ident,)*
+                            ))
+                            .order((
+                                #(#schema::#table_ident::#key_column_ident.asc(),)*
+                            ))
+                            .limit(per_page)
+                            .offset(offset);
+
+                        #log_query
+
+                        Ok((select.load::<#ident>(conn)?, total_rows))
+                    })
+                    .await?;
+
+                    Ok(::reign::model::Page::new(rows, page, per_page, total_rows))
                 }
             }
         }
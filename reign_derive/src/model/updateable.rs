@@ -110,16 +110,16 @@ impl Model {
             .iter()
             .filter(|x| !x.no_write)
             .map(|f| {
-                let Field { ident, ty, .. } = &f.field;
-                let ident = ident.as_ref().expect(INTERNAL_ERR);
+                let ty = &f.field.ty;
                 let column_ident = &f.column_ident;
+                let value = self.write_value(f, &["updated_at"]);
 
                 (
                     quote! {
                         Option<::reign::model::diesel::dsl::Eq<#schema::#table_ident::#column_ident, #ty>>
                     },
                     quote! {
-                        self.#ident.map(|x| #schema::#table_ident::#column_ident.eq(x))
+                        #value.map(|x| #schema::#table_ident::#column_ident.eq(x))
                     },
                 )
             })
@@ -212,7 +212,7 @@ impl Model {
         let updateable_ident = self.updateable_ident();
         let table_ident = &self.table_ident;
         let schema = self.schema();
-        let db = self.db();
+        let log_query = self.log_query(quote! { query });
         let vis = &self.vis;
 
         let column_ident = fields.iter().map(|x| &x.column_ident).collect::<Vec<_>>();
@@ -232,36 +232,38 @@ impl Model {
             #[allow(dead_code, unreachable_code)]
             impl #updateable_ident<#ident, Vec<#ident>> {
                 #vis async fn save(self) -> Result<Vec<#ident>, ::reign::model::Error> {
-                    use ::reign::model::tokio_diesel::AsyncRunQueryDsl;
-                    use ::reign::model::diesel::QueryDsl;
+                    use ::reign::model::diesel::{QueryDsl, RunQueryDsl};
 
-                    Ok(::reign::model::diesel::update(
+                    let query = ::reign::model::diesel::update(
                             #schema::#table_ident::table.filter(self.statement.statement),
                         )
                         .set(self.inner)
                         .returning((
                             #(#schema::#table_ident::#column_ident,)*
-                        ))
-                        .get_results_async::<#ident>(#db)
-                        .await?)
+                        ));
+
+                    #log_query
+
+                    ::reign::model::Database::run(move |conn| query.get_results::<#ident>(conn)).await
                 }
             }
 
             #[allow(dead_code, unreachable_code)]
             impl #updateable_ident<#ident, #ident> {
                 #vis async fn save(self) -> Result<#ident, ::reign::model::Error> {
-                    use ::reign::model::tokio_diesel::AsyncRunQueryDsl;
-                    use ::reign::model::diesel::QueryDsl;
+                    use ::reign::model::diesel::{QueryDsl, RunQueryDsl};
 
-                    Ok(::reign::model::diesel::update(
+                    let query = ::reign::model::diesel::update(
                             #schema::#table_ident::table.filter(self.statement.statement),
                         )
                         .set(self.inner)
                         .returning((
                             #(#schema::#table_ident::#column_ident,)*
-                        ))
-                        .get_result_async::<#ident>(#db)
-                        .await?)
+                        ));
+
+                    #log_query
+
+                    ::reign::model::Database::run(move |conn| query.get_result::<#ident>(conn)).await
                 }
             }
         }
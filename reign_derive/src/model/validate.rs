@@ -0,0 +1,117 @@
+use crate::{
+    model::{
+        attr::{Attr, Validation},
+        model::Model,
+    },
+    INTERNAL_ERR,
+};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::{abort, ResultExt};
+use quote::quote;
+use regex::Regex;
+
+impl Model {
+    // Generates `validate` method if any of the fields have validations
+    pub fn gen_validate(&self) -> TokenStream {
+        let ident = &self.ident;
+        let vis = &self.vis;
+
+        let checks = self
+            .fields
+            .iter()
+            .flat_map(|f| {
+                f.attrs
+                    .iter()
+                    .filter_map(|attr| match attr {
+                        Attr::Validate(_, validations) => Some(validations),
+                        _ => None,
+                    })
+                    .flatten()
+                    .map(move |validation| (f, validation))
+            })
+            .map(|(f, validation)| {
+                let field_ident = f.field.ident.as_ref().expect(INTERNAL_ERR);
+                let name = field_ident.to_string();
+
+                let value = quote! {
+                    ::reign::model::Validatable::as_validatable(&self.#field_ident)
+                };
+
+                match validation {
+                    Validation::Presence(_) => quote! {
+                        if #value.map_or(true, |x| x.trim().is_empty()) {
+                            errors.add(#name, "can't be blank");
+                        }
+                    },
+                    Validation::Length(_, min, max) => {
+                        let min = min.as_ref().map(|min| {
+                            let min = min.base10_parse::<usize>().unwrap_or_abort();
+                            let message = format!("is too short (minimum is {} characters)", min);
+
+                            quote! {
+                                if length < #min {
+                                    errors.add(#name, #message);
+                                }
+                            }
+                        });
+                        let max = max.as_ref().map(|max| {
+                            let max = max.base10_parse::<usize>().unwrap_or_abort();
+                            let message = format!("is too long (maximum is {} characters)", max);
+
+                            quote! {
+                                if length > #max {
+                                    errors.add(#name, #message);
+                                }
+                            }
+                        });
+
+                        quote! {
+                            if let Some(value) = #value {
+                                let length = value.chars().count();
+
+                                #min
+                                #max
+                            }
+                        }
+                    }
+                    Validation::Format(_, format) => {
+                        if Regex::new(&format.value()).is_err() {
+                            abort!(format, "`format` needs a valid regex");
+                        }
+
+                        quote! {
+                            if let Some(value) = #value {
+                                static REGEX: ::reign::model::once_cell::sync::Lazy<::reign::model::regex::Regex> =
+                                    ::reign::model::once_cell::sync::Lazy::new(|| {
+                                        ::reign::model::regex::Regex::new(#format).unwrap()
+                                    });
+
+                                if !REGEX.is_match(value) {
+                                    errors.add(#name, "is invalid");
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if checks.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            #[allow(dead_code, unreachable_code)]
+            impl #ident {
+                #vis fn validate(&self) -> Result<(), ::reign::model::ValidationErrors> {
+                    let mut errors = ::reign::model::ValidationErrors::new();
+
+                    #(#checks)*
+
+                    errors.into_result()
+                }
+            }
+        }
+    }
+}
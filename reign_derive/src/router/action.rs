@@ -0,0 +1,113 @@
+use crate::router::params::{arg_ident, arg_ty};
+
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{
+    parse_quote, spanned::Spanned, FnArg, GenericArgument, Ident, ItemFn, PathArguments,
+    ReturnType, Signature, Type,
+};
+
+// Request is given to the action if the argument is a mutable reference
+fn is_request(arg: &FnArg) -> bool {
+    if let Type::Reference(x) = arg_ty(arg) {
+        return x.mutability.is_some();
+    }
+
+    false
+}
+
+// `Result<T>` is expanded to `Result<T, Error>` using the router error
+fn default_error(output: &mut ReturnType) {
+    if let ReturnType::Type(_, ty) = output {
+        if let Type::Path(path) = &mut **ty {
+            if let Some(segment) = path.path.segments.last_mut() {
+                if segment.ident != "Result" {
+                    return;
+                }
+
+                if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    if args.args.len() == 1 {
+                        let error: GenericArgument = parse_quote! { ::reign::router::Error };
+                        args.args.push(error);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn action(input: ItemFn) -> TokenStream {
+    let ItemFn {
+        attrs,
+        sig,
+        block,
+        vis,
+    } = input;
+    let Signature {
+        ident,
+        inputs,
+        asyncness,
+        fn_token,
+        mut output,
+        ..
+    } = sig;
+
+    if asyncness.is_none() {
+        abort!(
+            fn_token.span(),
+            "expected the action to be an async function"
+        );
+    }
+
+    if let ReturnType::Default = output {
+        abort!(ident.span(), "expected the action to return a result");
+    }
+
+    default_error(&mut output);
+
+    let req_ident = inputs
+        .iter()
+        .find(|x| is_request(x))
+        .map(arg_ident)
+        .unwrap_or_else(|| Ident::new("__req", Span::call_site()));
+
+    let args = inputs
+        .iter()
+        .map(|x| {
+            let ident = arg_ident(x);
+
+            if is_request(x) {
+                return (quote! { #ident }, quote! {});
+            }
+
+            let ty = arg_ty(x);
+
+            (
+                quote! { #ident },
+                quote! {
+                    let #ident = <#ty as ::reign::router::FromRequest>::from_request(#req_ident).await?;
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let idents = args.iter().map(|x| &x.0).collect::<Vec<_>>();
+    let extractions = args.iter().map(|x| &x.1).collect::<Vec<_>>();
+
+    quote! {
+        #(#attrs)*
+        #vis #asyncness #fn_token #ident(
+            #req_ident: &mut ::reign::router::Request
+        ) #output {
+            #[inline]
+            #asyncness #fn_token _call(
+                #inputs
+            ) #output #block
+
+            #(#extractions)*
+
+            _call(#(#idents),*).await
+        }
+    }
+}
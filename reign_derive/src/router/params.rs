@@ -3,9 +3,110 @@ use crate::{router::ty::subty_if_name, INTERNAL_ERR};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{spanned::Spanned, FnArg, Ident, ItemFn, LitStr, Pat, Signature, Type};
+use syn::{
+    parse::{Parse, ParseStream, Result},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    FnArg, Ident, ItemFn, LitStr, Pat, Signature, Token, Type,
+};
 
-fn arg_ident(arg: &FnArg) -> Ident {
+// Segments of the path given to `#[params]`, written the same way as in `p!`
+pub struct Path {
+    segments: Punctuated<Segment, Token![/]>,
+}
+
+enum Segment {
+    Static(LitStr),
+    Param { ident: Ident, glob: bool, opt: bool },
+}
+
+impl Parse for Path {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            segments: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+impl Parse for Segment {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(Segment::Static(input.parse()?));
+        }
+
+        let ident = input.parse()?;
+        let glob = input.parse::<Option<Token![*]>>()?.is_some();
+        let opt = input.parse::<Option<Token![?]>>()?.is_some();
+
+        if input.parse::<Option<Token![@]>>()?.is_some() {
+            input.parse::<LitStr>()?;
+        }
+
+        Ok(Segment::Param { ident, glob, opt })
+    }
+}
+
+// Make sure the dynamic segments of the path line up with the params of the handle
+fn check_path(path: &Path, inputs: &Punctuated<FnArg, Token![,]>) {
+    let params = path
+        .segments
+        .iter()
+        .filter_map(|x| match x {
+            Segment::Param { ident, glob, opt } => Some((ident, *glob, *opt)),
+            Segment::Static(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    for arg in inputs.iter().skip(1) {
+        let ident = arg_ident(arg);
+
+        if !params.iter().any(|(x, _, _)| **x == ident) {
+            abort!(
+                ident.span(),
+                "param `{}` is not declared in the path",
+                ident
+            );
+        }
+    }
+
+    for (ident, glob, opt) in params {
+        let arg = if let Some(arg) = inputs.iter().skip(1).find(|x| arg_ident(x) == *ident) {
+            arg
+        } else {
+            abort!(
+                ident.span(),
+                "path param `{}` is not used by the handle",
+                ident
+            );
+        };
+
+        let mut ty = arg_ty(arg);
+
+        if opt {
+            ty = if let Some(ty) = subty_if_name(ty, "Option") {
+                ty
+            } else {
+                abort!(
+                    arg.span(),
+                    "optional path param `{}` expects an `Option`",
+                    ident
+                );
+            };
+        } else if subty_if_name(ty.clone(), "Option").is_some() {
+            abort!(
+                arg.span(),
+                "required path param `{}` can't be an `Option`",
+                ident
+            );
+        }
+
+        if glob && subty_if_name(ty, "Vec").is_none() {
+            abort!(arg.span(), "glob path param `{}` expects a `Vec`", ident);
+        }
+    }
+}
+
+pub(super) fn arg_ident(arg: &FnArg) -> Ident {
     if let FnArg::Typed(x) = arg {
         if let Pat::Ident(x) = &*x.pat {
             return x.ident.clone();
@@ -15,7 +116,7 @@ fn arg_ident(arg: &FnArg) -> Ident {
     abort!(arg.span(), "expected a typed function arg with clear ident");
 }
 
-fn arg_ty(arg: &FnArg) -> Type {
+pub(super) fn arg_ty(arg: &FnArg) -> Type {
     if let FnArg::Typed(x) = arg {
         return (*x.ty).clone();
     }
@@ -23,7 +124,7 @@ fn arg_ty(arg: &FnArg) -> Type {
     abort!(arg.span(), "expected a typed function arg with clear ident");
 }
 
-pub fn params(input: ItemFn) -> TokenStream {
+pub fn params(path: Option<Path>, input: ItemFn) -> TokenStream {
     let ItemFn {
         attrs,
         sig,
@@ -39,6 +140,10 @@ pub fn params(input: ItemFn) -> TokenStream {
         ..
     } = sig;
 
+    if let Some(path) = &path {
+        check_path(path, &inputs);
+    }
+
     let args = inputs.iter().map(|x| arg_ident(x)).collect::<Vec<_>>();
 
     let req = if let Some(arg) = inputs.first() {
@@ -82,6 +82,11 @@ pub fn views(input: Views) -> TokenStream {
 
 #[cfg(not(feature = "hot-reload"))]
 pub fn views(input: Views) -> TokenStream {
+    // Errors in the generated views point at the paths given to the macro
+    let span = input
+        .paths
+        .first()
+        .map_or_else(Span::call_site, |x| x.span());
     let dir = get_dir(input);
     let mut map = HashMap::new();
 
@@ -98,6 +103,7 @@ pub fn views(input: Views) -> TokenStream {
         },
         |_, _, file| Ok(file),
         |_, views| Ok(views),
+        span,
     )
     .expect(INTERNAL_ERR);
 
@@ -144,6 +150,14 @@ fn view_path(input: &Render) -> TokenStream {
     }
 }
 
+fn capacity(input: &Render) -> TokenStream {
+    let path = view_path(input);
+
+    quote! {
+        crate::views::#path::CAPACITY
+    }
+}
+
 fn capture(input: &Render) -> TokenStream {
     let path = view_path(input);
     let value = IDENTMAP.get().expect(INTERNAL_ERR).get(&input.id());
@@ -182,19 +196,57 @@ fn capture(input: &Render) -> TokenStream {
 
 pub fn render(mut input: Render) -> TokenStream {
     let capture = capture(&input);
+    let capacity = capacity(&input);
 
     let status: Expr = input
         .options
         .remove("status")
         .unwrap_or_else(|| parse_str("200").unwrap());
 
+    let content_type = match input.options.remove("content_type") {
+        Some(content_type) => quote! { Some(#content_type) },
+        None => quote! { None },
+    };
+
     if cfg!(feature = "router") {
-        quote! {
-            ::reign::router::helpers::render(#capture, #status)
-        }
+        let rendered = quote! {
+            ::reign::router::helpers::render_with_capacity(#capture, #status, #content_type, #capacity)
+        };
+
+        template_modified(&input, rendered)
     } else {
         quote! {
-            format!("{}", #capture)
+            {
+                let mut content = String::with_capacity(#capacity);
+
+                ::std::fmt::Write::write_fmt(&mut content, format_args!("{}", #capture))
+                    .expect("a Display implementation returned an error unexpectedly");
+                content
+            }
         }
     }
 }
+
+// In `hot-reload` mode, the templates are on the disk and we can let the browser
+// cache the rendered view until the template changes.
+#[cfg(feature = "hot-reload")]
+fn template_modified(input: &Render, rendered: TokenStream) -> TokenStream {
+    let mut path = DIR.get().expect(INTERNAL_ERR).clone();
+
+    for part in input.parts() {
+        path.push(part);
+    }
+
+    path.set_extension("html");
+
+    let path = LitStr::new(&path.to_string_lossy(), Span::call_site());
+
+    quote! {
+        #rendered.map(|r| ::reign::router::helpers::template_modified(r, #path))
+    }
+}
+
+#[cfg(not(feature = "hot-reload"))]
+fn template_modified(_: &Render, rendered: TokenStream) -> TokenStream {
+    rendered
+}
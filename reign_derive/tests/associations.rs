@@ -0,0 +1,76 @@
+mod schema;
+
+use reign::{model::diesel, prelude::*};
+use serial_test::serial;
+
+#[derive(Debug, Model)]
+#[model(has_many(Post))]
+pub struct User {
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Model)]
+pub struct Post {
+    id: i32,
+    #[model(belongs_to(User))]
+    user_id: i32,
+    title: String,
+}
+
+#[derive(Debug, Model)]
+#[model(table_name = users, has_many(Article, foreign_key = user_id))]
+pub struct Author {
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Model)]
+#[model(table_name = posts)]
+pub struct Article {
+    id: i32,
+    #[model(belongs_to(Author, primary_key = id))]
+    user_id: i32,
+    title: String,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_belongs_to() {
+    schema::setup().await;
+
+    let post = Post::filter().title("Hi").one().await.unwrap().unwrap();
+    let user = post.user().await.unwrap().unwrap();
+
+    assert_eq!(user.id, 2);
+    assert_eq!(user.name, "Sean");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_has_many() {
+    schema::setup().await;
+
+    let user = User::filter().id(1).one().await.unwrap().unwrap();
+    let posts = user.posts().await.unwrap();
+
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].title, "Hello");
+    assert_eq!(posts[1].title, "World");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_custom_keys() {
+    schema::setup().await;
+
+    let author = Author::filter().id(2).one().await.unwrap().unwrap();
+    let articles = author.articles().await.unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "Hi");
+
+    let author = articles[0].author().await.unwrap().unwrap();
+
+    assert_eq!(author.name, "Sean");
+}
@@ -62,6 +62,20 @@ async fn test_drop() {
     assert!(user.is_none());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_drop_not_found() {
+    schema::setup().await;
+
+    let user = User::filter().id(3).one().await.unwrap().unwrap();
+    let user = user.drop().await.unwrap();
+
+    assert!(matches!(
+        user.drop().await,
+        Err(reign::model::Error::NotFound)
+    ));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[serial]
 async fn test_tag_filter_drop() {
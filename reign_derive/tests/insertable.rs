@@ -11,6 +11,16 @@ pub struct User {
     email: Option<String>,
 }
 
+#[derive(Debug, Model)]
+#[model(table_name = users)]
+pub struct Account {
+    #[model(no_write)]
+    id: i32,
+    #[model(required)]
+    name: String,
+    email: Option<String>,
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[serial]
 async fn test_new() {
@@ -87,3 +97,20 @@ async fn test_tag_new() {
 
     assert_eq!(one.id, 4);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_new_required() {
+    schema::setup().await;
+
+    let one = Account::new()
+        .email(None)
+        .name("Ray".into())
+        .insert()
+        .await
+        .unwrap();
+
+    assert_eq!(one.id, 4);
+    assert_eq!(one.name, "Ray");
+    assert_eq!(one.email, None);
+}
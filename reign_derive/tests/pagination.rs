@@ -0,0 +1,59 @@
+mod schema;
+
+use reign::{model::diesel, prelude::*};
+use serial_test::serial;
+
+#[derive(Debug, Model)]
+pub struct Item {
+    #[model(no_write)]
+    id: i32,
+    position: i32,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_paginate() {
+    schema::setup().await;
+
+    for position in 1..=25 {
+        Item::new().position(position).insert().await.unwrap();
+    }
+
+    let page = Item::paginate(2, 10).await.unwrap();
+
+    assert_eq!(
+        page.rows.iter().map(|x| x.position).collect::<Vec<_>>(),
+        (11..=20).collect::<Vec<_>>()
+    );
+    assert_eq!(page.page, 2);
+    assert_eq!(page.per_page, 10);
+    assert_eq!(page.total_rows, 25);
+    assert_eq!(page.total_pages, 3);
+
+    let page = Item::paginate(3, 10).await.unwrap();
+
+    assert_eq!(page.rows.len(), 5);
+
+    let page = Item::paginate(i64::MAX, 10).await.unwrap();
+
+    assert!(page.rows.is_empty());
+    assert_eq!(page.page, i64::MAX);
+    assert_eq!(page.total_rows, 25);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_paginate_filter() {
+    schema::setup().await;
+
+    for position in 1..=25 {
+        Item::new().position(position % 2).insert().await.unwrap();
+    }
+
+    let page = Item::filter().position(0).paginate(0, 5).await.unwrap();
+
+    assert_eq!(page.page, 1);
+    assert_eq!(page.rows.len(), 5);
+    assert_eq!(page.total_rows, 12);
+    assert_eq!(page.total_pages, 3);
+}
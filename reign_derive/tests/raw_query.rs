@@ -0,0 +1,49 @@
+mod schema;
+
+use reign::{
+    model::{
+        diesel::{self, sql_types::Text},
+        RawQuery,
+    },
+    prelude::*,
+};
+use serial_test::serial;
+
+#[derive(Debug, Model)]
+pub struct User {
+    id: i32,
+    name: String,
+    email: Option<String>,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_find_by_sql() {
+    schema::setup().await;
+
+    let users = User::find_by_sql(
+        RawQuery::new("SELECT * FROM users WHERE name = $1 ORDER BY id").bind::<Text, _>("John"),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].id, 1);
+    assert_eq!(users[0].email, None);
+    assert_eq!(users[1].id, 3);
+    assert_eq!(users[1].email, Some("john@mail.com".into()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_bind_not_interpolated() {
+    schema::setup().await;
+
+    let users = User::find_by_sql(
+        RawQuery::new("SELECT * FROM users WHERE name = $1").bind::<Text, _>("John' OR '1' = '1"),
+    )
+    .await
+    .unwrap();
+
+    assert!(users.is_empty());
+}
@@ -8,6 +8,45 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    posts (id) {
+        id -> Int4,
+        user_id -> Int4,
+        title -> Varchar,
+    }
+}
+
+diesel::table! {
+    notes (id) {
+        id -> Int4,
+        body -> Varchar,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    comments (id) {
+        id -> Int4,
+        body -> Varchar,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    items (id) {
+        id -> Int4,
+        position -> Int4,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Int4,
+        name -> Varchar,
+    }
+}
+
 pub async fn setup() {
     let conn = Database::get_opt()
         .or_else(|| {
@@ -31,4 +70,78 @@ pub async fn setup() {
     conn.batch_execute_async("INSERT INTO users (name, email) VALUES ('John', NULL), ('Sean', 'sean@mail.com'), ('John', 'john@mail.com')")
         .await
         .unwrap();
+
+    conn.batch_execute_async("DROP TABLE IF EXISTS posts")
+        .await
+        .unwrap();
+    conn.batch_execute_async(
+        "CREATE TABLE posts (
+            id SERIAL,
+            user_id INTEGER NOT NULL,
+            title VARCHAR(255) NOT NULL
+        )",
+    )
+    .await
+    .unwrap();
+    conn.batch_execute_async(
+        "INSERT INTO posts (user_id, title) VALUES (1, 'Hello'), (2, 'Hi'), (1, 'World')",
+    )
+    .await
+    .unwrap();
+
+    conn.batch_execute_async("DROP TABLE IF EXISTS notes")
+        .await
+        .unwrap();
+    conn.batch_execute_async(
+        "CREATE TABLE notes (
+            id SERIAL,
+            body VARCHAR(255) NOT NULL,
+            created_at TIMESTAMP NOT NULL,
+            updated_at TIMESTAMP NOT NULL
+        )",
+    )
+    .await
+    .unwrap();
+
+    conn.batch_execute_async("DROP TABLE IF EXISTS comments")
+        .await
+        .unwrap();
+    conn.batch_execute_async(
+        "CREATE TABLE comments (
+            id SERIAL,
+            body VARCHAR(255) NOT NULL,
+            deleted_at TIMESTAMP
+        )",
+    )
+    .await
+    .unwrap();
+    conn.batch_execute_async(
+        "INSERT INTO comments (body, deleted_at) VALUES ('Nice', NULL), ('Spam', NOW())",
+    )
+    .await
+    .unwrap();
+
+    conn.batch_execute_async("DROP TABLE IF EXISTS items")
+        .await
+        .unwrap();
+    conn.batch_execute_async(
+        "CREATE TABLE items (
+            id SERIAL,
+            position INTEGER NOT NULL
+        )",
+    )
+    .await
+    .unwrap();
+
+    conn.batch_execute_async("DROP TABLE IF EXISTS tags")
+        .await
+        .unwrap();
+    conn.batch_execute_async(
+        "CREATE TABLE tags (
+            id SERIAL,
+            name VARCHAR(255) NOT NULL UNIQUE
+        )",
+    )
+    .await
+    .unwrap();
 }
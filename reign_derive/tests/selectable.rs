@@ -185,3 +185,19 @@ async fn test_multi_tag_one() {
     assert_eq!(one.name, "John");
     assert_eq!(one.email, None);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_multi_tag_all_filter_limit() {
+    schema::setup().await;
+
+    let all = UserDetails::filter()
+        .name("John")
+        .all_from(Some(1), Some(1))
+        .await
+        .unwrap();
+
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].name, "John");
+    assert_eq!(all[0].email, Some("john@mail.com".into()));
+}
@@ -0,0 +1,96 @@
+mod schema;
+
+use chrono::NaiveDateTime;
+use reign::{model::diesel, prelude::*};
+use serial_test::serial;
+
+#[derive(Debug, Model)]
+#[model(soft_delete)]
+pub struct Comment {
+    #[model(no_write)]
+    id: i32,
+    body: String,
+    deleted_at: Option<NaiveDateTime>,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_default_scope() {
+    schema::setup().await;
+
+    let comments = Comment::all().await.unwrap();
+
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].body, "Nice");
+
+    let comment = Comment::filter().body("Spam").one().await.unwrap();
+
+    assert!(comment.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_with_deleted() {
+    schema::setup().await;
+
+    let comments = Comment::filter().with_deleted().all().await.unwrap();
+
+    assert_eq!(comments.len(), 2);
+
+    let comment = Comment::filter()
+        .body("Spam")
+        .with_deleted()
+        .one()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(comment.deleted_at.is_some());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_soft_delete() {
+    schema::setup().await;
+
+    let comment = Comment::one().await.unwrap().unwrap();
+    let deleted = comment.soft_delete().await.unwrap();
+
+    assert_eq!(deleted.id, comment.id);
+    assert!(deleted.deleted_at.is_some());
+
+    assert!(Comment::all().await.unwrap().is_empty());
+    assert_eq!(
+        Comment::filter().with_deleted().all().await.unwrap().len(),
+        2
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_soft_delete_filter() {
+    schema::setup().await;
+
+    let spam = Comment::filter()
+        .body("Spam")
+        .with_deleted()
+        .one()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let deleted = Comment::filter().soft_delete().await.unwrap();
+
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0].body, "Nice");
+
+    let after = Comment::filter()
+        .body("Spam")
+        .with_deleted()
+        .one()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(after.deleted_at, spam.deleted_at);
+}
@@ -0,0 +1,59 @@
+mod schema;
+
+use chrono::{Duration as ChronoDuration, NaiveDate, NaiveDateTime, Utc};
+use reign::{model::diesel, prelude::*};
+use serial_test::serial;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[derive(Debug, Model)]
+#[model(timestamps)]
+pub struct Note {
+    #[model(no_write)]
+    id: i32,
+    body: String,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_insert_update() {
+    schema::setup().await;
+
+    // The database stores timestamps with a lower precision
+    let before = Utc::now().naive_utc() - ChronoDuration::seconds(1);
+    let note = Note::new().body("Hello".into()).insert().await.unwrap();
+
+    assert!(note.created_at >= before);
+    assert!(note.updated_at >= before);
+
+    sleep(Duration::from_millis(10)).await;
+
+    let updated = note.set().body("World".into()).save().await.unwrap();
+
+    assert_eq!(updated.body, "World");
+    assert_eq!(updated.created_at, note.created_at);
+    assert!(updated.updated_at > note.updated_at);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_manual() {
+    schema::setup().await;
+
+    let time = NaiveDate::from_ymd_opt(2020, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let note = Note::new()
+        .body("Hello".into())
+        .created_at(time)
+        .updated_at(time)
+        .insert()
+        .await
+        .unwrap();
+
+    assert_eq!(note.created_at, time);
+    assert_eq!(note.updated_at, time);
+}
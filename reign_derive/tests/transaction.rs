@@ -0,0 +1,118 @@
+mod schema;
+
+use reign::{
+    model::{diesel, Database, Error},
+    prelude::*,
+};
+use serial_test::serial;
+use tokio::time::{sleep, timeout};
+
+use std::time::Duration;
+
+#[derive(Debug, Model)]
+pub struct Tag {
+    #[model(no_write)]
+    id: i32,
+    name: String,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_commit() {
+    schema::setup().await;
+
+    let (one, two) = Database::transaction(|| async {
+        let one = Tag::new().name("rust".into()).insert().await?;
+        let two = Tag::new().name("web".into()).insert().await?;
+
+        Ok::<_, Error>((one, two))
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(one.name, "rust");
+    assert_eq!(two.name, "web");
+    assert_eq!(Tag::all().await.unwrap().len(), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_rollback() {
+    schema::setup().await;
+
+    let result = Database::transaction(|| async {
+        Tag::new().name("rust".into()).insert().await?;
+
+        // Fails because the name is unique
+        Tag::new().name("rust".into()).insert().await?;
+
+        Ok(())
+    })
+    .await;
+
+    assert!(result.is_err());
+    assert!(Tag::all().await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_nested() {
+    schema::setup().await;
+
+    let result = Database::transaction(|| async {
+        Tag::new().name("rust".into()).insert().await?;
+
+        Database::transaction(|| async {
+            Tag::new().name("web".into()).insert().await?;
+
+            Ok(())
+        })
+        .await?;
+
+        Err::<(), _>(Error::NotFound)
+    })
+    .await;
+
+    assert!(matches!(result, Err(Error::NotFound)));
+    assert!(Tag::all().await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_cancelled() {
+    schema::setup().await;
+
+    let result = timeout(
+        Duration::from_millis(100),
+        Database::transaction(|| async {
+            Tag::new().name("rust".into()).insert().await?;
+
+            sleep(Duration::from_secs(10)).await;
+
+            Ok(())
+        }),
+    )
+    .await;
+
+    assert!(result.is_err());
+
+    // Wait for the rollback of the dropped transaction
+    sleep(Duration::from_millis(100)).await;
+
+    assert!(Tag::all().await.unwrap().is_empty());
+
+    // Connections from the pool are not left inside the transaction
+    for name in ["web", "db", "orm"] {
+        Tag::new().name(name.into()).insert().await.unwrap();
+    }
+
+    Database::transaction(|| async {
+        Tag::new().name("cli".into()).insert().await?;
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(Tag::all().await.unwrap().len(), 4);
+}
@@ -12,4 +12,16 @@ struct Org {
     foo: i32,
 }
 
+#[derive(Model)]
+struct Team {
+    #[model(has_many(User))]
+    id: i32,
+}
+
+#[derive(Model)]
+struct Comment {
+    #[model(soft_delete)]
+    deleted_at: i32,
+}
+
 fn main() {}
@@ -18,4 +18,10 @@ struct Team {
     id: i32,
 }
 
+#[derive(Model)]
+#[model(belongs_to(User))]
+struct Member {
+    id: i32,
+}
+
 fn main() {}
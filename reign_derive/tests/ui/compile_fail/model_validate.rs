@@ -0,0 +1,21 @@
+use reign::prelude::*;
+
+#[derive(Model)]
+struct User {
+    #[model(validate(unique))]
+    id: i32,
+}
+
+#[derive(Model)]
+struct Post {
+    #[model(validate(format = "[a-z"))]
+    id: i32,
+}
+
+#[derive(Model)]
+struct Note {
+    #[model(validate(length()))]
+    id: i32,
+}
+
+fn main() {}
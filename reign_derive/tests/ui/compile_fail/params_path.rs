@@ -0,0 +1,23 @@
+use reign::prelude::*;
+
+#[params("users" / id)]
+async fn unused(req: &mut Request) -> Result<impl Response, Error> {
+    Ok("unused")
+}
+
+#[params("users" / id)]
+async fn undeclared(req: &mut Request, id: String, name: String) -> Result<impl Response, Error> {
+    Ok(name)
+}
+
+#[params("users" / id?)]
+async fn optional(req: &mut Request, id: String) -> Result<impl Response, Error> {
+    Ok(id)
+}
+
+#[params("files" / path*)]
+async fn glob(req: &mut Request, path: String) -> Result<impl Response, Error> {
+    Ok(path)
+}
+
+fn main() {}
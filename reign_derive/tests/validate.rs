@@ -0,0 +1,71 @@
+mod schema;
+
+use reign::{model::diesel, prelude::*};
+
+#[derive(Debug, Model)]
+pub struct User {
+    #[model(no_write)]
+    id: i32,
+    #[model(validate(presence, length(min = 2, max = 50)))]
+    name: String,
+    #[model(validate(format = "^[^@]+@[^@]+$"))]
+    email: Option<String>,
+}
+
+#[test]
+fn test_valid() {
+    let user = User {
+        id: 1,
+        name: "John".into(),
+        email: Some("john@mail.com".into()),
+    };
+
+    assert!(user.validate().is_ok());
+
+    let user = User {
+        id: 1,
+        name: "John".into(),
+        email: None,
+    };
+
+    assert!(user.validate().is_ok());
+}
+
+#[test]
+fn test_invalid() {
+    let user = User {
+        id: 1,
+        name: "J".into(),
+        email: Some("john".into()),
+    };
+
+    let errors = user.validate().unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors.get("name"),
+        Some(&["is too short (minimum is 2 characters)".to_string()][..])
+    );
+    assert_eq!(errors.get("email"), Some(&["is invalid".to_string()][..]));
+}
+
+#[test]
+fn test_presence() {
+    let user = User {
+        id: 1,
+        name: "".into(),
+        email: None,
+    };
+
+    let errors = user.validate().unwrap_err();
+
+    assert_eq!(
+        errors.get("name"),
+        Some(
+            &[
+                "can't be blank".to_string(),
+                "is too short (minimum is 2 characters)".to_string()
+            ][..]
+        )
+    );
+}
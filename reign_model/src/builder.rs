@@ -0,0 +1,5 @@
+/// Marks a required column of an insert builder as given.
+pub struct Set;
+
+/// Marks a required column of an insert builder as not yet given.
+pub struct Unset;
@@ -1,11 +1,31 @@
-use diesel::r2d2::{ConnectionManager, Pool};
+use crate::{request_id::spawn_blocking, Error};
+
+use diesel::{
+    connection::{AnsiTransactionManager, TransactionManager},
+    r2d2::{ConnectionManager, Pool, PooledConnection},
+    QueryResult,
+};
+use log::error;
 use once_cell::sync::OnceCell;
+use tokio::runtime::Handle;
+use tokio_diesel::AsyncError;
+
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+};
 
 #[cfg(feature = "model-postgres")]
 use diesel::PgConnection as Connection;
 
+type Transaction = Arc<Mutex<PooledConnection<ConnectionManager<Connection>>>>;
+
 static DB: OnceCell<Pool<ConnectionManager<Connection>>> = OnceCell::new();
 
+tokio::task_local! {
+    static TRANSACTION: Transaction;
+}
+
 pub struct Database {
     url: String,
 }
@@ -36,4 +56,120 @@ impl Database {
     pub fn get() -> &'static Pool<ConnectionManager<Connection>> {
         Self::get_opt().expect("Database must be connected before using it")
     }
+
+    /// Runs the given future inside a database transaction.
+    ///
+    /// The queries made by the future use the connection of the transaction. The transaction
+    /// is committed if the future returns `Ok` and rolled back otherwise, which also happens
+    /// if the future is dropped before finishing, like when it times out. Nested calls are
+    /// part of the outer transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use reign::model::Database;
+    ///
+    /// let (user, post) = Database::transaction(|| async {
+    ///     let user = User::new().name("John".into()).insert().await?;
+    ///     let post = Post::new().user_id(user.id).insert().await?;
+    ///
+    ///     Ok((user, post))
+    /// })
+    /// .await?;
+    /// ```
+    pub async fn transaction<F, Fut, T>(f: F) -> Result<T, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        if TRANSACTION.try_with(|_| ()).is_ok() {
+            return f().await;
+        }
+
+        let conn = spawn_blocking(|| {
+            let mut conn = Self::get().get().map_err(AsyncError::Checkout)?;
+
+            AnsiTransactionManager::begin_transaction(&mut *conn).map_err(AsyncError::Error)?;
+
+            Ok::<_, AsyncError>(conn)
+        })
+        .await?;
+
+        let guard = TransactionGuard(Some(Arc::new(Mutex::new(conn))));
+
+        let result = TRANSACTION.scope(guard.conn(), f()).await;
+
+        guard.finish(result.is_ok()).await?;
+
+        result
+    }
+
+    // Runs the given queries on the connection of the current transaction or one from the pool
+    #[doc(hidden)]
+    pub async fn run<F, R>(f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut Connection) -> QueryResult<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let transaction = TRANSACTION.try_with(|x| x.clone()).ok();
+
+        spawn_blocking(move || match transaction {
+            Some(conn) => f(&mut conn.lock().expect(LOCK_ERR)).map_err(AsyncError::Error),
+            None => {
+                let mut conn = Self::get().get().map_err(AsyncError::Checkout)?;
+
+                f(&mut conn).map_err(AsyncError::Error)
+            }
+        })
+        .await
+        .map_err(Error::from)
+    }
+}
+
+const LOCK_ERR: &str = "Transaction connection was poisoned by a panic";
+
+// Rolls back the transaction if it is dropped without being finished
+struct TransactionGuard(Option<Transaction>);
+
+impl TransactionGuard {
+    fn conn(&self) -> Transaction {
+        self.0.clone().expect(GUARD_ERR)
+    }
+
+    async fn finish(mut self, commit: bool) -> Result<(), Error> {
+        let conn = self.0.take().expect(GUARD_ERR);
+
+        spawn_blocking(move || end(&conn, commit))
+            .await
+            .map_err(|err| AsyncError::Error(err).into())
+    }
+}
+
+impl Drop for TransactionGuard {
+    fn drop(&mut self) {
+        if let Some(conn) = self.0.take() {
+            let rollback = move || {
+                if let Err(err) = end(&conn, false) {
+                    error!("Unable to rollback the dropped transaction: {}", err);
+                }
+            };
+
+            match Handle::try_current() {
+                Ok(handle) => drop(handle.spawn_blocking(rollback)),
+                Err(_) => rollback(),
+            }
+        }
+    }
+}
+
+const GUARD_ERR: &str = "Transaction was already finished";
+
+fn end(conn: &Transaction, commit: bool) -> QueryResult<()> {
+    let mut conn = conn.lock().unwrap_or_else(|err| err.into_inner());
+
+    if commit {
+        AnsiTransactionManager::commit_transaction(&mut **conn)
+    } else {
+        AnsiTransactionManager::rollback_transaction(&mut **conn)
+    }
 }
@@ -1,7 +1,40 @@
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 use thiserror::Error;
+use tokio_diesel::AsyncError;
 
 #[derive(Error, Debug)]
 pub enum Error {
+    #[error("record not found")]
+    NotFound,
     #[error(transparent)]
-    Diesel(#[from] tokio_diesel::AsyncError),
+    Diesel(AsyncError),
+}
+
+impl From<AsyncError> for Error {
+    fn from(err: AsyncError) -> Self {
+        match err {
+            AsyncError::Error(DieselError::NotFound) => Self::NotFound,
+            err => Self::Diesel(err),
+        }
+    }
+}
+
+impl Error {
+    /// Returns true if the error is temporary and the query can be retried.
+    ///
+    /// Failing to get a connection, lost connections, serialization failures and deadlocks
+    /// are considered transient while constraint violations are not.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Diesel(AsyncError::Checkout(_)) => true,
+            Self::Diesel(AsyncError::Error(DieselError::DatabaseError(kind, info))) => match kind {
+                DatabaseErrorKind::SerializationFailure
+                | DatabaseErrorKind::UnableToSendCommand
+                | DatabaseErrorKind::ClosedConnection => true,
+                DatabaseErrorKind::Unknown => info.message().contains("deadlock detected"),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
 }
@@ -2,12 +2,32 @@
 #![doc = include_str!("../README.md")]
 
 pub use diesel;
+#[doc(hidden)]
+pub use once_cell;
+#[doc(hidden)]
+pub use regex;
 pub use tokio_diesel;
 
+mod builder;
 mod connection;
 mod error;
+mod page;
 #[cfg(feature = "plugin")]
 mod plugin;
+mod query;
+mod request_id;
+mod retry;
+mod timestamp;
+mod validation;
 
+pub use builder::{Set, Unset};
 pub use connection::Database;
 pub use error::Error;
+pub use page::Page;
+#[cfg(feature = "plugin")]
+pub use plugin::QueryLog;
+pub use query::RawQuery;
+pub use request_id::{log_query, request_id, with_request_id};
+pub use retry::Retry;
+pub use timestamp::Timestamp;
+pub use validation::{Validatable, ValidationErrors};
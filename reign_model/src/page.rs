@@ -0,0 +1,67 @@
+/// A page of records returned by the `paginate` method of models.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    /// Records in the page.
+    pub rows: Vec<T>,
+    /// Current page, starting from 1.
+    pub page: i64,
+    /// Number of records per page.
+    pub per_page: i64,
+    /// Number of records across all the pages.
+    pub total_rows: i64,
+    /// Number of pages.
+    pub total_pages: i64,
+}
+
+impl<T> Page<T> {
+    /// Maximum number of records allowed per page.
+    pub const MAX_PER_PAGE: i64 = 100;
+
+    #[doc(hidden)]
+    pub fn new(rows: Vec<T>, page: i64, per_page: i64, total_rows: i64) -> Self {
+        Self {
+            rows,
+            page,
+            per_page,
+            total_rows,
+            total_pages: (total_rows + per_page - 1) / per_page,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn clamp(page: i64, per_page: i64) -> (i64, i64) {
+        (page.max(1), per_page.clamp(1, Self::MAX_PER_PAGE))
+    }
+
+    // Offset of the first record of the clamped page, unless it overflows
+    #[doc(hidden)]
+    pub fn offset(page: i64, per_page: i64) -> Option<i64> {
+        (page - 1).checked_mul(per_page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(Page::<()>::clamp(0, 0), (1, 1));
+        assert_eq!(Page::<()>::clamp(2, 1000), (2, 100));
+        assert_eq!(Page::<()>::clamp(3, 10), (3, 10));
+    }
+
+    #[test]
+    fn test_offset() {
+        assert_eq!(Page::<()>::offset(1, 10), Some(0));
+        assert_eq!(Page::<()>::offset(3, 10), Some(20));
+        assert_eq!(Page::<()>::offset(i64::MAX, 100), None);
+    }
+
+    #[test]
+    fn test_total_pages() {
+        assert_eq!(Page::<()>::new(vec![], 1, 10, 0).total_pages, 0);
+        assert_eq!(Page::<()>::new(vec![], 1, 10, 10).total_pages, 1);
+        assert_eq!(Page::<()>::new(vec![], 1, 10, 25).total_pages, 3);
+    }
+}
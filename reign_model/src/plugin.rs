@@ -1,6 +1,12 @@
-use crate::connection::Database;
+use crate::{connection::Database, with_request_id, Error};
 
-use reign_plugin::{reign_router::futures::FutureExt, Plugin};
+use reign_plugin::{
+    reign_router::{
+        futures::FutureExt, hyper::StatusCode, Chain, Error as RouterError, HandleFuture,
+        Middleware, Request,
+    },
+    Plugin,
+};
 
 use std::{future::Future, pin::Pin};
 
@@ -12,3 +18,43 @@ impl Plugin for Database {
         .boxed()
     }
 }
+
+impl From<Error> for RouterError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::NotFound => RouterError::Status(StatusCode::NOT_FOUND),
+            err => RouterError::Other(err.into()),
+        }
+    }
+}
+
+/// Attaches the request id to the logs of the queries made while handling a request.
+///
+/// The id is read from the `x-request-id` header of the request.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{model::QueryLog, router::Router};
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("common").add(QueryLog::default());
+/// }
+/// ```
+#[derive(Default)]
+pub struct QueryLog {}
+
+impl Middleware for QueryLog {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        let id = req
+            .headers()
+            .get("x-request-id")
+            .and_then(|x| x.to_str().ok())
+            .map(|x| x.to_string());
+
+        match id {
+            Some(id) => with_request_id(id, chain.run(req)).boxed(),
+            None => chain.run(req),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{log_query, Database, Error};
+
+use diesel::{
+    debug_query,
+    deserialize::QueryableByName,
+    query_builder::{BoxedSqlQuery, SqlQuery},
+    serialize::ToSql,
+    sql_query,
+    sql_types::HasSqlType,
+    RunQueryDsl,
+};
+
+#[cfg(feature = "model-postgres")]
+use diesel::pg::Pg as Backend;
+
+/// Raw SQL query with parameters bound by the database.
+///
+/// Used for queries that can't be expressed by the generated query builders. Parameters
+/// are written as `$1`, `$2`, etc. and are never interpolated into the SQL string.
+///
+/// # Examples
+///
+/// ```ignore
+/// use reign::model::{diesel::sql_types::Text, RawQuery};
+///
+/// let users = User::find_by_sql(
+///     RawQuery::new("SELECT * FROM users WHERE lower(name) = lower($1)").bind::<Text, _>("John"),
+/// )
+/// .await?;
+/// ```
+pub struct RawQuery {
+    inner: BoxedSqlQuery<'static, Backend, SqlQuery>,
+}
+
+impl RawQuery {
+    pub fn new<S>(sql: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            inner: sql_query(sql).into_boxed(),
+        }
+    }
+
+    /// Binds the next parameter with the given SQL type.
+    pub fn bind<ST, V>(mut self, value: V) -> Self
+    where
+        Backend: HasSqlType<ST>,
+        V: ToSql<ST, Backend> + Send + 'static,
+        ST: Send + 'static,
+    {
+        self.inner = self.inner.bind::<ST, V>(value);
+        self
+    }
+}
+
+impl Database {
+    /// Runs the given raw query and loads the returned rows.
+    pub async fn query<T>(query: RawQuery) -> Result<Vec<T>, Error>
+    where
+        T: QueryableByName<Backend> + Send + 'static,
+    {
+        let query = query.inner;
+
+        log_query(debug_query::<Backend, _>(&query));
+
+        Database::run(move |conn| query.load::<T>(conn)).await
+    }
+}
@@ -0,0 +1,72 @@
+use log::debug;
+
+use std::{fmt::Display, future::Future};
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Runs the given future with a request id attached to the queries it makes.
+///
+/// The id is prefixed to the query logs so that the queries can be traced back to the
+/// request that made them.
+///
+/// # Examples
+///
+/// ```ignore
+/// use reign::model::with_request_id;
+///
+/// let users = with_request_id("8f14e45f".to_string(), User::all()).await?;
+/// ```
+pub async fn with_request_id<F>(id: String, f: F) -> F::Output
+where
+    F: Future,
+{
+    REQUEST_ID.scope(id, f).await
+}
+
+/// Returns the request id of the current task if one was attached using [`with_request_id`].
+pub fn request_id() -> Option<String> {
+    REQUEST_ID.try_with(|x| x.clone()).ok()
+}
+
+// Runs the given closure on a blocking thread with the request id of the current task
+pub(crate) async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let id = request_id();
+
+    let handle = tokio::task::spawn_blocking(move || match id {
+        Some(id) => REQUEST_ID.sync_scope(id, f),
+        None => f(),
+    });
+
+    match handle.await {
+        Ok(result) => result,
+        Err(err) => std::panic::resume_unwind(err.into_panic()),
+    }
+}
+
+#[doc(hidden)]
+pub fn log_query<Q: Display>(query: Q) {
+    match request_id() {
+        Some(id) => debug!(target: "reign_model", "[{}] {}", id, query),
+        None => debug!(target: "reign_model", "{}", query),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_request_id() {
+        assert_eq!(request_id(), None);
+
+        let id = with_request_id("abc".to_string(), async { request_id() }).await;
+
+        assert_eq!(id, Some("abc".to_string()));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{request_id, Error};
+
+use log::warn;
+use tokio::time::sleep;
+
+use std::{future::Future, time::Duration};
+
+/// Retries a query when it fails with a transient error.
+///
+/// The time waited between the attempts doubles after every failure.
+///
+/// # Examples
+///
+/// ```ignore
+/// use reign::model::Retry;
+/// use std::time::Duration;
+///
+/// let users = Retry::new(3)
+///     .backoff(Duration::from_millis(100))
+///     .run(|| User::all())
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Retry {
+    retries: u32,
+    backoff: Duration,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self {
+            retries: 3,
+            backoff: Duration::from_millis(50),
+        }
+    }
+}
+
+impl Retry {
+    /// Retry the query at most the given number of times.
+    pub fn new(retries: u32) -> Self {
+        Self {
+            retries,
+            ..Default::default()
+        }
+    }
+
+    /// Time to wait before the first retry.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Run the query returned by the given closure, retrying it on transient errors.
+    pub async fn run<F, Fut, T>(&self, mut f: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempt = 0;
+        let mut backoff = self.backoff;
+
+        loop {
+            match f().await {
+                Err(err) if attempt < self.retries && err.is_transient() => {
+                    attempt += 1;
+
+                    match request_id() {
+                        Some(id) => warn!(
+                            "[{}] Retrying query ({}/{}): {}",
+                            id, attempt, self.retries, err
+                        ),
+                        None => warn!("Retrying query ({}/{}): {}", attempt, self.retries, err),
+                    }
+
+                    sleep(backoff).await;
+                    backoff *= 2;
+                }
+                result => return result,
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// Type of a column that is filled automatically by models with `timestamps`.
+pub trait Timestamp {
+    /// Returns the current time.
+    fn now() -> Self;
+}
+
+impl Timestamp for NaiveDateTime {
+    fn now() -> Self {
+        Utc::now().naive_utc()
+    }
+}
+
+impl Timestamp for DateTime<Utc> {
+    fn now() -> Self {
+        Utc::now()
+    }
+}
+
+impl<T> Timestamp for Option<T>
+where
+    T: Timestamp,
+{
+    fn now() -> Self {
+        Some(T::now())
+    }
+}
@@ -0,0 +1,129 @@
+use std::{collections::BTreeMap, error::Error, fmt};
+
+/// Errors returned by the `validate` method of models, mapping field names to messages.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationErrors(BTreeMap<String, Vec<String>>);
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error message for the given field.
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.0
+            .entry(field.to_string())
+            .or_default()
+            .push(message.into());
+    }
+
+    /// Returns the error messages of the given field.
+    pub fn get(&self, field: &str) -> Option<&[String]> {
+        self.0.get(field).map(|x| x.as_slice())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Vec<String>)> {
+        self.0.iter()
+    }
+
+    #[doc(hidden)]
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+
+        for (field, messages) in &self.0 {
+            for message in messages {
+                if !first {
+                    f.write_str(", ")?;
+                }
+
+                write!(f, "{} {}", field, message)?;
+                first = false;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ValidationErrors {}
+
+/// Type of a field that can be validated by models.
+///
+/// Validations other than `presence` are skipped when the value is missing.
+pub trait Validatable {
+    /// Returns the value as a string if it is present.
+    fn as_validatable(&self) -> Option<&str>;
+}
+
+impl Validatable for String {
+    fn as_validatable(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl Validatable for &str {
+    fn as_validatable(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T> Validatable for Option<T>
+where
+    T: Validatable,
+{
+    fn as_validatable(&self) -> Option<&str> {
+        self.as_ref().and_then(|x| x.as_validatable())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let mut errors = ValidationErrors::new();
+
+        assert!(errors.clone().into_result().is_ok());
+
+        errors.add("name", "can't be blank");
+        errors.add("name", "is invalid");
+        errors.add("email", "is invalid");
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors.get("name"),
+            Some(&["can't be blank".to_string(), "is invalid".to_string()][..])
+        );
+        assert_eq!(
+            errors.to_string(),
+            "email is invalid, name can't be blank, name is invalid"
+        );
+        assert!(errors.into_result().is_err());
+    }
+
+    #[test]
+    fn test_validatable() {
+        assert_eq!("foo".to_string().as_validatable(), Some("foo"));
+        assert_eq!(Some("foo").as_validatable(), Some("foo"));
+        assert_eq!(None::<String>.as_validatable(), None);
+    }
+}
@@ -5,4 +5,4 @@ pub use reign_router;
 
 mod plugin;
 
-pub use plugin::Plugin;
+pub use plugin::{prepend, Plugin, RouterFn};
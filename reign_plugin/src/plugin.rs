@@ -2,12 +2,74 @@ use reign_router::{futures::FutureExt, Router};
 
 use std::{future::Future, pin::Pin};
 
+/// Router function that is passed through the plugins before being served.
+pub type RouterFn = Box<dyn FnOnce(&mut Router)>;
+
+/// Plugin that can initialize resources and contribute to the router of a Reign app.
+///
+/// Plugins are applied in the order they are added to the app. The router function given
+/// to [`Plugin::router`] contains the user's routes along with any changes made by the
+/// plugins added before this one. The returned router function replaces it.
+///
+/// Since the first matching route wins, routes defined by a plugin before calling the given
+/// router function take precedence over the user's routes while routes defined after it
+/// act as fallbacks. Use [`prepend`] for the common case of adding routes in front.
+///
+/// # Examples
+///
+/// ```
+/// use reign_plugin::{
+///     prepend,
+///     reign_router::{Error, Request, Response, Router},
+///     Plugin, RouterFn,
+/// };
+///
+/// async fn health(_: &mut Request) -> Result<impl Response, Error> {
+///     Ok("ok")
+/// }
+///
+/// struct HealthPlugin;
+///
+/// impl Plugin for HealthPlugin {
+///     fn router(&self, f: RouterFn) -> RouterFn {
+///         prepend(f, |r| {
+///             r.get("health", health);
+///         })
+///     }
+/// }
+/// ```
 pub trait Plugin {
+    /// Initialize the resources needed by the plugin before the server starts.
     fn init<'a>(&'a self) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         async move {}.boxed()
     }
 
-    fn router(&self, f: Box<dyn FnOnce(&mut Router)>) -> Box<dyn FnOnce(&mut Router)> {
+    /// Release the resources held by the plugin after the server is gracefully shut down.
+    ///
+    /// This is only called when the app is served with a shutdown signal, in the reverse
+    /// order of how the plugins were added.
+    fn shutdown<'a>(&'a self) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        async move {}.boxed()
+    }
+
+    /// Wrap the router function to add routes, scopes or pipes to the app.
+    ///
+    /// The default implementation returns the given router function untouched.
+    fn router(&self, f: RouterFn) -> RouterFn {
         f
     }
 }
+
+/// Define the given routes before the ones from the router function.
+///
+/// The routes defined in `routes` will take precedence over the routes defined
+/// in the router function.
+pub fn prepend<R>(f: RouterFn, routes: R) -> RouterFn
+where
+    R: FnOnce(&mut Router) + 'static,
+{
+    Box::new(|r| {
+        routes(r);
+        f(r);
+    })
+}
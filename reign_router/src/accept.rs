@@ -0,0 +1,124 @@
+use mime::{Mime, STAR};
+
+// Media range of the `Accept` header along with its quality
+struct MediaRange {
+    mime: Mime,
+    quality: f32,
+}
+
+impl MediaRange {
+    fn matches(&self, mime: &Mime) -> bool {
+        (self.mime.type_() == STAR || self.mime.type_() == mime.type_())
+            && (self.mime.subtype() == STAR || self.mime.subtype() == mime.subtype())
+    }
+
+    // More specific ranges take precedence when multiple of them match
+    fn specificity(&self) -> u8 {
+        match (self.mime.type_() == STAR, self.mime.subtype() == STAR) {
+            (true, _) => 0,
+            (false, true) => 1,
+            (false, false) => 2,
+        }
+    }
+}
+
+fn parse(header: &str) -> Vec<MediaRange> {
+    header
+        .split(',')
+        .filter_map(|x| x.trim().parse::<Mime>().ok())
+        .map(|mime| {
+            let quality = mime
+                .get_param("q")
+                .and_then(|q| q.as_str().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            MediaRange { mime, quality }
+        })
+        .collect()
+}
+
+/// Returns the offered mime with the highest quality in the given `Accept` header, preferring
+/// the ones offered earlier for the same quality.
+pub(crate) fn preferred(header: Option<&str>, offered: &[&str]) -> Option<Mime> {
+    let offered = offered.iter().filter_map(|x| x.parse::<Mime>().ok());
+
+    let ranges = match header.map(parse) {
+        Some(ranges) if !ranges.is_empty() => ranges,
+        // Anything is accepted when the header is missing
+        _ => return offered.into_iter().next(),
+    };
+
+    let mut best: Option<(Mime, f32)> = None;
+
+    for mime in offered {
+        let quality = ranges
+            .iter()
+            .filter(|x| x.matches(&mime))
+            .max_by_key(|x| x.specificity())
+            .map_or(0.0, |x| x.quality);
+
+        if quality > 0.0 && best.as_ref().map_or(true, |x| quality > x.1) {
+            best = Some((mime, quality));
+        }
+    }
+
+    best.map(|x| x.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::preferred;
+
+    #[test]
+    fn test_preferred_quality() {
+        let header = Some("text/html;q=0.8, application/json");
+
+        assert_eq!(
+            preferred(header, &["text/html", "application/json"]).unwrap(),
+            "application/json"
+        );
+    }
+
+    #[test]
+    fn test_preferred_wildcard() {
+        let header = Some("text/*;q=0.5, */*;q=0.1");
+
+        assert_eq!(
+            preferred(header, &["application/json", "text/plain"]).unwrap(),
+            "text/plain"
+        );
+        assert_eq!(
+            preferred(header, &["application/json"]).unwrap(),
+            "application/json"
+        );
+    }
+
+    #[test]
+    fn test_preferred_specific() {
+        let header = Some("text/*, text/html;q=0");
+
+        assert!(preferred(header, &["text/html"]).is_none());
+        assert_eq!(
+            preferred(header, &["text/html", "text/plain"]).unwrap(),
+            "text/plain"
+        );
+    }
+
+    #[test]
+    fn test_preferred_tie() {
+        let header = Some("application/json, text/html");
+
+        assert_eq!(
+            preferred(header, &["text/html", "application/json"]).unwrap(),
+            "text/html"
+        );
+    }
+
+    #[test]
+    fn test_preferred_empty() {
+        assert_eq!(preferred(None, &["text/html"]).unwrap(), "text/html");
+        assert_eq!(preferred(Some(""), &["text/html"]).unwrap(), "text/html");
+        assert!(preferred(Some("application/json"), &["text/html"]).is_none());
+        assert!(preferred(None, &[]).is_none());
+    }
+}
@@ -0,0 +1,80 @@
+//! Contains helpers to build and combine route constraints.
+//!
+//! Every helper returns a closure that can be given wherever a constraint is accepted, such as
+//! [`any_with_constraint`](crate::Router::any_with_constraint), [`Route::constraint`](crate::Route::constraint),
+//! [`Scope::constraint`](crate::Scope::constraint) or [`Pipe::constraint`](crate::Pipe::constraint).
+//!
+//! # Examples
+//!
+//! ```
+//! use reign::{
+//!     prelude::*,
+//!     router::{
+//!         constraints::{and, header_equals, not, query_present},
+//!         hyper::Method,
+//!         Router,
+//!     },
+//! };
+//! #
+//! # async fn debug(req: &mut Request) -> Result<impl Response, Error> { Ok("debug") }
+//!
+//! fn router(r: &mut Router) {
+//!     r.any_with_constraint(
+//!         &[Method::GET],
+//!         "debug",
+//!         and(not(header_equals("x-env", "prod")), query_present("debug")),
+//!         debug,
+//!     );
+//! }
+//! ```
+
+use crate::Request;
+
+/// Combines two constraints so that both of them need to return true.
+pub fn and<A, B>(a: A, b: B) -> impl Fn(&Request) -> bool + Send + Sync + 'static
+where
+    A: Fn(&Request) -> bool + Send + Sync + 'static,
+    B: Fn(&Request) -> bool + Send + Sync + 'static,
+{
+    move |req| a(req) && b(req)
+}
+
+/// Combines two constraints so that either of them needs to return true.
+pub fn or<A, B>(a: A, b: B) -> impl Fn(&Request) -> bool + Send + Sync + 'static
+where
+    A: Fn(&Request) -> bool + Send + Sync + 'static,
+    B: Fn(&Request) -> bool + Send + Sync + 'static,
+{
+    move |req| a(req) || b(req)
+}
+
+/// Inverts the given constraint.
+pub fn not<C>(constraint: C) -> impl Fn(&Request) -> bool + Send + Sync + 'static
+where
+    C: Fn(&Request) -> bool + Send + Sync + 'static,
+{
+    move |req| !constraint(req)
+}
+
+/// Matches when the request has the given header with exactly the given value.
+pub fn header_equals(
+    name: &'static str,
+    value: &'static str,
+) -> impl Fn(&Request) -> bool + Send + Sync + 'static {
+    move |req| {
+        req.headers()
+            .get_all(name)
+            .iter()
+            .any(|x| x.as_bytes() == value.as_bytes())
+    }
+}
+
+/// Matches when the request has the given query parameter, irrespective of its value.
+pub fn query_present(name: &'static str) -> impl Fn(&Request) -> bool + Send + Sync + 'static {
+    move |req| req.query(name).is_some()
+}
+
+/// Matches when the request URI contains an explicit port.
+pub fn has_port() -> impl Fn(&Request) -> bool + Send + Sync + 'static {
+    |req| req.uri().port().is_some()
+}
@@ -9,7 +9,7 @@ use crate::{
 use thiserror::Error;
 use tokio::io::Error as TokioIoError;
 
-use std::str::Utf8Error;
+use std::{error::Error as StdError, io::Error as IoError, str::Utf8Error};
 
 /// Used in [`enum@Error`] when trying to access params from [`Request`](crate::Request).
 #[derive(Error, Debug)]
@@ -22,14 +22,107 @@ pub enum ParamError {
     UnableToConvertParam(String),
 }
 
+/// Used in [`enum@Error`] when the request body can't be extracted from [`Request`](crate::Request).
+#[derive(Error, Debug)]
+pub enum BodyError {
+    #[error("request body is larger than the limit of {0} bytes")]
+    TooLarge(u64),
+    #[error("request content-type is not `{0}`")]
+    UnsupportedContentType(&'static str),
+    #[cfg(feature = "json")]
+    #[error("invalid json request body: {0}")]
+    Json(#[source] serde_json::Error),
+}
+
+/// Used in [`enum@Error`] when building the url of a named route.
+#[derive(Error, Debug)]
+pub enum UrlError {
+    #[error("route with name `{0}` not found")]
+    RouteNotFound(String),
+    #[error("required param `{0}` not given")]
+    MissingParam(String),
+    #[error("param `{0}` does not match the path")]
+    InvalidParam(String),
+}
+
+/// Returned by [`serve`](crate::serve) when the server fails to start or stops unexpectedly.
+///
+/// Distinguishes the failures so that startup code can react differently, like retrying
+/// when the address is in use.
+#[derive(Error, Debug)]
+pub enum ServeError {
+    #[error("invalid socket address: {0}")]
+    Addr(#[source] IoError),
+    #[error("unable to bind to the socket address: {0}")]
+    Bind(#[source] HyperError),
+    #[error("io error while serving: {0}")]
+    Io(#[source] HyperError),
+    #[error("protocol error while serving: {0}")]
+    Protocol(#[source] HyperError),
+    #[cfg(unix)]
+    #[error("unable to bind to the unix socket `{0}`: {1}")]
+    Unix(std::path::PathBuf, #[source] IoError),
+    #[cfg(feature = "tls")]
+    #[error("unable to configure tls: {0}")]
+    Tls(#[source] TlsError),
+}
+
+/// Used in [`ServeError`] when the certificate or the private key given to
+/// [`serve_tls`](crate::serve_tls) can't be loaded.
+#[cfg(feature = "tls")]
+#[derive(Error, Debug)]
+pub enum TlsError {
+    #[error("unable to read `{0}`: {1}")]
+    Read(std::path::PathBuf, #[source] IoError),
+    #[error("invalid pem: {0}")]
+    InvalidPem(#[source] IoError),
+    #[error("no certificate found")]
+    MissingCert,
+    #[error("no private key found")]
+    MissingKey,
+    #[error("private key does not match the certificate")]
+    KeyMismatch,
+    #[error("invalid certificate or private key: {0}")]
+    Rustls(#[from] tokio_rustls::rustls::Error),
+}
+
+impl ServeError {
+    pub(crate) fn serving(err: HyperError) -> Self {
+        if err.source().map_or(false, |x| x.is::<IoError>()) {
+            Self::Io(err)
+        } else {
+            Self::Protocol(err)
+        }
+    }
+}
+
 /// Main error that can be used by endpoint handlers.
 ///
 /// Implements [`Response`] so that this can be converted into a valid server response.
+///
+/// The following errors can be converted into this using `?` and respond with:
+///
+/// | Error | Status |
+/// |-------|--------|
+/// | [`ParamError`], [`tokio::io::Error`] | `404 Not Found` |
+/// | [`hyper::Error`], [`std::str::Utf8Error`] | `400 Bad Request` |
+/// | `serde::de::value::Error` (with `form` feature) | `400 Bad Request` |
+/// | `BodyError::Json` (with `json` feature) | `400 Bad Request` |
+/// | [`BodyError::TooLarge`] | `413 Payload Too Large` |
+/// | [`BodyError::UnsupportedContentType`] | `415 Unsupported Media Type` |
+/// | [`UrlError`], [`hyper::http::Error`], [`hyper::header::ToStrError`], [`anyhow::Error`] | `500 Internal Server Error` |
+/// | `serde_json::Error` (with `json` feature) | `500 Internal Server Error` |
+///
+/// Use [`Error::Status`] to respond with any other status code.
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(transparent)]
     Param(#[from] ParamError),
     #[error(transparent)]
+    Body(#[from] BodyError),
+    #[error(transparent)]
+    Url(#[from] UrlError),
+    #[error(transparent)]
     TokioIo(#[from] TokioIoError),
     #[error(transparent)]
     Utf8(#[from] Utf8Error),
@@ -39,25 +132,49 @@ pub enum Error {
     Http(#[from] HttpError),
     #[error(transparent)]
     HeaderStr(#[from] HttpToStrError),
+    #[cfg(feature = "json")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[cfg(feature = "form")]
+    #[error(transparent)]
+    Form(#[from] serde::de::value::Error),
     #[error("status {0}")]
     Status(StatusCode),
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
 
-impl Response for Error {
-    fn respond(self) -> Result<HyperResponse<Body>, HttpError> {
+impl Error {
+    /// Status of the response this error is converted into.
+    pub fn status(&self) -> StatusCode {
         match self {
-            Self::Param(_) | Self::TokioIo(_) => HyperResponse::builder()
-                .status(StatusCode::NOT_FOUND)
-                .body(Body::empty()),
-            Self::Hyper(_) | Self::Utf8(_) => HyperResponse::builder()
-                .status(StatusCode::BAD_REQUEST)
-                .body(Body::empty()),
-            Self::Status(code) => HyperResponse::builder().status(code).body(Body::empty()),
-            _ => HyperResponse::builder()
-                .status(StatusCode::INTERNAL_SERVER_ERROR)
-                .body(Body::empty()),
+            Self::Param(_) | Self::TokioIo(_) => StatusCode::NOT_FOUND,
+            // Body read through `BodyLimit` fails with this error as the source
+            Self::Hyper(err)
+                if err
+                    .source()
+                    .and_then(|x| x.downcast_ref::<BodyError>())
+                    .is_some() =>
+            {
+                StatusCode::PAYLOAD_TOO_LARGE
+            }
+            Self::Hyper(_) | Self::Utf8(_) => StatusCode::BAD_REQUEST,
+            #[cfg(feature = "json")]
+            Self::Body(BodyError::Json(_)) => StatusCode::BAD_REQUEST,
+            #[cfg(feature = "form")]
+            Self::Form(_) => StatusCode::BAD_REQUEST,
+            Self::Body(BodyError::TooLarge(_)) => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::Body(BodyError::UnsupportedContentType(_)) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Status(code) => *code,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
+
+impl Response for Error {
+    fn respond(self) -> Result<HyperResponse<Body>, HttpError> {
+        HyperResponse::builder()
+            .status(self.status())
+            .body(Body::empty())
+    }
+}
@@ -1,4 +1,21 @@
-use crate::{hyper::StatusCode, Error};
+use crate::{
+    hyper::{
+        header::{HeaderName, HeaderValue},
+        http::Error as HttpError,
+        Body, Response as HyperResponse, StatusCode,
+    },
+    Error,
+};
+
+#[cfg(feature = "cookie")]
+use crate::{
+    hyper::header::SET_COOKIE,
+    middleware::cookie::{encode_value, Cookie},
+};
+
+#[cfg(feature = "cookie")]
+use std::borrow::Cow;
+use std::convert::TryFrom;
 
 /// Extension trait for [`Option<T>`] containing methods for ease of use in handles.
 pub trait OptionExt {
@@ -32,3 +49,86 @@ impl<T> OptionExt for Option<T> {
         self.ok_or_else(|| Error::Status(StatusCode::NOT_FOUND))
     }
 }
+
+/// Extension trait for [`hyper::Response`] containing methods for adding headers to the
+/// responses returned by the [`helpers`](crate::helpers).
+pub trait ResponseExt: Sized {
+    fn header<K, V>(self, key: K, value: V) -> Result<Self, HttpError>
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HttpError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HttpError>;
+
+    #[cfg(feature = "cookie")]
+    fn cookie(self, cookie: Cookie<'_>) -> Self;
+}
+
+impl ResponseExt for HyperResponse<Body> {
+    /// Appends the given header to the response. Existing values of the header are kept,
+    /// which means headers like `Set-Cookie` can be added more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn login(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(redirect("/dashboard")?
+    ///         .header("set-cookie", "user=reign; HttpOnly")?
+    ///         .header("set-cookie", "theme=dark")?)
+    /// }
+    /// ```
+    fn header<K, V>(mut self, key: K, value: V) -> Result<Self, HttpError>
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HttpError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HttpError>,
+    {
+        let key = HeaderName::try_from(key).map_err(Into::into)?;
+        let value = HeaderValue::try_from(value).map_err(Into::into)?;
+
+        self.headers_mut().append(key, value);
+        Ok(self)
+    }
+
+    /// Adds a `Set-Cookie` header with the given cookie to the response. Characters which are
+    /// not allowed in the value of the cookie are percent-encoded, which is decoded by
+    /// [`Request::cookie`](crate::Request::cookie) and the cookie middleware. A `%` is kept
+    /// as is, so values which are already encoded are not encoded twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::{
+    ///     prelude::*,
+    ///     router::middleware::cookie::{Cookie, SameSite},
+    /// };
+    ///
+    /// async fn login(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(redirect("/dashboard")?
+    ///         .cookie(Cookie::new("user", "reign"))
+    ///         .cookie(
+    ///             Cookie::build("theme", "dark")
+    ///                 .path("/")
+    ///                 .http_only(true)
+    ///                 .secure(true)
+    ///                 .same_site(SameSite::Lax)
+    ///                 .finish(),
+    ///         ))
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    fn cookie(mut self, mut cookie: Cookie<'_>) -> Self {
+        if let Cow::Owned(value) = encode_value(cookie.value()) {
+            cookie.set_value(value);
+        }
+
+        if let Ok(value) = HeaderValue::try_from(cookie.to_string()) {
+            self.headers_mut().append(SET_COOKIE, value);
+        }
+
+        self
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    futures::FutureExt,
+    hyper::{body::Bytes, HeaderMap, Method, Uri, Version},
+    Error, Request, RequestParts,
+};
+
+#[cfg(feature = "json")]
+use serde::de::DeserializeOwned;
+
+use std::{future::Future, net::SocketAddr, pin::Pin, str::from_utf8};
+
+/// Return type of [`FromRequest::from_request`].
+pub type FromRequestFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send + 'a>>;
+
+/// Types that can be extracted from a [`Request`].
+///
+/// Implement this for your own types (current user, pagination, validated body, etc..)
+/// and retrieve them in handlers with [`Request::extract`].
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::{FromRequest, FromRequestFuture}};
+///
+/// struct Page(u32);
+///
+/// impl FromRequest for Page {
+///     fn from_request<'a>(req: &'a mut Request) -> FromRequestFuture<'a, Self> {
+///         let page = req.query("page").and_then(|p| p.parse().ok()).unwrap_or(1);
+///
+///         Box::pin(async move { Ok(Page(page)) })
+///     }
+/// }
+///
+/// async fn list(req: &mut Request) -> Result<impl Response, Error> {
+///     let page = req.extract::<Page>().await?;
+///
+///     Ok(format!("page {}", page.0))
+/// }
+/// ```
+pub trait FromRequest: Sized {
+    fn from_request<'a>(req: &'a mut Request) -> FromRequestFuture<'a, Self>;
+}
+
+impl<T> FromRequest for Option<T>
+where
+    T: FromRequest + Send + 'static,
+{
+    fn from_request<'a>(req: &'a mut Request) -> FromRequestFuture<'a, Self> {
+        async move { Ok(T::from_request(req).await.ok()) }.boxed()
+    }
+}
+
+macro_rules! from_parts {
+    ($ty:ty, $method:ident) => {
+        impl FromRequest for $ty {
+            fn from_request<'a>(req: &'a mut Request) -> FromRequestFuture<'a, Self> {
+                let value = req.$method().clone();
+
+                async move { Ok(value) }.boxed()
+            }
+        }
+    };
+}
+
+from_parts!(Method, method);
+from_parts!(Uri, uri);
+from_parts!(Version, version);
+from_parts!(HeaderMap, headers);
+from_parts!(SocketAddr, ip);
+
+impl FromRequest for RequestParts {
+    fn from_request<'a>(req: &'a mut Request) -> FromRequestFuture<'a, Self> {
+        let value = req.to_parts();
+
+        async move { Ok(value) }.boxed()
+    }
+}
+
+/// Consumes the request body, which is empty if it was already consumed. The body is
+/// read using [`Request::body_bytes`].
+impl FromRequest for Bytes {
+    fn from_request<'a>(req: &'a mut Request) -> FromRequestFuture<'a, Self> {
+        async move { req.body_bytes().await }.boxed()
+    }
+}
+
+/// Consumes the request body, which is empty if it was already consumed.
+impl FromRequest for String {
+    fn from_request<'a>(req: &'a mut Request) -> FromRequestFuture<'a, Self> {
+        async move {
+            let bytes = Bytes::from_request(req).await?;
+
+            Ok(from_utf8(&bytes)?.to_string())
+        }
+        .boxed()
+    }
+}
+
+/// Deserializes the JSON request body.
+///
+/// The body is read with [`Request::json`], which responds with `415 Unsupported Media Type`
+/// if the request has a content-type which is not JSON and with `400 Bad Request` if the
+/// body is not valid JSON for the type.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::Json};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// #[action]
+/// async fn create(user: Json<User>) -> Result<impl Response, Error> {
+///     Ok(user.0.name)
+/// }
+/// ```
+#[cfg(feature = "json")]
+#[derive(Debug, Clone)]
+pub struct Json<T>(pub T);
+
+#[cfg(feature = "json")]
+impl<T> FromRequest for Json<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    fn from_request<'a>(req: &'a mut Request) -> FromRequestFuture<'a, Self> {
+        async move { Ok(Json(req.json().await?)) }.boxed()
+    }
+}
@@ -0,0 +1,236 @@
+use serde::{
+    de::{
+        value::{Error, MapDeserializer, SeqDeserializer},
+        DeserializeOwned, Deserializer, Error as _, IntoDeserializer, Visitor,
+    },
+    forward_to_deserialize_any,
+};
+use url::form_urlencoded::parse;
+
+use std::collections::{hash_map::Entry, HashMap};
+
+/// Deserializes `application/x-www-form-urlencoded` data into the given type.
+///
+/// Unlike `serde_urlencoded`, repeated keys are collected so that they can be deserialized
+/// into sequences, and checkbox values like `on` can be deserialized into booleans.
+///
+/// A key given more than once is deserialized into a sequence of all its values in the order
+/// they were given when the field is a sequence, and into its first value otherwise.
+pub(crate) fn from_urlencoded<T>(input: &[u8]) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let mut pairs: Vec<(String, Values)> = vec![];
+    let mut indices = HashMap::<_, usize>::new();
+
+    for (key, value) in parse(input) {
+        match indices.entry(key) {
+            Entry::Occupied(entry) => pairs[*entry.get()].1 .0.push(value.into_owned()),
+            Entry::Vacant(entry) => {
+                pairs.push((entry.key().to_string(), Values(vec![value.into_owned()])));
+                entry.insert(pairs.len() - 1);
+            }
+        }
+    }
+
+    T::deserialize(MapDeserializer::new(pairs.into_iter()))
+}
+
+// All the values of a key in the order they were given
+struct Values(Vec<String>);
+
+impl Values {
+    // Single values use the first one given, same as `Request::query`
+    fn first(self) -> String {
+        self.0.into_iter().next().unwrap_or_default()
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Values {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+macro_rules! deserialize_parse {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let value = self.first();
+
+                match value.parse() {
+                    Ok(v) => visitor.$visit(v),
+                    Err(_) => Err(Error::custom(format!("invalid value `{}`", value))),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Values {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.first())
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(SeqDeserializer::new(
+            self.0.into_iter().map(|x| Values(vec![x])),
+        ))
+    }
+
+    fn deserialize_tuple<V>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.0.iter().all(String::is_empty) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.first().as_str() {
+            "on" | "true" | "1" => visitor.visit_bool(true),
+            "off" | "false" | "0" | "" => visitor.visit_bool(false),
+            value => Err(Error::custom(format!("invalid boolean `{}`", value))),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(self.first().into_deserializer())
+    }
+
+    deserialize_parse! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Filter {
+        page: u32,
+        limit: Option<u32>,
+        #[serde(default)]
+        tag: Vec<String>,
+        #[serde(default)]
+        archived: bool,
+    }
+
+    #[test]
+    fn test_from_urlencoded() {
+        let filter =
+            from_urlencoded::<Filter>(b"page=2&tag=a&tag=b%20c&archived=on&limit=").unwrap();
+
+        assert_eq!(
+            filter,
+            Filter {
+                page: 2,
+                limit: None,
+                tag: vec!["a".into(), "b c".into()],
+                archived: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_urlencoded_single_seq() {
+        let filter = from_urlencoded::<Filter>(b"page=1&tag=a").unwrap();
+
+        assert_eq!(filter.tag, vec!["a"]);
+        assert!(!filter.archived);
+    }
+
+    #[test]
+    fn test_from_urlencoded_repeated() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Repeated {
+            name: String,
+            page: u32,
+            tag: Vec<String>,
+            pair: (u32, u32),
+        }
+
+        let repeated = from_urlencoded::<Repeated>(
+            b"name=a&tag=x&page=1&pair=1&name=b&tag=y&page=2&pair=2&tag=z",
+        )
+        .unwrap();
+
+        assert_eq!(
+            repeated,
+            Repeated {
+                name: "a".into(),
+                page: 1,
+                tag: vec!["x".into(), "y".into(), "z".into()],
+                pair: (1, 2),
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_urlencoded_invalid() {
+        assert!(from_urlencoded::<Filter>(b"page=one").is_err());
+        assert!(from_urlencoded::<Filter>(b"limit=1").is_err());
+    }
+}
@@ -0,0 +1,267 @@
+//! Contains handlers for common endpoints
+
+use crate::{
+    futures::FutureExt,
+    helpers::{etag, http_date, is_not_modified},
+    hyper::{
+        header::{
+            ACCEPT_RANGES, ALLOW, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG, LAST_MODIFIED,
+            RANGE,
+        },
+        Body, Method, Response as HyperResponse, StatusCode,
+    },
+    stream::reader_stream,
+    Error, HandleFuture, Request,
+};
+
+use log::debug;
+use mime_guess::from_path;
+use percent_encoding::percent_decode_str;
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt},
+};
+
+use std::{
+    fs::Metadata,
+    io::SeekFrom,
+    path::{Component, Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Serves the files in the given directory using the glob param named `path`.
+///
+/// The `Content-Type` header is guessed from the extension of the file, and the
+/// `Content-Length`, `Last-Modified` and `ETag` headers are sent along with it, and
+/// `304 Not Modified` is responded when the client already has the file. A single byte
+/// range requested with the `Range` header is responded with `206 Partial Content`, while
+/// multiple ranges are ignored and the whole file is sent. Requests
+/// for missing files, directories or paths trying to escape the directory are responded
+/// with `404 Not Found`, and requests with methods other than `GET` and `HEAD` with
+/// `405 Method Not Allowed`.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{handlers::static_files, hyper::Method, path as p, Router};
+///
+/// fn router(r: &mut Router) {
+///     r.any(&[Method::GET, Method::HEAD], p!("assets" / path*), static_files("./public"));
+/// }
+/// ```
+pub fn static_files<P>(root: P) -> impl Fn(&mut Request) -> HandleFuture + Send + Sync + 'static
+where
+    P: Into<PathBuf>,
+{
+    let root = root.into();
+
+    move |req: &mut Request| {
+        let root = root.clone();
+
+        async move {
+            if req.method() != Method::GET && req.method() != Method::HEAD {
+                return Ok(HyperResponse::builder()
+                    .status(StatusCode::METHOD_NOT_ALLOWED)
+                    .header(ALLOW, "GET, HEAD")
+                    .body(Body::empty())?);
+            }
+
+            let path = match resolve(&root, req.param_opt_glob::<String>("path")?) {
+                Some(path) => path,
+                None => return Err(Error::Status(StatusCode::NOT_FOUND)),
+            };
+
+            let file = match File::open(&path).await {
+                Ok(file) => file,
+                Err(err) => {
+                    debug!("Unable to open {:?}: {}", path, err);
+                    return Err(Error::Status(StatusCode::NOT_FOUND));
+                }
+            };
+
+            let metadata = file.metadata().await?;
+
+            if !metadata.is_file() {
+                return Err(Error::Status(StatusCode::NOT_FOUND));
+            }
+
+            let mut response = HyperResponse::builder().header(
+                CONTENT_TYPE,
+                from_path(&path).first_or_octet_stream().as_ref(),
+            );
+
+            let modified = modified(&metadata);
+            let etag = modified.map(|x| etag(metadata.len(), x));
+
+            if let Some(modified) = modified {
+                response = response.header(LAST_MODIFIED, http_date(modified));
+            }
+
+            if let Some(etag) = &etag {
+                response = response.header(ETAG, etag);
+            }
+
+            if is_not_modified(req, etag.as_deref(), modified) {
+                return Ok(response
+                    .status(StatusCode::NOT_MODIFIED)
+                    .body(Body::empty())?);
+            }
+
+            let len = metadata.len();
+            let range = req
+                .headers()
+                .get(RANGE)
+                .and_then(|x| x.to_str().ok())
+                .map_or(Ok(None), |x| parse_range(x, len));
+
+            response = response.header(ACCEPT_RANGES, "bytes");
+
+            let (start, end) = match range {
+                Ok(Some((start, end))) => {
+                    response = response
+                        .status(StatusCode::PARTIAL_CONTENT)
+                        .header(CONTENT_RANGE, format!("bytes {}-{}/{}", start, end, len));
+
+                    (start, end)
+                }
+                Ok(None) => (0, len.saturating_sub(1)),
+                Err(_) => {
+                    return Ok(response
+                        .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                        .header(CONTENT_RANGE, format!("bytes */{}", len))
+                        .body(Body::empty())?);
+                }
+            };
+
+            let size = if len == 0 { 0 } else { end - start + 1 };
+
+            response = response.header(CONTENT_LENGTH, size);
+
+            let body = if req.method() == Method::HEAD {
+                Body::empty()
+            } else {
+                let mut file = file;
+
+                if start > 0 {
+                    file.seek(SeekFrom::Start(start)).await?;
+                }
+
+                Body::wrap_stream(reader_stream(file.take(size)))
+            };
+
+            Ok(response.body(body)?)
+        }
+        .boxed()
+    }
+}
+
+// Parse the `Range` header into the first and last byte of the range. Returns `None` when
+// the whole file needs to be sent, and an error when the range is outside of the file.
+fn parse_range(header: &str, len: u64) -> Result<Option<(u64, u64)>, ()> {
+    let range = match header.trim().strip_prefix("bytes=") {
+        // Multiple ranges are not supported
+        Some(range) if !range.contains(',') => range.trim(),
+        _ => return Ok(None),
+    };
+
+    let (start, end) = match range.split_once('-') {
+        Some(x) => x,
+        None => return Ok(None),
+    };
+
+    if start.is_empty() {
+        // Suffix range with the last bytes of the file
+        return match end.parse::<u64>() {
+            Ok(0) => Err(()),
+            Ok(_) if len == 0 => Err(()),
+            Ok(suffix) => Ok(Some((len.saturating_sub(suffix), len - 1))),
+            Err(_) => Ok(None),
+        };
+    }
+
+    let start = match start.parse::<u64>() {
+        Ok(start) => start,
+        Err(_) => return Ok(None),
+    };
+
+    let end = if end.is_empty() {
+        u64::MAX
+    } else {
+        match end.parse::<u64>() {
+            Ok(end) if end >= start => end,
+            _ => return Ok(None),
+        }
+    };
+
+    if start >= len {
+        return Err(());
+    }
+
+    Ok(Some((start, end.min(len - 1))))
+}
+
+// Join the segments to the root, making sure that the path can't escape it
+fn resolve(root: &Path, segments: Option<Vec<String>>) -> Option<PathBuf> {
+    let mut path = root.to_path_buf();
+
+    for segment in segments.unwrap_or_default() {
+        let segment = percent_decode_str(&segment).decode_utf8().ok()?;
+
+        if segment.is_empty() {
+            continue;
+        }
+
+        // Decoded segments can contain separators or be `..`
+        let mut components = Path::new(segment.as_ref()).components();
+
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(x)), None) if !segment.contains('\\') => path.push(x),
+            _ => return None,
+        }
+    }
+
+    // Symbolic links in the directory can point outside of it
+    let path = path.canonicalize().ok()?;
+
+    if path.starts_with(root.canonicalize().ok()?) {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+fn modified(metadata: &Metadata) -> Option<SystemTime> {
+    metadata
+        .modified()
+        .ok()
+        .filter(|x| x.duration_since(UNIX_EPOCH).is_ok())
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_range;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("bytes=0-9", 100), Ok(Some((0, 9))));
+        assert_eq!(parse_range("bytes=90-", 100), Ok(Some((90, 99))));
+        assert_eq!(parse_range("bytes=90-200", 100), Ok(Some((90, 99))));
+        assert_eq!(parse_range("bytes=-10", 100), Ok(Some((90, 99))));
+        assert_eq!(parse_range("bytes=-200", 100), Ok(Some((0, 99))));
+    }
+
+    #[test]
+    fn test_parse_range_ignored() {
+        assert_eq!(parse_range("bytes=0-9,20-29", 100), Ok(None));
+        assert_eq!(parse_range("items=0-9", 100), Ok(None));
+        assert_eq!(parse_range("bytes=9-0", 100), Ok(None));
+        assert_eq!(parse_range("bytes=a-9", 100), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_range_unsatisfiable() {
+        assert_eq!(parse_range("bytes=100-", 100), Err(()));
+        assert_eq!(parse_range("bytes=-0", 100), Err(()));
+        assert_eq!(parse_range("bytes=-10", 0), Err(()));
+    }
+}
@@ -1,10 +1,26 @@
-use hyper::{header, http::Error as HttpError, Body, Response as HyperResponse, StatusCode};
+use crate::Request;
 
-use std::fmt::{write, Display};
+use hyper::{
+    header::{self, HeaderValue},
+    http::Error as HttpError,
+    Body, Response as HyperResponse, StatusCode,
+};
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "view")]
+use reign_view::AsyncRender;
+
+use std::{
+    fmt::{write, Display, Error as FmtError},
+    fs::metadata,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 /// Renders a view for [reign router](reign_router) endpoint handle
 ///
-/// The response is sent with content-type set as `text/html`.
+/// The response is sent with content-type set as `text/html; charset=utf-8`. Use
+/// [`render_as`] to send it with a different content-type.
 ///
 /// # Examples
 ///
@@ -62,10 +78,113 @@ use std::fmt::{write, Display};
 /// # });
 /// ```
 pub fn render<D: Display>(view: D, status: u16) -> Result<HyperResponse<Body>, HttpError> {
+    render_with_capacity(view, status, None, 0)
+}
+
+/// Renders a view with the given content-type for [reign router](reign_router) endpoint handle
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::{helpers::render_as, hyper::header::HeaderValue}};
+///
+/// async fn handle(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(render_as(
+///         "<h1>Hello Reign!</h1>",
+///         200,
+///         HeaderValue::from_static("text/html; charset=iso-8859-1"),
+///     )?)
+/// }
+/// ```
+pub fn render_as<D: Display>(
+    view: D,
+    status: u16,
+    content_type: HeaderValue,
+) -> Result<HyperResponse<Body>, HttpError> {
+    render_with_capacity(view, status, Some(content_type), 0)
+}
+
+/// Renders a view into a buffer that is pre-sized with the given capacity.
+///
+/// This is used by `render!` with the length of the static content of the view.
+#[doc(hidden)]
+pub fn render_with_capacity<D: Display>(
+    view: D,
+    status: u16,
+    content_type: Option<HeaderValue>,
+    capacity: usize,
+) -> Result<HyperResponse<Body>, HttpError> {
+    let mut content = String::with_capacity(capacity);
+    let result = write(&mut content, format_args!("{}", view));
+
+    respond_rendered(result.map(|_| content), status, content_type)
+}
+
+/// Renders an [`AsyncRender`] view for [reign router](reign_router) endpoint handle
+///
+/// This is the asynchronous variant of [`render`] for views whose template awaits futures.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::helpers::render_async, view::AsyncRender};
+/// use std::{fmt::{Result as FmtResult, Write}, future::Future, pin::Pin};
+///
+/// struct CustomView;
+///
+/// impl AsyncRender for CustomView {
+///     fn render<'b>(
+///         &'b self,
+///         buf: &'b mut String,
+///     ) -> Pin<Box<dyn Future<Output = FmtResult> + Send + 'b>> {
+///         Box::pin(async move { write!(buf, "<h1>Hello Reign!</h1>") })
+///     }
+/// }
+///
+/// async fn handle(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(render_async(CustomView, 200).await?)
+/// }
+/// ```
+#[cfg(feature = "view")]
+pub async fn render_async<V: AsyncRender>(
+    view: V,
+    status: u16,
+) -> Result<HyperResponse<Body>, HttpError> {
+    render_async_with(view, status, None).await
+}
+
+/// Renders an [`AsyncRender`] view with the given content-type for [reign router](reign_router)
+/// endpoint handle
+///
+/// This is the asynchronous variant of [`render_as`].
+#[cfg(feature = "view")]
+pub async fn render_async_as<V: AsyncRender>(
+    view: V,
+    status: u16,
+    content_type: HeaderValue,
+) -> Result<HyperResponse<Body>, HttpError> {
+    render_async_with(view, status, Some(content_type)).await
+}
+
+#[cfg(feature = "view")]
+async fn render_async_with<V: AsyncRender>(
+    view: V,
+    status: u16,
+    content_type: Option<HeaderValue>,
+) -> Result<HyperResponse<Body>, HttpError> {
     let mut content = String::new();
+    let result = view.render(&mut content).await;
+
+    respond_rendered(result.map(|_| content), status, content_type)
+}
 
-    match write(&mut content, format_args!("{}", view)) {
-        Ok(()) => {
+fn respond_rendered(
+    content: Result<String, FmtError>,
+    status: u16,
+    content_type: Option<HeaderValue>,
+) -> Result<HyperResponse<Body>, HttpError> {
+    match content {
+        Ok(content) => {
             let status = StatusCode::from_u16(status)?;
             let mut response = HyperResponse::builder()
                 .status(status)
@@ -73,7 +192,8 @@ pub fn render<D: Display>(view: D, status: u16) -> Result<HyperResponse<Body>, H
 
             response.headers_mut().insert(
                 header::CONTENT_TYPE,
-                mime::TEXT_HTML_UTF_8.as_ref().parse().unwrap(),
+                content_type
+                    .unwrap_or_else(|| HeaderValue::from_static("text/html; charset=utf-8")),
             );
 
             *response.body_mut() = content.into();
@@ -86,10 +206,130 @@ pub fn render<D: Display>(view: D, status: u16) -> Result<HyperResponse<Body>, H
     }
 }
 
+/// Sets the `Last-Modified` header of a rendered view from the modified time of its template.
+///
+/// This is used by `render!` in `hot-reload` mode where the templates are on the disk.
+#[doc(hidden)]
+pub fn template_modified<P: AsRef<Path>>(
+    mut response: HyperResponse<Body>,
+    path: P,
+) -> HyperResponse<Body> {
+    if let Ok(modified) = metadata(path).and_then(|m| m.modified()) {
+        if let Ok(value) = http_date(modified).parse() {
+            response.headers_mut().insert(header::LAST_MODIFIED, value);
+        }
+    }
+
+    response
+}
+
+// Format the time as needed by headers like `Last-Modified`
+pub(crate) fn http_date(time: SystemTime) -> String {
+    let time: DateTime<Utc> = time.into();
+
+    format!("{} GMT", time.format("%a, %d %b %Y %H:%M:%S"))
+}
+
+/// Computes a weak `ETag` from the length and the modified time of some content, like a file.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::helpers::etag;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let modified = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+///
+/// assert_eq!(etag(1024, modified), "W/\"400-5f5e1000.0\"");
+/// ```
+pub fn etag(len: u64, modified: SystemTime) -> String {
+    let since = modified.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+    format!(
+        "W/\"{:x}-{:x}.{:x}\"",
+        len,
+        since.as_secs(),
+        since.subsec_nanos()
+    )
+}
+
+/// Checks whether the client already has the content with the given `ETag` and modified time
+/// using the `If-None-Match` and `If-Modified-Since` headers of the request.
+///
+/// `If-Modified-Since` is ignored when the request has `If-None-Match`. Respond with
+/// [`not_modified`] when this returns true.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::helpers::{etag, is_not_modified, not_modified}};
+/// use std::time::SystemTime;
+///
+/// async fn handle(req: &mut Request) -> Result<impl Response, Error> {
+///     let modified = SystemTime::now();
+///     let tag = etag(5, modified);
+///
+///     if is_not_modified(req, Some(&tag), Some(modified)) {
+///         return Ok(not_modified()?);
+///     }
+///
+///     Ok("hello".respond()?)
+/// }
+/// ```
+pub fn is_not_modified(req: &Request, etag: Option<&str>, modified: Option<SystemTime>) -> bool {
+    if let Some(tags) = req
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+    {
+        // Weak comparison ignores the `W/` prefix
+        let weak = |x: &str| x.trim().trim_start_matches("W/").to_string();
+
+        return etag.map_or(false, |etag| {
+            tags.split(',')
+                .any(|x| x.trim() == "*" || weak(x) == weak(etag))
+        });
+    }
+
+    let since = req
+        .headers()
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| DateTime::parse_from_rfc2822(v).ok());
+
+    match (since, modified) {
+        (Some(since), Some(modified)) => {
+            let modified: DateTime<Utc> = modified.into();
+
+            // Header dates don't have sub second precision
+            modified.timestamp() <= since.timestamp()
+        }
+        _ => false,
+    }
+}
+
+/// Responds with `304 Not Modified` and an empty body.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{helpers::not_modified, hyper::StatusCode};
+///
+/// assert_eq!(not_modified().unwrap().status(), StatusCode::NOT_MODIFIED);
+/// ```
+pub fn not_modified() -> Result<HyperResponse<Body>, HttpError> {
+    HyperResponse::builder()
+        .status(StatusCode::NOT_MODIFIED)
+        .body(Body::empty())
+}
+
 /// Sends a redirect for [reign router](reign_router) endpoint
 /// handle
 ///
-/// The response is sent with status code `303` and `location` header.
+/// The response is sent with status code `302` and `location` header. Use
+/// [`redirect_see_other`] after handling a form so that the client follows it with `GET`.
+///
+/// Returns an error if the location is not a valid header value.
 ///
 /// # Examples
 ///
@@ -123,7 +363,7 @@ pub fn render<D: Display>(view: D, status: u16) -> Result<HyperResponse<Body>, H
 /// #           .await
 /// #           .unwrap();
 /// #
-/// #       assert_eq!(response.status(), reqwest::StatusCode::SEE_OTHER);
+/// #       assert_eq!(response.status(), reqwest::StatusCode::FOUND);
 /// #       assert!(response.headers().contains_key("location"));
 /// #       assert_eq!(
 /// #           response.headers()["location"],
@@ -138,16 +378,72 @@ pub fn render<D: Display>(view: D, status: u16) -> Result<HyperResponse<Body>, H
 /// #   }
 /// # });
 /// ```
-pub fn redirect<L: AsRef<str>>(location: L) -> Result<HyperResponse<Body>, HttpError> {
-    let mut response = HyperResponse::builder()
-        .status(StatusCode::SEE_OTHER)
-        .body(Body::empty())?;
+pub fn redirect<L: Into<String>>(location: L) -> Result<HyperResponse<Body>, HttpError> {
+    redirect_with(location, StatusCode::FOUND)
+}
 
-    response
-        .headers_mut()
-        .insert(header::LOCATION, location.as_ref().parse().unwrap());
+/// Sends a permanent redirect for [reign router](reign_router) endpoint handle
+///
+/// The response is sent with status code `308` and `location` header. The client
+/// keeps the method and body of the request when following it.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::helpers::redirect_permanent};
+///
+/// async fn handle(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(redirect_permanent("/v2/users")?)
+/// }
+/// ```
+pub fn redirect_permanent<L: Into<String>>(location: L) -> Result<HyperResponse<Body>, HttpError> {
+    redirect_with(location, StatusCode::PERMANENT_REDIRECT)
+}
+
+/// Sends a temporary redirect for [reign router](reign_router) endpoint handle
+///
+/// The response is sent with status code `307` and `location` header. The client
+/// keeps the method and body of the request when following it.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::helpers::redirect_temporary};
+///
+/// async fn handle(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(redirect_temporary("/maintenance")?)
+/// }
+/// ```
+pub fn redirect_temporary<L: Into<String>>(location: L) -> Result<HyperResponse<Body>, HttpError> {
+    redirect_with(location, StatusCode::TEMPORARY_REDIRECT)
+}
+
+/// Sends a see other redirect for [reign router](reign_router) endpoint handle
+///
+/// The response is sent with status code `303` and `location` header. The client
+/// follows it with a `GET` request, which makes it useful after handling a form.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::helpers::redirect_see_other};
+///
+/// async fn handle(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(redirect_see_other("/dashboard")?)
+/// }
+/// ```
+pub fn redirect_see_other<L: Into<String>>(location: L) -> Result<HyperResponse<Body>, HttpError> {
+    redirect_with(location, StatusCode::SEE_OTHER)
+}
 
-    Ok(response)
+fn redirect_with<L: Into<String>>(
+    location: L,
+    status: StatusCode,
+) -> Result<HyperResponse<Body>, HttpError> {
+    HyperResponse::builder()
+        .status(status)
+        .header(header::LOCATION, location.into())
+        .body(Body::empty())
 }
 
 /// Serializes and sends JSON for [reign router](reign_router)
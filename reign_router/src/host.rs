@@ -0,0 +1,117 @@
+use crate::{hyper::header::HOST, Request};
+
+use regex::{escape, Regex};
+
+use std::collections::HashMap as Map;
+
+/// Host pattern of a scope, which can capture labels of the host as params.
+#[derive(Debug, Clone)]
+pub(crate) struct Host {
+    regex: Regex,
+}
+
+impl Host {
+    pub(crate) fn new(pattern: &str) -> Self {
+        let pattern = pattern.trim_end_matches('.');
+
+        let labels = pattern
+            .split('.')
+            .map(|label| {
+                if label == "*" {
+                    "[^.]+".to_string()
+                } else if label.starts_with('{') && label.ends_with('}') {
+                    format!("(?P<{}>[^.]+)", &label[1..label.len() - 1])
+                } else {
+                    escape(label)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            regex: Regex::new(&format!("(?i)^{}$", labels.join(r"\.")))
+                .unwrap_or_else(|_| panic!("invalid host pattern `{}`", pattern)),
+        }
+    }
+
+    /// Returns the captured params if the host of the request matches.
+    pub(crate) fn captures(&self, req: &Request) -> Option<Map<String, String>> {
+        let host = req
+            .headers()
+            .get(HOST)
+            .and_then(|x| x.to_str().ok())
+            .or_else(|| req.uri().host())?;
+
+        // Drop the port, taking care of IPv6 addresses like `[::1]:8080`
+        let host = match host.rfind(':') {
+            Some(i) if !host[i..].contains(']') => &host[..i],
+            _ => host,
+        };
+
+        let captures = self.regex.captures(host.trim_end_matches('.'))?;
+
+        Some(
+            self.regex
+                .capture_names()
+                .flatten()
+                .filter_map(|name| {
+                    captures
+                        .name(name)
+                        .map(|x| (name.to_string(), x.as_str().to_lowercase()))
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hyper::{Body, Request as HyperRequest};
+
+    fn request(host: &str) -> Request {
+        Request::new(
+            "10.10.10.10:80".parse().unwrap(),
+            HyperRequest::get("/")
+                .header(HOST, host)
+                .body(Body::empty())
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_captures() {
+        let host = Host::new("{tenant}.example.com");
+
+        let params = host.captures(&request("Acme.Example.com:8080")).unwrap();
+
+        assert_eq!(params.get("tenant").unwrap(), "acme");
+        assert!(host.captures(&request("example.com")).is_none());
+        assert!(host.captures(&request("a.b.example.com")).is_none());
+        assert!(host.captures(&request("acme.example.org")).is_none());
+    }
+
+    #[test]
+    fn test_captures_exact() {
+        let host = Host::new("example.com");
+
+        assert!(host.captures(&request("example.com.")).unwrap().is_empty());
+        assert!(host.captures(&request("exampleXcom")).is_none());
+    }
+
+    #[test]
+    fn test_captures_wildcard() {
+        let host = Host::new("*.example.com");
+
+        assert!(host
+            .captures(&request("www.example.com"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_captures_ipv6() {
+        let host = Host::new("[::1]");
+
+        assert!(host.captures(&request("[::1]:8080")).is_some());
+    }
+}
@@ -0,0 +1,84 @@
+//! Contains an outbound HTTP client that is shared by the whole app.
+//!
+//! All requests share a single connection pool and time out after [`TIMEOUT`].
+//!
+//! **NOTE:** Only plain `http` URLs are supported by this client.
+
+use crate::{
+    hyper::{
+        client::HttpConnector, http::Error as HttpError, Body, Client, Request as HyperRequest,
+        Response as HyperResponse, StatusCode, Uri,
+    },
+    Error,
+};
+
+use log::error;
+use once_cell::sync::Lazy;
+use tokio::time::timeout;
+
+use std::{convert::TryFrom, time::Duration};
+
+/// Time after which an outbound request is abandoned.
+pub const TIMEOUT: Duration = Duration::from_secs(30);
+
+static CLIENT: Lazy<Client<HttpConnector>> = Lazy::new(|| {
+    let mut connector = HttpConnector::new();
+
+    connector.set_connect_timeout(Some(Duration::from_secs(10)));
+
+    Client::builder()
+        .pool_idle_timeout(Duration::from_secs(90))
+        .build(connector)
+});
+
+/// Returns the shared client for making outbound requests without the default timeout.
+pub fn client() -> &'static Client<HttpConnector> {
+    &CLIENT
+}
+
+/// Send the given request using the shared client.
+///
+/// If the request fails, the error responds with `502 Bad Gateway`. If it does not
+/// finish within [`TIMEOUT`], the error responds with `504 Gateway Timeout`.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::{http::request, hyper::Request as HyperRequest}};
+///
+/// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+///     let upstream = HyperRequest::post("http://127.0.0.1:9000/upload")
+///         .body(req.body_stream().unwrap_or_default())?;
+///
+///     Ok(request(upstream).await?)
+/// }
+/// ```
+pub async fn request(req: HyperRequest<Body>) -> Result<HyperResponse<Body>, Error> {
+    match timeout(TIMEOUT, CLIENT.request(req)).await {
+        Ok(Ok(response)) => Ok(response),
+        Ok(Err(err)) => {
+            error!("{}", err);
+            Err(Error::Status(StatusCode::BAD_GATEWAY))
+        }
+        Err(_) => Err(Error::Status(StatusCode::GATEWAY_TIMEOUT)),
+    }
+}
+
+/// Send a `GET` request to the given URI using the shared client.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::http::get};
+///
+/// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(get("http://127.0.0.1:9000/status").await?)
+/// }
+/// ```
+pub async fn get<U>(uri: U) -> Result<HyperResponse<Body>, Error>
+where
+    Uri: TryFrom<U>,
+    <Uri as TryFrom<U>>::Error: Into<HttpError>,
+{
+    request(HyperRequest::get(uri).body(Body::empty())?).await
+}
@@ -4,9 +4,14 @@
 pub use futures;
 pub use hyper;
 
+mod accept;
 mod error;
 mod ext;
+mod extract;
+#[cfg(feature = "form")]
+mod form;
 mod handle;
+mod host;
 mod path;
 mod pipe;
 mod request;
@@ -14,41 +19,83 @@ mod response;
 mod route;
 mod scope;
 mod service;
+mod sse;
+mod state;
+mod stream;
+#[cfg(feature = "tls")]
+mod tls;
+#[cfg(unix)]
+mod unix;
 
+pub mod constraints;
+#[cfg(feature = "file-handlers")]
+pub mod handlers;
 pub mod helpers;
+#[cfg(feature = "http")]
+pub mod http;
 pub mod middleware;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 pub use error::*;
-pub use ext::OptionExt;
+pub use ext::{OptionExt, ResponseExt};
+#[cfg(feature = "json")]
+pub use extract::Json;
+pub use extract::{FromRequest, FromRequestFuture};
 pub use handle::HandleFuture;
 #[doc(inline)]
 pub use middleware::{Chain, Middleware};
 pub use path::Path;
 pub use pipe::Pipe;
-pub use request::Request;
+pub use request::{Request, RequestParts};
 pub use response::Response;
+pub use route::{Route, RouteMut};
 pub use scope::Scope;
-pub use service::{service, Service};
+pub use service::{service, RemoteService, Service};
+pub use sse::{Sse, SseEvent};
+pub use stream::StreamBody;
+#[cfg(feature = "tls")]
+pub use tls::{serve_tls, TlsConfig};
+#[cfg(unix)]
+pub use unix::{serve_unix, serve_unix_with_shutdown, UnixPeer};
 
-use futures::future::ok;
+use futures::{future::try_join_all, FutureExt};
 use handle::Handle;
 use hyper::{
-    server::{conn::AddrStream, Server},
-    service::{make_service_fn, service_fn},
-    Error as HyperError, Method,
+    server::{conn::AddrIncoming, Builder, Server},
+    Method,
 };
+use middleware::MethodOverride;
+use path::{url_for, Names};
 use pipe::MiddlewareItem;
-use route::{Constraint, Route};
+use route::Constraint;
 use service::RouteRef;
+use state::State;
 
-use log::trace;
 use paste::paste;
 
-use std::{collections::HashMap as Map, convert::Infallible, net::ToSocketAddrs};
+use std::{
+    collections::HashMap as Map,
+    future::Future,
+    io::{Error as IoError, ErrorKind},
+    net::{SocketAddr, TcpListener, ToSocketAddrs},
+    sync::Arc,
+};
 
 pub(crate) const INTERNAL_ERR: &str =
     "Internal error on reign_router. Please create an issue on https://github.com/pksunkara/reign";
 
+/// HTTP methods commonly used by REST APIs, which leaves out `CONNECT` and `TRACE`.
+pub const REST_METHODS: &[Method] = &[
+    Method::GET,
+    Method::POST,
+    Method::PUT,
+    Method::PATCH,
+    Method::DELETE,
+    Method::HEAD,
+    Method::OPTIONS,
+];
+
 macro_rules! method {
     ($method:ident) => {
         paste! {
@@ -67,12 +114,12 @@ macro_rules! method {
             /// }
             /// ```
             #[inline]
-            pub fn $method<P, H>(&mut self, path: P, handle: H)
+            pub fn $method<P, H>(&mut self, path: P, handle: H) -> RouteMut
             where
                 P: Into<Path>,
                 H: Handle,
             {
-                self.any(&[Method::[<$method:snake:upper>]], path, handle);
+                self.any(&[Method::[<$method:snake:upper>]], path, handle)
             }
         }
     };
@@ -106,9 +153,280 @@ pub struct Router {
     pipes: Map<String, Pipe>,
     scopes: Vec<Scope>,
     routes: Vec<Route>,
+    allow_duplicates: bool,
+    auto_options: bool,
+    auto_head: bool,
+    state: State,
+    method_override: Option<MethodOverride>,
+    max_header_size: Option<usize>,
+    max_headers: Option<usize>,
+    max_body_size: Option<u64>,
+    not_found: Option<Arc<Box<dyn Handle>>>,
+    method_not_allowed: Option<Arc<Box<dyn Handle>>>,
 }
 
 impl Router {
+    /// Allow the same method and path to be defined more than once without a constraint.
+    ///
+    /// By default, such duplicate routes panic in debug builds because only the first
+    /// one would ever be matched. This needs to be called on the top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn foo(req: &mut Request) -> Result<impl Response, Error> { Ok("foo") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.allow_duplicates();
+    ///
+    ///     r.get("foo", foo);
+    ///     r.get("foo", foo);
+    /// }
+    /// ```
+    pub fn allow_duplicates(&mut self) -> &mut Self {
+        self.allow_duplicates = true;
+        self
+    }
+
+    /// Respond to `OPTIONS` requests with `204 No Content` and an `Allow` header listing the
+    /// methods defined for the path, unless an `OPTIONS` endpoint is defined for it.
+    ///
+    /// This needs to be called on the top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn foo(req: &mut Request) -> Result<impl Response, Error> { Ok("foo") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.auto_options(true);
+    ///
+    ///     r.get("foo", foo);
+    ///     r.post("foo", foo);
+    /// }
+    /// ```
+    pub fn auto_options(&mut self, enable: bool) -> &mut Self {
+        self.auto_options = enable;
+        self
+    }
+
+    /// Respond to `HEAD` requests by running the `GET` endpoint of the path and dropping the
+    /// body of its response, unless a `HEAD` endpoint is defined for it.
+    ///
+    /// The headers of the response are kept and `Content-Length` is set to the size of the
+    /// dropped body when it is known. This needs to be called on the top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn foo(req: &mut Request) -> Result<impl Response, Error> { Ok("foo") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.auto_head(true);
+    ///
+    ///     r.get("foo", foo);
+    /// }
+    /// ```
+    pub fn auto_head(&mut self, enable: bool) -> &mut Self {
+        self.auto_head = enable;
+        self
+    }
+
+    /// Limit the size in bytes of the buffer that reads the request line and headers.
+    ///
+    /// Requests exceeding it are responded with `431 Request Header Fields Too Large`. It
+    /// needs to be at least `8192` and defaults to about `400kb`. This needs to be called
+    /// on the top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.max_header_size(64 * 1024);
+    /// }
+    /// ```
+    pub fn max_header_size(&mut self, size: usize) -> &mut Self {
+        assert!(size >= 8192, "max header size needs to be at least 8192");
+
+        self.max_header_size = Some(size);
+        self
+    }
+
+    /// Limit the number of headers of a request.
+    ///
+    /// Requests exceeding it are responded with `431 Request Header Fields Too Large`. The
+    /// server already refuses requests with more than `100` headers, so this can only lower
+    /// that. This needs to be called on the top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.max_headers(200);
+    /// }
+    /// ```
+    pub fn max_headers(&mut self, count: usize) -> &mut Self {
+        self.max_headers = Some(count);
+        self
+    }
+
+    /// Limit the size in bytes of the request body read by [`Request::body_bytes`] and the
+    /// helpers built on it, like [`Request::json`].
+    ///
+    /// Larger bodies are responded with `413 Payload Too Large`. It defaults to `2mb`. This
+    /// needs to be called on the top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.max_body_size(10 * 1024 * 1024);
+    /// }
+    /// ```
+    pub fn max_body_size(&mut self, size: u64) -> &mut Self {
+        self.max_body_size = Some(size);
+        self
+    }
+
+    /// Respond with the given handler when no route matches the request.
+    ///
+    /// Responses with `200 OK` status are sent as `404 Not Found`. Middlewares are not run
+    /// for this handler since no scope was matched. This needs to be called on the top
+    /// level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    ///
+    /// async fn not_found(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(format!("{} was not found", req.path()))
+    /// }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.not_found(not_found);
+    /// }
+    /// ```
+    pub fn not_found<H>(&mut self, handle: H) -> &mut Self
+    where
+        H: Handle,
+    {
+        self.not_found = Some(Arc::new(Box::new(handle)));
+        self
+    }
+
+    /// Respond with the given handler when routes match the path of the request but
+    /// not its method.
+    ///
+    /// Responses with `200 OK` status are sent as `405 Method Not Allowed`, and the `Allow`
+    /// header is added unless the handler sets it. Middlewares are not run for this handler.
+    /// This needs to be called on the top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    ///
+    /// async fn method_not_allowed(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(format!("{} is not allowed", req.method()))
+    /// }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.method_not_allowed(method_not_allowed);
+    /// }
+    /// ```
+    pub fn method_not_allowed<H>(&mut self, handle: H) -> &mut Self
+    where
+        H: Handle,
+    {
+        self.method_not_allowed = Some(Arc::new(Box::new(handle)));
+        self
+    }
+
+    /// Override the method of `POST` requests before they are matched to the routes.
+    ///
+    /// This needs to be called on the top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{middleware::MethodOverride, Router};
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn destroy(req: &mut Request) -> Result<impl Response, Error> { Ok("destroy") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.method_override(MethodOverride::default().without_field());
+    ///
+    ///     r.delete("articles", destroy);
+    /// }
+    /// ```
+    pub fn method_override(&mut self, method_override: MethodOverride) -> &mut Self {
+        self.method_override = Some(method_override);
+        self
+    }
+
+    /// Register a value as application state which can be retrieved by all the handlers
+    /// and middlewares using [`Request::state`].
+    ///
+    /// Only one value of each type can be registered, and this needs to be called on the
+    /// top level router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    ///
+    /// struct Config {
+    ///     name: String,
+    /// }
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.state::<Config>().unwrap().name.clone())
+    /// }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.state(Config { name: "reign".into() });
+    ///
+    ///     r.get("foo", foo);
+    /// }
+    /// ```
+    pub fn state<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Send + Sync + 'static,
+    {
+        self.state_arc(Arc::new(value))
+    }
+
+    /// Register a value that is already shared as application state.
+    ///
+    /// This is same as [`Router::state`] but useful when the value is also held elsewhere.
+    pub fn state_arc<T>(&mut self, value: Arc<T>) -> &mut Self
+    where
+        T: Send + Sync + 'static,
+    {
+        self.state.insert(value);
+        self
+    }
+
     /// Define a middleware pipe that can be used later.
     ///
     /// # Examples
@@ -155,6 +473,30 @@ impl Router {
         self.scopes.last_mut().expect(INTERNAL_ERR)
     }
 
+    /// Define a scope without a path prefix that only matches requests with the given host.
+    ///
+    /// See [`Scope::host`] for the supported patterns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn foo(req: &mut Request) -> Result<impl Response, Error> { Ok("foo") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.scope_host("admin.example.com").to(|r| {
+    ///         r.get("foo", foo);
+    ///     });
+    /// }
+    /// ```
+    pub fn scope_host(&mut self, pattern: &str) -> &mut Scope {
+        let scope = self.scope("");
+        scope.host(pattern);
+        scope
+    }
+
     method!(get);
     method!(post);
     method!(put);
@@ -179,13 +521,12 @@ impl Router {
     ///     r.any(&[Method::GET], "foo", foo);
     /// }
     /// ```
-    pub fn any<P, H>(&mut self, methods: &[Method], path: P, handle: H)
+    pub fn any<P, H>(&mut self, methods: &[Method], path: P, handle: H) -> RouteMut
     where
         P: Into<Path>,
         H: Handle,
     {
-        self.routes
-            .push(Route::new(path).methods(methods).handle(handle));
+        self.route(Route::new(path).methods(methods).handle(handle))
     }
 
     /// Define an endpoint with path that allows all HTTP methods.
@@ -202,12 +543,34 @@ impl Router {
     ///     r.all("foo", foo);
     /// }
     /// ```
-    pub fn all<P, H>(&mut self, path: P, handle: H)
+    pub fn all<P, H>(&mut self, path: P, handle: H) -> RouteMut
+    where
+        P: Into<Path>,
+        H: Handle,
+    {
+        self.route(Route::new(path).handle(handle))
+    }
+
+    /// Define an endpoint with path that allows the [`REST_METHODS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn foo(req: &mut Request) -> Result<impl Response, Error> { Ok("foo") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.rest("foo", foo);
+    /// }
+    /// ```
+    pub fn rest<P, H>(&mut self, path: P, handle: H) -> RouteMut
     where
         P: Into<Path>,
         H: Handle,
     {
-        self.routes.push(Route::new(path).handle(handle));
+        self.any(REST_METHODS, path, handle)
     }
 
     /// Define an endpoint with path and constraint that allows any of the given HTTP methods.
@@ -237,17 +600,18 @@ impl Router {
         path: P,
         constraint: C,
         handle: H,
-    ) where
+    ) -> RouteMut
+    where
         P: Into<Path>,
         C: Fn(&Request) -> bool + Send + Sync + 'static,
         H: Handle,
     {
-        self.routes.push(
+        self.route(
             Route::new(path)
                 .methods(methods)
                 .constraint(constraint)
                 .handle(handle),
-        );
+        )
     }
 
     /// Define an endpoint with path and constraint that allows all HTTP methods.
@@ -270,18 +634,157 @@ impl Router {
     ///     );
     /// }
     /// ```
-    pub fn all_with_constraint<P, C, H>(&mut self, path: P, constraint: C, handle: H)
+    pub fn all_with_constraint<P, C, H>(&mut self, path: P, constraint: C, handle: H) -> RouteMut
     where
         P: Into<Path>,
         C: Fn(&Request) -> bool + Send + Sync + 'static,
         H: Handle,
     {
-        self.routes
-            .push(Route::new(path).constraint(constraint).handle(handle));
+        self.route(Route::new(path).constraint(constraint).handle(handle))
+    }
+
+    /// Define the routes of the given router definition under the given prefix.
+    ///
+    /// This is same as defining a scope with the router definition, which means that the
+    /// pipes defined in it can only be used by its routes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn users(req: &mut Request) -> Result<impl Response, Error> { Ok("users") }
+    /// #
+    /// # async fn posts(req: &mut Request) -> Result<impl Response, Error> { Ok("posts") }
+    ///
+    /// fn users_routes(r: &mut Router) {
+    ///     r.get("", users);
+    /// }
+    ///
+    /// fn posts_routes(r: &mut Router) {
+    ///     r.get("", posts);
+    /// }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.mount("users", users_routes);
+    ///     r.mount("posts", posts_routes);
+    /// }
+    /// ```
+    pub fn mount<P, R>(&mut self, prefix: P, f: R) -> &mut Scope
+    where
+        P: Into<Path>,
+        R: FnOnce(&mut Router),
+    {
+        self.scope(prefix).to(f)
+    }
+
+    /// Define the pipes, scopes, routes and state of the given router definition in this
+    /// router as if they were defined here.
+    ///
+    /// The other settings of the given router definition are ignored.
+    ///
+    /// # Panics
+    ///
+    /// If the given router definition defines a pipe with the same name as one already
+    /// defined in this router.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn users(req: &mut Request) -> Result<impl Response, Error> { Ok("users") }
+    ///
+    /// fn users_routes(r: &mut Router) {
+    ///     r.get("users", users);
+    /// }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.merge(users_routes);
+    /// }
+    /// ```
+    pub fn merge<R>(&mut self, f: R) -> &mut Self
+    where
+        R: FnOnce(&mut Router),
+    {
+        let mut other = Router::default();
+        f(&mut other);
+
+        for (name, pipe) in other.pipes {
+            if self.pipes.contains_key(&name) {
+                panic!(
+                    "pipe `{}` is already defined and can't be defined again by merging",
+                    name
+                );
+            }
+
+            self.pipes.insert(name, pipe);
+        }
+
+        self.scopes.extend(other.scopes);
+        self.routes.extend(other.routes);
+        self.state.extend(other.state);
+        self
+    }
+
+    /// Define endpoints from routes that were built at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{hyper::Method, Route, Router};
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn foo(req: &mut Request) -> Result<impl Response, Error> { Ok("foo") }
+    /// #
+    /// # async fn bar(req: &mut Request) -> Result<impl Response, Error> { Ok("bar") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.extend(vec![
+    ///         Route::new("foo").methods(&[Method::GET]).handle(foo),
+    ///         Route::new("bar").handle(bar),
+    ///     ]);
+    /// }
+    /// ```
+    pub fn extend<I>(&mut self, routes: I)
+    where
+        I: IntoIterator<Item = Route>,
+    {
+        self.routes.extend(routes);
+    }
+
+    /// Build the url of the route with the given name, including the paths of all the
+    /// scopes it is under.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{path as p, Router};
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn show(req: &mut Request) -> Result<impl Response, Error> { Ok("show") }
+    ///
+    /// let mut r = Router::default();
+    ///
+    /// r.scope("users").to(|r| {
+    ///     r.get(p!(id), show).name("user_show");
+    /// });
+    ///
+    /// assert_eq!(r.url_for("user_show", &[("id", "42")]).unwrap(), "/users/42");
+    /// ```
+    pub fn url_for(&self, name: &str, params: &[(&str, &str)]) -> Result<String, Error> {
+        url_for(&self.names(), name, params)
     }
 }
 
 impl Router {
+    fn route(&mut self, route: Route) -> RouteMut {
+        self.routes.push(route);
+        RouteMut(self.routes.last_mut().expect(INTERNAL_ERR))
+    }
+
     pub(crate) fn regex(&self) -> Vec<(String, String)> {
         let mut regexes = self.routes.iter().map(|x| x.regex()).collect::<Vec<_>>();
 
@@ -296,14 +799,41 @@ impl Router {
         regexes
     }
 
+    pub(crate) fn names(&self) -> Names {
+        let mut names = Names::new();
+        self.collect_names(&Path::new(), &mut names);
+        names
+    }
+
+    fn collect_names(&self, prefix: &Path, names: &mut Names) {
+        for route in &self.routes {
+            if let Some(name) = &route.name {
+                if names
+                    .insert(name.clone(), prefix.join(&route.path))
+                    .is_some()
+                {
+                    panic!("route name `{}` is defined more than once", name);
+                }
+            }
+        }
+
+        for scope in &self.scopes {
+            scope.router.collect_names(&prefix.join(&scope.path), names);
+        }
+    }
+
     pub(crate) fn refs(&self, upper_pipes: Map<&String, &Pipe>) -> Vec<RouteRef> {
         let mut routes = self
             .routes
             .iter()
             .map(|x| RouteRef {
+                path: x.path.clone(),
                 handle: x.handle.clone(),
-                middlewares: vec![],
+                middlewares: x.middlewares.clone(),
                 constraints: vec![x.constraint.clone()],
+                methods: x.methods.clone(),
+                hosts: vec![],
+                timeout: x.timeout,
             })
             .collect::<Vec<_>>();
 
@@ -313,27 +843,33 @@ impl Router {
         for scope in &self.scopes {
             let scope_ref = scope.refs(pipes.clone());
 
-            for route_ref in scope_ref.1 {
-                let mut constraints = vec![scope_ref.0.clone()];
-                let mut middlewares = scope_ref
-                    .2
-                    .iter()
-                    .flat_map(|x| {
-                        let pipe = pipes.get(x);
-
-                        debug_assert!(pipe.is_some(), "can't find pipe with name `{}`", x);
+            // Pipes are checked even when the scope has no routes
+            let scope_middlewares = scope_ref
+                .3
+                .iter()
+                .flat_map(|x| match pipes.get(x) {
+                    Some(pipe) => pipe.refs(),
+                    None => panic!("can't find pipe with name `{}`", x),
+                })
+                .collect::<Vec<_>>();
 
-                        pipe.map(|p| p.middlewares.clone()).unwrap_or(vec![])
-                    })
-                    .collect::<Vec<_>>();
+            for route_ref in scope_ref.2 {
+                let mut constraints = vec![scope_ref.0.clone()];
+                let mut hosts = scope_ref.1.iter().cloned().collect::<Vec<_>>();
+                let mut middlewares = scope_middlewares.clone();
 
                 constraints.extend(route_ref.constraints.into_iter());
+                hosts.extend(route_ref.hosts.into_iter());
                 middlewares.extend(route_ref.middlewares.into_iter());
 
                 routes.push(RouteRef {
+                    path: scope.path.join(&route_ref.path),
                     handle: route_ref.handle.clone(),
                     middlewares,
                     constraints,
+                    methods: route_ref.methods,
+                    hosts,
+                    timeout: route_ref.timeout,
                 })
             }
         }
@@ -356,29 +892,206 @@ impl Router {
 ///     serve("127.0.0.1:8080", router).await.unwrap();
 /// }
 /// ```
-pub async fn serve<A, R>(addr: A, f: R) -> Result<(), HyperError>
+pub async fn serve<A, R>(addr: A, f: R) -> Result<(), ServeError>
 where
     A: ToSocketAddrs + Send + 'static,
     R: FnOnce(&mut Router),
 {
     let router_service = service(f);
 
-    let socket_addr = addr
-        .to_socket_addrs()
-        .expect("One of the socket address is not valid")
-        .next()
-        .expect("Must be given at least one socket address");
+    router_service
+        .configure(try_bind(addr)?)
+        .serve(router_service)
+        .await
+        .map_err(ServeError::serving)
+}
+
+/// Bind the server using the given router definition and return the bound address along
+/// with the future that runs the server.
+///
+/// Unlike [`serve`], the address is bound before the future is returned, which makes it
+/// possible to bind to port `0` and connect to the port assigned by the OS without waiting
+/// for the server to start. Needs to be called from within a tokio runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reign::router::{bind, Router};
+///
+/// fn router(r: &mut Router) {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let (addr, server) = bind("127.0.0.1:0", router).unwrap();
+///     println!("Listening on {}", addr);
+///
+///     server.await.unwrap();
+/// }
+/// ```
+pub fn bind<A, R>(
+    addr: A,
+    f: R,
+) -> Result<(SocketAddr, impl Future<Output = Result<(), ServeError>>), ServeError>
+where
+    A: ToSocketAddrs,
+    R: FnOnce(&mut Router),
+{
+    let router_service = service(f);
+    let incoming = AddrIncoming::bind(&socket_addr(addr)?).map_err(ServeError::Bind)?;
+    let addr = incoming.local_addr();
+
+    let server = router_service
+        .configure(Server::builder(incoming))
+        .serve(router_service)
+        .map(|x| x.map_err(ServeError::serving));
+
+    Ok((addr, server))
+}
+
+/// Create the server using the given router definition on an already bound listener.
+///
+/// This is useful when the listener is handed over by the system, like with systemd socket
+/// activation, or when binding to port `0` and reading back the port assigned by the OS.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reign::router::{serve_incoming, Router};
+/// use std::net::TcpListener;
+///
+/// fn router(r: &mut Router) {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+///     println!("Listening on {}", listener.local_addr().unwrap());
+///
+///     serve_incoming(listener, router).await.unwrap();
+/// }
+/// ```
+pub async fn serve_incoming<R>(listener: TcpListener, f: R) -> Result<(), ServeError>
+where
+    R: FnOnce(&mut Router),
+{
+    let router_service = service(f);
 
-    let make_svc = make_service_fn(|socket: &AddrStream| {
-        let remote_addr = socket.remote_addr();
-        let router_service = router_service.clone();
+    router_service
+        .configure(Server::from_tcp(listener).map_err(ServeError::Bind)?)
+        .serve(router_service)
+        .await
+        .map_err(ServeError::serving)
+}
 
-        trace!("Incoming request to service function");
+/// Create the server using the given router definition, which is gracefully shut down
+/// when the given `signal` completes.
+///
+/// The server stops accepting new connections once the signal completes and waits for
+/// the in-flight requests to finish before resolving.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reign::router::{serve_with_shutdown, Router};
+///
+/// fn router(r: &mut Router) {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     serve_with_shutdown("127.0.0.1:8080", router, async {
+///         tokio::signal::ctrl_c().await.unwrap();
+///     })
+///     .await
+///     .unwrap();
+/// }
+/// ```
+pub async fn serve_with_shutdown<A, R, S>(addr: A, f: R, signal: S) -> Result<(), ServeError>
+where
+    A: ToSocketAddrs + Send + 'static,
+    R: FnOnce(&mut Router),
+    S: Future<Output = ()>,
+{
+    let router_service = service(f);
 
-        ok::<_, Infallible>(service_fn(move |req| {
-            router_service.clone().call(req, remote_addr)
-        }))
-    });
+    router_service
+        .configure(try_bind(addr)?)
+        .serve(router_service)
+        .with_graceful_shutdown(signal)
+        .await
+        .map_err(ServeError::serving)
+}
 
-    Server::bind(&socket_addr).serve(make_svc).await
+/// Create multiple servers, each with their own router definition, that run concurrently.
+///
+/// All the servers are gracefully shut down together when the given `signal` completes.
+/// If any of the servers fails, its error is returned right away and the other servers are
+/// dropped. They stop accepting connections, but are not gracefully shut down, so the
+/// requests that are in-flight on their connections are not waited for.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reign::router::{futures::future::pending, serve_many, Router};
+///
+/// fn public(r: &mut Router) {}
+///
+/// fn admin(r: &mut Router) {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     serve_many(
+///         vec![
+///             ("0.0.0.0:8080", public as fn(&mut Router)),
+///             ("127.0.0.1:9090", admin),
+///         ],
+///         pending(),
+///     )
+///     .await
+///     .unwrap();
+/// }
+/// ```
+pub async fn serve_many<I, A, R, S>(listeners: I, signal: S) -> Result<(), ServeError>
+where
+    I: IntoIterator<Item = (A, R)>,
+    A: ToSocketAddrs + Send + 'static,
+    R: FnOnce(&mut Router),
+    S: Future<Output = ()>,
+{
+    let signal = signal.shared();
+
+    let servers = listeners
+        .into_iter()
+        .map(|(addr, f)| {
+            let router_service = service(f);
+
+            Ok(router_service
+                .configure(try_bind(addr)?)
+                .serve(router_service)
+                .with_graceful_shutdown(signal.clone()))
+        })
+        .collect::<Result<Vec<_>, ServeError>>()?;
+
+    try_join_all(servers).await.map_err(ServeError::serving)?;
+    Ok(())
+}
+
+fn try_bind<A>(addr: A) -> Result<Builder<AddrIncoming>, ServeError>
+where
+    A: ToSocketAddrs,
+{
+    Server::try_bind(&socket_addr(addr)?).map_err(ServeError::Bind)
+}
+
+pub(crate) fn socket_addr<A>(addr: A) -> Result<SocketAddr, ServeError>
+where
+    A: ToSocketAddrs,
+{
+    addr.to_socket_addrs()
+        .map_err(ServeError::Addr)?
+        .next()
+        .ok_or_else(|| {
+            ServeError::Addr(IoError::new(
+                ErrorKind::InvalidInput,
+                "must be given at least one socket address",
+            ))
+        })
 }
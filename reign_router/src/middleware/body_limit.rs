@@ -0,0 +1,98 @@
+use crate::{
+    futures::{FutureExt, StreamExt},
+    hyper::{header::CONTENT_LENGTH, Body},
+    BodyError, Chain, HandleFuture, Middleware, Request,
+};
+
+use log::warn;
+
+use std::error::Error as StdError;
+
+/// Rejects requests whose body is larger than the given number of bytes with
+/// `413 Payload Too Large`.
+///
+/// Requests with a larger `Content-Length` are rejected before the rest of the chain runs.
+/// Otherwise, reading the body fails once it goes over the limit, which also covers chunked
+/// requests. The limit is also used by [`Request::body_bytes`] instead of the one set with
+/// [`Router::max_body_size`](crate::Router::max_body_size).
+///
+/// Use different pipes for the scopes which need a different limit, like uploads.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::BodyLimit, Router};
+/// # use reign::prelude::*;
+/// #
+/// # async fn foo(req: &mut Request) -> Result<impl Response, Error> { Ok("foo") }
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("api").add(BodyLimit::new(64 * 1024));
+///     r.pipe("upload").add(BodyLimit::new(100 * 1024 * 1024));
+///
+///     r.scope("api").through(&["api"]).to(|r| {
+///         r.post("foo", foo);
+///     });
+///
+///     r.scope("upload").through(&["upload"]).to(|r| {
+///         r.post("foo", foo);
+///     });
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BodyLimit {
+    limit: u64,
+}
+
+impl BodyLimit {
+    pub fn new(limit: u64) -> Self {
+        Self { limit }
+    }
+}
+
+impl Middleware for BodyLimit {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            let limit = self.limit;
+
+            let length = req
+                .headers()
+                .get(CONTENT_LENGTH)
+                .and_then(|x| x.to_str().ok())
+                .and_then(|x| x.parse::<u64>().ok());
+
+            if length.map_or(false, |x| x > limit) {
+                warn!(
+                    "{} {} - body of {} bytes is over the limit",
+                    req.method(),
+                    req.path(),
+                    length.unwrap_or_default()
+                );
+
+                return Err(BodyError::TooLarge(limit).into());
+            }
+
+            req.body_limit = limit;
+
+            if let Some(body) = req.body_stream() {
+                let mut read = 0;
+
+                let body = body.map(move |chunk| {
+                    let chunk = chunk?;
+                    read += chunk.len() as u64;
+
+                    if read > limit {
+                        return Err(BodyError::TooLarge(limit).into());
+                    }
+
+                    Ok::<_, Box<dyn StdError + Send + Sync>>(chunk)
+                });
+
+                req.extensions_mut().insert(Body::wrap_stream(body));
+            }
+
+            chain.run(req).await
+        }
+        .boxed()
+    }
+}
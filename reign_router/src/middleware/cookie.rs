@@ -5,11 +5,29 @@ use crate::{
     Chain, HandleFuture, Middleware, Request,
 };
 
-use cookie::Cookie;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
 
-pub use cookie::CookieJar;
+use std::borrow::Cow;
+
+pub use cookie::{Cookie, CookieBuilder, CookieJar, SameSite};
+
+// Characters that are not allowed in a cookie value. `%` is allowed so that values which
+// are already encoded are not encoded again
+const COOKIE_VALUE: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b',').add(b';').add(b'\\');
+
+// Percent-encodes the value only if it has characters not allowed in a cookie
+pub(crate) fn encode_value(value: &str) -> Cow<'_, str> {
+    utf8_percent_encode(value, COOKIE_VALUE).into()
+}
+
+// Decodes the value of a cookie sent with a request
+pub(crate) fn decode_value(value: &str) -> Cow<'_, str> {
+    percent_decode_str(value).decode_utf8_lossy()
+}
 
 /// Parses the cookie and adds a CookieJar to the request storage.
+///
+/// The values of the cookies are percent-decoded like [`Request::cookie`].
 #[derive(Default)]
 pub struct CookieParser {}
 
@@ -26,7 +44,11 @@ impl CookieParser {
             .flat_map(HeaderValue::to_str)
             .flat_map(|x| x.split("; "))
             .flat_map(|x| Cookie::parse(x.to_owned()))
-            .fold(CookieJar::new(), |mut jar, cookie| {
+            .fold(CookieJar::new(), |mut jar, mut cookie| {
+                if let Cow::Owned(value) = decode_value(cookie.value()) {
+                    cookie.set_value(value);
+                }
+
                 jar.add_original(cookie);
                 jar
             })
@@ -0,0 +1,127 @@
+use crate::{
+    futures::FutureExt,
+    hyper::{body::HttpBody, header::CONTENT_LENGTH},
+    middleware::runtime::dur_to_string,
+    Chain, HandleFuture, Middleware, Request,
+};
+
+use log::{log, log_enabled, Level};
+
+use std::time::Instant;
+
+const DEFAULT_FORMAT: &str = r#"{ip} "{method} {path}" {status} {size} {time}"#;
+
+/// Logs a line for every request once it is responded, like an access log.
+///
+/// The line is built from a format string with the following placeholders:
+///
+/// | Placeholder | Value |
+/// |-------------|-------|
+/// | `{method}` | Method of the request |
+/// | `{path}` | Path of the request |
+/// | `{query}` | Query string of the request |
+/// | `{ip}` | Remote address of the request |
+/// | `{status}` | Status of the response |
+/// | `{size}` | Size of the response body in bytes, `-` if not known |
+/// | `{time}` | Time taken to respond |
+/// | `{id}` | ID given by [`RequestId`](crate::middleware::RequestId), which needs to run before this |
+///
+/// # Examples
+///
+/// ```
+/// use reign::{
+///     log::Level,
+///     router::{middleware::Logger, Router},
+/// };
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("common")
+///         .add(Logger::new(Level::Debug).format("{method} {path} - {status} in {time}"));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Logger {
+    level: Level,
+    format: String,
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self {
+            level: Level::Info,
+            format: DEFAULT_FORMAT.to_string(),
+        }
+    }
+}
+
+impl Logger {
+    /// Instantiate the middleware with the default format and the given log level.
+    pub fn new(level: Level) -> Self {
+        Self {
+            level,
+            ..Default::default()
+        }
+    }
+
+    /// Set the format of the log line. Defaults to
+    /// `{ip} "{method} {path}" {status} {size} {time}`.
+    pub fn format<S>(mut self, format: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.format = format.into();
+        self
+    }
+}
+
+impl Middleware for Logger {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        if !log_enabled!(target: "reign_router", self.level) {
+            return chain.run(req);
+        }
+
+        async move {
+            let start = Instant::now();
+            let method = req.method().to_string();
+            let path = req.path().to_string();
+            let query = req.uri().query().unwrap_or_default().to_string();
+            let ip = req.ip().to_string();
+            let id = req.request_id().unwrap_or("-").to_string();
+
+            let result = chain.run(req).await;
+
+            let time = dur_to_string(start.elapsed().as_micros() as i64);
+
+            // Errors are logged with the status they are responded with later
+            let (status, size) = match &result {
+                Ok(response) => (
+                    response.status(),
+                    response
+                        .headers()
+                        .get(CONTENT_LENGTH)
+                        .and_then(|x| x.to_str().ok())
+                        .map(String::from)
+                        .or_else(|| response.body().size_hint().exact().map(|x| x.to_string()))
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+                Err(err) => (err.status(), "0".to_string()),
+            };
+
+            let line = self
+                .format
+                .replace("{method}", &method)
+                .replace("{path}", &path)
+                .replace("{query}", &query)
+                .replace("{ip}", &ip)
+                .replace("{status}", status.as_str())
+                .replace("{size}", &size)
+                .replace("{time}", &time)
+                .replace("{id}", &id);
+
+            log!(target: "reign_router", self.level, "{}", line);
+
+            result
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    futures::FutureExt,
+    hyper::{header::RETRY_AFTER, Body, Response as HyperResponse, StatusCode},
+    Chain, HandleFuture, Middleware, Request,
+};
+
+use log::info;
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Responds with `503 Service Unavailable` to all requests without running the rest of
+/// the chain while enabled.
+///
+/// Clones of the middleware share the same switch, so a clone kept in the application
+/// state can be used to toggle it at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::MaintenanceMode, Router};
+/// use std::time::Duration;
+///
+/// fn router(r: &mut Router) {
+///     let maintenance = MaintenanceMode::new(false).retry_after(Duration::from_secs(300));
+///
+///     r.state(maintenance.clone());
+///     r.pipe("common").add(maintenance);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MaintenanceMode {
+    enabled: Arc<AtomicBool>,
+    retry_after: Option<Duration>,
+}
+
+impl MaintenanceMode {
+    /// Instantiate the middleware with the given initial state.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: Arc::new(AtomicBool::new(enabled)),
+            retry_after: None,
+        }
+    }
+
+    /// Send a `Retry-After` header with the given duration in the responses.
+    pub fn retry_after(mut self, duration: Duration) -> Self {
+        self.retry_after = Some(duration);
+        self
+    }
+
+    /// Start responding with `503 Service Unavailable`.
+    pub fn enable(&self) {
+        self.enabled.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop responding with `503 Service Unavailable`.
+    pub fn disable(&self) {
+        self.enabled.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns whether the maintenance mode is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+}
+
+impl Middleware for MaintenanceMode {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            if !self.is_enabled() {
+                return chain.run(req).await;
+            }
+
+            info!("{} {} - 503 Service Unavailable", req.method(), req.path());
+
+            let mut builder = HyperResponse::builder().status(StatusCode::SERVICE_UNAVAILABLE);
+
+            if let Some(duration) = self.retry_after {
+                builder = builder.header(RETRY_AFTER, duration.as_secs());
+            }
+
+            Ok(builder.body(Body::empty())?)
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,191 @@
+use crate::hyper::{
+    body::HttpBody,
+    header::{HeaderName, CONTENT_LENGTH, CONTENT_TYPE},
+    Body, Method, Request as HyperRequest, StatusCode,
+};
+
+use log::debug;
+use url::form_urlencoded::parse;
+
+/// Allows HTML forms to reach `PUT`, `PATCH` and `DELETE` routes by overriding the method
+/// of `POST` requests.
+///
+/// The method is taken from the `X-HTTP-Method-Override` header or the `_method` field of
+/// an url encoded form body. Since the method needs to be changed before the route is
+/// matched, this is registered on the router using [`Router::method_override`] instead of
+/// a pipe.
+///
+/// When reading the form field, the body of url encoded `POST` requests is buffered
+/// before routing. Since no route is matched yet, the size of the body is limited by
+/// [`Router::max_body_size`] and not by any [`BodyLimit`] middleware. Larger bodies are
+/// responded with `413 Payload Too Large` and bodies which fail to be read with
+/// `400 Bad Request`.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::MethodOverride, Router};
+/// # use reign::prelude::*;
+/// #
+/// # async fn destroy(req: &mut Request) -> Result<impl Response, Error> { Ok("destroy") }
+///
+/// fn router(r: &mut Router) {
+///     r.method_override(MethodOverride::default());
+///
+///     r.delete("articles", destroy);
+/// }
+/// ```
+///
+/// [`Router::method_override`]: crate::Router::method_override
+/// [`Router::max_body_size`]: crate::Router::max_body_size
+/// [`BodyLimit`]: crate::middleware::BodyLimit
+#[derive(Debug, Clone)]
+pub struct MethodOverride {
+    header: Option<HeaderName>,
+    field: Option<String>,
+}
+
+impl Default for MethodOverride {
+    fn default() -> Self {
+        Self {
+            header: Some(HeaderName::from_static("x-http-method-override")),
+            field: Some("_method".into()),
+        }
+    }
+}
+
+impl MethodOverride {
+    /// Use a custom header name to read the method from. The name is case insensitive.
+    ///
+    /// # Panics
+    ///
+    /// If the given name is not a valid header name.
+    pub fn header(mut self, header: &str) -> Self {
+        self.header = Some(HeaderName::from_bytes(header.as_bytes()).expect("invalid header name"));
+        self
+    }
+
+    /// Use a custom form field name to read the method from.
+    pub fn field(mut self, field: &str) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    /// Do not read the method from the form body.
+    pub fn without_field(mut self) -> Self {
+        self.field = None;
+        self
+    }
+
+    pub(crate) async fn apply(
+        &self,
+        mut req: HyperRequest<Body>,
+        limit: u64,
+    ) -> Result<HyperRequest<Body>, StatusCode> {
+        if req.method() != Method::POST {
+            return Ok(req);
+        }
+
+        if let Some(header) = &self.header {
+            let method = req
+                .headers()
+                .get(header)
+                .and_then(|x| x.to_str().ok())
+                .and_then(allowed);
+
+            if let Some(method) = method {
+                debug!("Overriding method with {} from header", method);
+
+                *req.method_mut() = method;
+                return Ok(req);
+            }
+        }
+
+        let field = match &self.field {
+            Some(field) => field,
+            None => return Ok(req),
+        };
+
+        let is_form = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|x| x.to_str().ok())
+            .map_or(false, |x| {
+                x.starts_with("application/x-www-form-urlencoded")
+            });
+
+        if !is_form {
+            return Ok(req);
+        }
+
+        let (parts, body) = req.into_parts();
+
+        let length = parts
+            .headers
+            .get(CONTENT_LENGTH)
+            .and_then(|x| x.to_str().ok())
+            .and_then(|x| x.parse::<u64>().ok());
+
+        if length.map_or(false, |x| x > limit) {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        let bytes = read(body, limit).await?;
+
+        let method = parse(&bytes)
+            .find(|(name, _)| name == field)
+            .and_then(|(_, value)| allowed(&value));
+
+        let mut req = HyperRequest::from_parts(parts, Body::from(bytes));
+
+        if let Some(method) = method {
+            debug!("Overriding method with {} from form field", method);
+
+            *req.method_mut() = method;
+        }
+
+        Ok(req)
+    }
+}
+
+// Reads the body while making sure it is not larger than the limit
+async fn read(mut body: Body, limit: u64) -> Result<Vec<u8>, StatusCode> {
+    let mut bytes = Vec::new();
+
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|err| {
+            debug!("Unable to read the body for method override: {}", err);
+            StatusCode::BAD_REQUEST
+        })?;
+
+        if (bytes.len() + chunk.len()) as u64 > limit {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Ok(bytes)
+}
+
+fn allowed(method: &str) -> Option<Method> {
+    match method.to_uppercase().as_str() {
+        "PUT" => Some(Method::PUT),
+        "PATCH" => Some(Method::PATCH),
+        "DELETE" => Some(Method::DELETE),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::allowed;
+    use crate::hyper::Method;
+
+    #[test]
+    fn test_allowed() {
+        assert_eq!(allowed("delete"), Some(Method::DELETE));
+        assert_eq!(allowed("GET"), None);
+        assert_eq!(allowed("CONNECT"), None);
+    }
+}
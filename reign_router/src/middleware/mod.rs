@@ -6,6 +6,18 @@ use std::sync::Arc;
 
 /// Represents a type which can be used as a middleware.
 ///
+/// A middleware receives the request along with the rest of the chain, which contains the
+/// remaining middlewares and the handler. Calling [`Chain::run`] passes the request on to
+/// them, but a middleware is free to not call it and return its own response instead.
+/// The rest of the chain doesn't run at all in that case, which is how middlewares like
+/// [`RequireAuth`] and [`MaintenanceMode`] stop the request.
+///
+/// The response returned by [`Chain::run`] can also be inspected and modified before
+/// returning it, which is how middlewares like [`Runtime`] and [`SecurityHeaders`] add
+/// headers to the responses of the handlers.
+///
+/// # Examples
+///
 /// ```
 /// use reign::router::{futures::FutureExt, Chain, HandleFuture, Middleware, Request};
 ///
@@ -24,6 +36,33 @@ use std::sync::Arc;
 ///     }
 /// }
 /// ```
+///
+/// Middleware which stops the request unless it has the `x-api-key` header.
+///
+/// ```
+/// use reign::router::{
+///     futures::FutureExt,
+///     hyper::{Body, Response as HyperResponse, StatusCode},
+///     Chain, HandleFuture, Middleware, Request,
+/// };
+///
+/// pub struct ApiKey {}
+///
+/// impl Middleware for ApiKey {
+///     fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+///         async move {
+///             if !req.headers().contains_key("x-api-key") {
+///                 return Ok(HyperResponse::builder()
+///                     .status(StatusCode::FORBIDDEN)
+///                     .body(Body::empty())?);
+///             }
+///
+///             chain.run(req).await
+///         }
+///         .boxed()
+///     }
+/// }
+/// ```
 pub trait Middleware {
     /// Handler for the main logic in the middleware.
     fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m>;
@@ -47,17 +86,44 @@ impl<'a> Chain<'a> {
     }
 }
 
+mod body_limit;
 mod content_type;
 mod headers_default;
+mod logger;
+mod maintenance_mode;
+mod method_override;
+mod not_modified;
 mod request_logger;
 mod runtime;
+mod security_headers;
+mod timeout;
 
 #[cfg(feature = "cookie")]
 pub mod cookie;
+#[cfg(feature = "rate-limit")]
+mod rate_limit;
+#[cfg(feature = "request-id")]
+mod request_id;
+#[cfg(feature = "auth")]
+mod require_auth;
 #[cfg(feature = "session")]
 pub mod session;
 
+pub use body_limit::BodyLimit;
 pub use content_type::ContentType;
 pub use headers_default::HeadersDefault;
+pub use logger::Logger;
+pub use maintenance_mode::MaintenanceMode;
+pub use method_override::MethodOverride;
+pub use not_modified::NotModified;
 pub use request_logger::RequestLogger;
 pub use runtime::Runtime;
+pub use security_headers::SecurityHeaders;
+pub use timeout::Timeout;
+
+#[cfg(feature = "rate-limit")]
+pub use rate_limit::RateLimit;
+#[cfg(feature = "request-id")]
+pub use request_id::RequestId;
+#[cfg(feature = "auth")]
+pub use require_auth::RequireAuth;
@@ -0,0 +1,65 @@
+use crate::{
+    futures::FutureExt,
+    hyper::{
+        header::{IF_MODIFIED_SINCE, LAST_MODIFIED},
+        Body, Method, Response, StatusCode,
+    },
+    Chain, HandleFuture, Middleware, Request,
+};
+
+use chrono::DateTime;
+
+/// Responds with `304 Not Modified` when the response did not change since the time given
+/// in the `If-Modified-Since` header of the request.
+///
+/// This only applies to successful `GET` and `HEAD` requests whose response has a
+/// `Last-Modified` header, like the views rendered in `hot-reload` mode.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::NotModified, Router};
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("common").add(NotModified::default());
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NotModified {}
+
+impl Middleware for NotModified {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            let is_get = *req.method() == Method::GET || *req.method() == Method::HEAD;
+            let since = req
+                .headers()
+                .get(IF_MODIFIED_SINCE)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| DateTime::parse_from_rfc2822(v).ok());
+
+            let response = chain.run(req).await?;
+
+            if !is_get || response.status() != StatusCode::OK {
+                return Ok(response);
+            }
+
+            let modified = response
+                .headers()
+                .get(LAST_MODIFIED)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| DateTime::parse_from_rfc2822(v).ok());
+
+            match (since, modified) {
+                (Some(since), Some(modified)) if modified <= since => {
+                    let (mut parts, _) = response.into_parts();
+
+                    parts.status = StatusCode::NOT_MODIFIED;
+
+                    Ok(Response::from_parts(parts, Body::empty()))
+                }
+                _ => Ok(response),
+            }
+        }
+        .boxed()
+    }
+}
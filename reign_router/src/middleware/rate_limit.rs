@@ -0,0 +1,183 @@
+use crate::{
+    futures::FutureExt,
+    hyper::{header::RETRY_AFTER, Body, Response as HyperResponse, StatusCode},
+    Chain, HandleFuture, Middleware, Request,
+};
+
+use dashmap::DashMap;
+use log::warn;
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+// Number of requests after which the full buckets are removed
+const CLEANUP_INTERVAL: u64 = 1024;
+
+type KeyFn = dyn Fn(&Request) -> String + Send + Sync;
+
+fn default_key(req: &Request) -> String {
+    #[cfg(unix)]
+    if let Some(peer) = req.ext::<crate::UnixPeer>() {
+        return match peer.pid {
+            Some(pid) => format!("unix:{}:{}", peer.uid, pid),
+            None => format!("unix:{}", peer.uid),
+        };
+    }
+
+    req.ip().ip().to_string()
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Limits the number of requests from a client using a token bucket.
+///
+/// Each client gets a bucket of `capacity` tokens which is refilled over the given period,
+/// and every request takes a token from it. Requests are responded with
+/// `429 Too Many Requests` and a `Retry-After` header when the bucket is empty.
+///
+/// Clients are identified by their IP address by default, or by the user and process of the
+/// client for requests served over a Unix domain socket. The buckets are kept in memory,
+/// so they are not shared between multiple instances of the server.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::RateLimit, Router};
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("api").add(RateLimit::per_minute(60));
+/// }
+/// ```
+pub struct RateLimit {
+    capacity: f64,
+    rate: f64,
+    key: Arc<KeyFn>,
+    buckets: Arc<DashMap<String, Bucket>>,
+    requests: AtomicU64,
+}
+
+impl RateLimit {
+    /// Allow `capacity` requests which are refilled evenly over the given period.
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        assert!(capacity > 0, "rate limit capacity needs to be more than 0");
+        assert!(!period.is_zero(), "rate limit period can't be zero");
+
+        Self {
+            capacity: capacity as f64,
+            rate: capacity as f64 / period.as_secs_f64(),
+            key: Arc::new(default_key),
+            buckets: Arc::new(DashMap::new()),
+            requests: AtomicU64::new(0),
+        }
+    }
+
+    /// Allow the given number of requests per second.
+    pub fn per_second(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(1))
+    }
+
+    /// Allow the given number of requests per minute.
+    pub fn per_minute(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(60))
+    }
+
+    /// Allow the given number of requests per hour.
+    pub fn per_hour(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(3600))
+    }
+
+    /// Identify the clients by the key returned from the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{middleware::RateLimit, Router};
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.pipe("api").add(RateLimit::per_minute(60).key_by(|req| {
+    ///         req.headers()
+    ///             .get("x-api-token")
+    ///             .and_then(|x| x.to_str().ok())
+    ///             .unwrap_or_default()
+    ///             .to_string()
+    ///     }));
+    /// }
+    /// ```
+    pub fn key_by<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Request) -> String + Send + Sync + 'static,
+    {
+        self.key = Arc::new(f);
+        self
+    }
+
+    // Take a token from the bucket of the key, or return the time after which one is available
+    fn take(&self, key: String) -> Result<(), Duration> {
+        let now = Instant::now();
+
+        let mut bucket = self.buckets.entry(key).or_insert_with(|| Bucket {
+            tokens: self.capacity,
+            updated: now,
+        });
+
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+
+    // Buckets that would have been refilled by now are the same as new ones
+    fn cleanup(&self) {
+        if self.requests.fetch_add(1, Ordering::Relaxed) % CLEANUP_INTERVAL != 0 {
+            return;
+        }
+
+        let now = Instant::now();
+
+        self.buckets.retain(|_, bucket| {
+            let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+
+            bucket.tokens + elapsed * self.rate < self.capacity
+        });
+    }
+}
+
+impl Middleware for RateLimit {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            let key = (self.key)(req);
+
+            self.cleanup();
+
+            if let Err(retry) = self.take(key) {
+                warn!("{} {} - rate limit exceeded", req.method(), req.path());
+
+                // Round up so that the client doesn't retry too early
+                let seconds = retry.as_secs() + u64::from(retry.subsec_nanos() > 0);
+
+                return Ok(HyperResponse::builder()
+                    .status(StatusCode::TOO_MANY_REQUESTS)
+                    .header(RETRY_AFTER, seconds)
+                    .body(Body::empty())?);
+            }
+
+            chain.run(req).await
+        }
+        .boxed()
+    }
+}
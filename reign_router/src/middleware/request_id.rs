@@ -0,0 +1,120 @@
+use crate::{
+    futures::FutureExt,
+    hyper::header::{HeaderName, HeaderValue},
+    request::RequestIdValue,
+    Chain, HandleFuture, Middleware, Request,
+};
+
+use uuid::Uuid;
+
+// Longer incoming IDs are replaced to keep them out of the logs
+const MAX_LENGTH: usize = 200;
+
+/// Tags every request with an ID which can be retrieved with
+/// [`Request::request_id`](crate::Request::request_id) and is sent back in the response.
+///
+/// The ID is taken from the `x-request-id` header of the request by default, so that it
+/// can be propagated between services, and a UUID v4 is generated when it is missing.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::RequestId, Router};
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("common").add(RequestId::default());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestId {
+    header: HeaderName,
+    trust_incoming: bool,
+}
+
+impl Default for RequestId {
+    fn default() -> Self {
+        Self {
+            header: HeaderName::from_static("x-request-id"),
+            trust_incoming: true,
+        }
+    }
+}
+
+impl RequestId {
+    /// Use the given header name instead of `x-request-id`. The name is case insensitive.
+    ///
+    /// # Panics
+    ///
+    /// If the given name is not a valid header name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{middleware::RequestId, Router};
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.pipe("common").add(RequestId::default().header("x-correlation-id"));
+    /// }
+    /// ```
+    pub fn header(mut self, header: &str) -> Self {
+        self.header = HeaderName::from_bytes(header.as_bytes()).expect("invalid header name");
+        self
+    }
+
+    /// Whether to use the ID sent by the client. If disabled, an ID is always generated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{middleware::RequestId, Router};
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.pipe("common").add(RequestId::default().trust_incoming(false));
+    /// }
+    /// ```
+    pub fn trust_incoming(mut self, trust: bool) -> Self {
+        self.trust_incoming = trust;
+        self
+    }
+}
+
+impl Middleware for RequestId {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            let incoming = req
+                .headers()
+                .get(&self.header)
+                .filter(|_| self.trust_incoming)
+                .and_then(|x| x.to_str().ok())
+                .map(str::trim)
+                .filter(|x| !x.is_empty() && x.len() <= MAX_LENGTH)
+                .map(String::from);
+
+            let id = incoming.unwrap_or_else(|| Uuid::new_v4().to_string());
+            let value = HeaderValue::from_str(&id);
+
+            req.set_ext(RequestIdValue(id));
+
+            let mut response = chain.run(req).await?;
+
+            if let Ok(value) = value {
+                response.headers_mut().insert(self.header.clone(), value);
+            }
+
+            Ok(response)
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RequestId;
+
+    #[test]
+    fn test_header_with_uppercase() {
+        let request_id = RequestId::default().header("X-Correlation-Id");
+
+        assert_eq!(request_id.header, "x-correlation-id");
+    }
+}
@@ -4,11 +4,14 @@ use crate::{
 };
 
 use chrono::prelude::Utc;
-use log::{log, log_enabled, Level};
+use log::{log, log_enabled, warn, Level};
+
+use std::time::Duration;
 
 /// Logs the request and then response if possible.
 pub struct RequestLogger {
     level: Level,
+    slow: Option<Duration>,
 }
 
 impl RequestLogger {
@@ -27,47 +30,97 @@ impl RequestLogger {
     /// }
     /// ```
     pub fn new(level: Level) -> Self {
-        RequestLogger { level }
+        RequestLogger { level, slow: None }
+    }
+
+    /// Logs a warning for the requests that take longer than the given threshold to respond,
+    /// irrespective of the log level of the middleware. The warning has the path the request
+    /// was matched with, which groups the requests of the same route.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::{
+    ///     log::Level,
+    ///     router::{middleware::RequestLogger, Router},
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.pipe("common")
+    ///         .add(RequestLogger::new(Level::Debug).slow(Duration::from_millis(500)));
+    /// }
+    /// ```
+    pub fn slow(mut self, threshold: Duration) -> Self {
+        self.slow = Some(threshold);
+        self
     }
 }
 
 impl Middleware for RequestLogger {
     fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
-        if !log_enabled!(self.level) {
+        let enabled = log_enabled!(self.level);
+
+        if !enabled && self.slow.is_none() {
             return chain.run(req);
         }
 
         async move {
             let start = Utc::now();
+            let method = req.method().clone();
+            let path = req.path().to_string();
+            let route = req.matched_path().unwrap_or("-").to_string();
+
+            if enabled {
+                log!(target: "reign_router", self.level, "{} {}", method, path);
+            }
 
-            log!(
-                target: "reign_router",
-                self.level,
-                "{} {}",
-                req.method(),
-                req.uri().path(),
-            );
+            let result = chain.run(req).await;
 
-            let response = chain.run(req).await?;
+            let duration = Utc::now()
+                .signed_duration_since(start)
+                .num_microseconds()
+                .unwrap_or(0);
 
-            let length = response
-                .headers()
-                .get(CONTENT_LENGTH)
-                .map(|len| len.to_str().unwrap())
-                .unwrap_or("0");
+            // Errors are logged with the status they are responded with later
+            let (status, length) = match &result {
+                Ok(response) => (
+                    response.status(),
+                    response
+                        .headers()
+                        .get(CONTENT_LENGTH)
+                        .map(|len| len.to_str().unwrap())
+                        .unwrap_or("0"),
+                ),
+                Err(err) => (err.status(), "0"),
+            };
 
-            let duration = Utc::now().signed_duration_since(start).num_microseconds();
+            if enabled {
+                log!(
+                    target: "reign_router",
+                    self.level,
+                    "{} - {} - {}",
+                    status,
+                    length,
+                    dur_to_string(duration),
+                );
+            }
 
-            log!(
-                target: "reign_router",
-                self.level,
-                "{} - {} - {}",
-                response.status(),
-                length,
-                dur_to_string(duration.unwrap_or(0)),
-            );
+            if let Some(slow) = self.slow {
+                if duration > slow.as_micros() as i64 {
+                    warn!(
+                        target: "reign_router",
+                        "Slow request {} {} ({}) - {} - {}",
+                        method,
+                        path,
+                        route,
+                        status,
+                        dur_to_string(duration),
+                    );
+                }
+            }
 
-            Ok(response)
+            result
         }
         .boxed()
     }
@@ -0,0 +1,100 @@
+use crate::{
+    futures::FutureExt,
+    hyper::{header::WWW_AUTHENTICATE, Body, Response as HyperResponse, StatusCode},
+    Chain, HandleFuture, Middleware, Request,
+};
+
+use log::info;
+
+use std::sync::Arc;
+
+enum Validator {
+    Basic(Box<dyn Fn(&str, &str) -> bool + Send + Sync>),
+    Bearer(Box<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+/// Responds with `401 Unauthorized` and a `WWW-Authenticate` challenge when the request
+/// doesn't have valid credentials in the `Authorization` header.
+///
+/// The credentials are checked using the given function.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::RequireAuth, Router};
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("admin").add(RequireAuth::basic("admin", |username, password| {
+///         username == "admin" && password == "secret"
+///     }));
+///
+///     r.pipe("api").add(RequireAuth::bearer("api", |token| token == "secret"));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RequireAuth {
+    realm: String,
+    validator: Arc<Validator>,
+}
+
+impl RequireAuth {
+    /// Require a username and password using the `Basic` scheme.
+    pub fn basic<S, F>(realm: S, f: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&str, &str) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            realm: realm.into(),
+            validator: Arc::new(Validator::Basic(Box::new(f))),
+        }
+    }
+
+    /// Require a token using the `Bearer` scheme.
+    pub fn bearer<S, F>(realm: S, f: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            realm: realm.into(),
+            validator: Arc::new(Validator::Bearer(Box::new(f))),
+        }
+    }
+
+    fn is_valid(&self, req: &Request) -> bool {
+        match &*self.validator {
+            Validator::Basic(f) => req
+                .basic_auth()
+                .map_or(false, |(username, password)| f(&username, &password)),
+            Validator::Bearer(f) => req.bearer_token().map_or(false, |token| f(&token)),
+        }
+    }
+
+    fn challenge(&self) -> String {
+        let realm = self.realm.replace('\\', "\\\\").replace('"', "\\\"");
+
+        match &*self.validator {
+            Validator::Basic(_) => format!("Basic realm=\"{}\", charset=\"UTF-8\"", realm),
+            Validator::Bearer(_) => format!("Bearer realm=\"{}\"", realm),
+        }
+    }
+}
+
+impl Middleware for RequireAuth {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            if !self.is_valid(req) {
+                info!("{} {} - 401 Unauthorized", req.method(), req.path());
+
+                return Ok(HyperResponse::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .header(WWW_AUTHENTICATE, self.challenge())
+                    .body(Body::empty())?);
+            }
+
+            chain.run(req).await
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    futures::FutureExt,
+    hyper::header::{
+        HeaderValue, CONTENT_SECURITY_POLICY, CONTENT_TYPE, X_CONTENT_TYPE_OPTIONS, X_FRAME_OPTIONS,
+    },
+    Chain, HandleFuture, Middleware, Request,
+};
+
+/// Adds security related headers to the responses.
+///
+/// `X-Content-Type-Options` and `X-Frame-Options` are added to all responses while
+/// `Content-Security-Policy` is only added to `text/html` responses. Headers already set by
+/// the handler are not changed.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::SecurityHeaders, Router};
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("common").add(
+///         SecurityHeaders::default().content_security_policy("default-src 'self' cdn.reign.rs"),
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SecurityHeaders {
+    content_security_policy: HeaderValue,
+    frame_options: HeaderValue,
+}
+
+impl SecurityHeaders {
+    /// Set the `Content-Security-Policy` header. Defaults to `default-src 'self'`.
+    pub fn content_security_policy(mut self, policy: &str) -> Self {
+        self.content_security_policy =
+            HeaderValue::from_str(policy).expect("invalid content-security-policy header value");
+        self
+    }
+
+    /// Set the `X-Frame-Options` header. Defaults to `DENY`.
+    pub fn frame_options(mut self, value: &str) -> Self {
+        self.frame_options =
+            HeaderValue::from_str(value).expect("invalid x-frame-options header value");
+        self
+    }
+}
+
+impl Default for SecurityHeaders {
+    fn default() -> Self {
+        Self {
+            content_security_policy: HeaderValue::from_static("default-src 'self'"),
+            frame_options: HeaderValue::from_static("DENY"),
+        }
+    }
+}
+
+impl Middleware for SecurityHeaders {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            let mut response = chain.run(req).await?;
+
+            let is_html = response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|x| x.to_str().ok())
+                .and_then(|x| x.parse::<mime::Mime>().ok())
+                .map_or(false, |x| x.essence_str() == mime::TEXT_HTML.essence_str());
+
+            let headers = response.headers_mut();
+
+            headers
+                .entry(X_CONTENT_TYPE_OPTIONS)
+                .or_insert_with(|| HeaderValue::from_static("nosniff"));
+            headers
+                .entry(X_FRAME_OPTIONS)
+                .or_insert_with(|| self.frame_options.clone());
+
+            if is_html {
+                headers
+                    .entry(CONTENT_SECURITY_POLICY)
+                    .or_insert_with(|| self.content_security_policy.clone());
+            }
+
+            Ok(response)
+        }
+        .boxed()
+    }
+}
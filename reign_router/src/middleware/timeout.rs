@@ -0,0 +1,68 @@
+use crate::{
+    futures::FutureExt,
+    hyper::{Body, Response as HyperResponse, StatusCode},
+    Chain, HandleFuture, Middleware, Request,
+};
+
+use log::warn;
+use tokio::time::timeout;
+
+use std::time::Duration;
+
+/// Aborts the rest of the chain if it doesn't respond within the given duration.
+///
+/// The remaining middlewares and the handler are dropped when the duration elapses, and
+/// `503 Service Unavailable` is returned by default. Use [`Route::timeout`](crate::Route::timeout)
+/// to limit a single route instead.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{middleware::Timeout, Router};
+/// use std::time::Duration;
+///
+/// fn router(r: &mut Router) {
+///     r.pipe("api").add(Timeout::new(Duration::from_secs(5)));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Timeout {
+    duration: Duration,
+    status: StatusCode,
+}
+
+impl Timeout {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            status: StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+
+    /// Set the status of the response returned when the duration elapses.
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+impl Middleware for Timeout {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            let method = req.method().clone();
+            let path = req.path().to_string();
+
+            match timeout(self.duration, chain.run(req)).await {
+                Ok(result) => result,
+                Err(_) => {
+                    warn!("{} {} - timed out after {:?}", method, path, self.duration);
+
+                    Ok(HyperResponse::builder()
+                        .status(self.status)
+                        .body(Body::empty())?)
+                }
+            }
+        }
+        .boxed()
+    }
+}
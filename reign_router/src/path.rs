@@ -1,4 +1,24 @@
-// use percent_encoding::utf8_percent_encode;
+use crate::{Error, UrlError};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use regex::Regex;
+
+use std::collections::HashMap as Map;
+
+// Characters that need to be encoded in a path, except for `/` which is allowed in glob params
+const SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+pub(crate) type Names = Map<String, Path>;
 
 #[derive(Debug, Clone)]
 enum PathPart {
@@ -211,6 +231,87 @@ impl Path {
 
         regex.join("")
     }
+
+    // Route pattern with the params shown as placeholders, like `/users/{id}`
+    pub(crate) fn template(&self) -> String {
+        let mut template = String::new();
+
+        for part in &self.parts {
+            match part {
+                PathPart::Static(p) => {
+                    template.push('/');
+                    template.push_str(p);
+                }
+                PathPart::Param(p) | PathPart::ParamRegex(p, _) => {
+                    template.push_str(&format!("/{{{}}}", p))
+                }
+                PathPart::ParamOpt(p) | PathPart::ParamOptRegex(p, _) => {
+                    template.push_str(&format!("/{{{}?}}", p))
+                }
+            }
+        }
+
+        if template.is_empty() {
+            template.push('/');
+        }
+
+        template
+    }
+
+    pub(crate) fn join(&self, other: &Path) -> Path {
+        let mut parts = self.parts.clone();
+        parts.extend(other.parts.iter().cloned());
+
+        Path { parts }
+    }
+
+    pub(crate) fn url(&self, params: &[(&str, &str)]) -> Result<String, UrlError> {
+        let mut url = String::new();
+
+        for part in &self.parts {
+            let (name, regex, optional) = match part {
+                PathPart::Static(p) => {
+                    url.push('/');
+                    url.push_str(p);
+                    continue;
+                }
+                PathPart::Param(p) => (p, "[^/]+", false),
+                PathPart::ParamOpt(p) => (p, "[^/]+", true),
+                PathPart::ParamRegex(p, r) => (p, r.as_str(), false),
+                PathPart::ParamOptRegex(p, r) => (p, r.as_str(), true),
+            };
+
+            match params.iter().find(|(k, _)| *k == name.as_str()) {
+                Some((_, value)) => {
+                    let valid = Regex::new(&format!("^(?:{})$", regex))
+                        .map_or(false, |x| x.is_match(value));
+
+                    if !valid {
+                        return Err(UrlError::InvalidParam(name.clone()));
+                    }
+
+                    url.push('/');
+                    url.push_str(&utf8_percent_encode(value, SEGMENT).to_string());
+                }
+                None if optional => {}
+                None => return Err(UrlError::MissingParam(name.clone())),
+            }
+        }
+
+        if url.is_empty() {
+            url.push('/');
+        }
+
+        Ok(url)
+    }
+}
+
+pub(crate) fn url_for(names: &Names, name: &str, params: &[(&str, &str)]) -> Result<String, Error> {
+    let path = names
+        .get(name)
+        .ok_or_else(|| UrlError::RouteNotFound(name.into()))?;
+
+    Ok(path.url(params)?)
 }
 
 impl<'a> Into<Path> for &'a str {
@@ -296,4 +397,39 @@ mod test {
         let p = Path::new().path("foo").path("bar");
         assert_eq!(p.regex(), "/foo/bar");
     }
+
+    #[test]
+    fn test_template() {
+        let path = Path::new()
+            .path("users")
+            .param("id")
+            .param_opt_regex("tab", "[a-z]+");
+
+        assert_eq!(path.template(), "/users/{id}/{tab?}");
+        assert_eq!(Path::new().template(), "/");
+    }
+
+    #[test]
+    fn test_url() {
+        let p = Path::new().path("users").param("id").param_opt("tab");
+
+        assert_eq!(p.url(&[("id", "42")]).unwrap(), "/users/42");
+        assert_eq!(
+            p.url(&[("id", "42"), ("tab", "a b")]).unwrap(),
+            "/users/42/a%20b"
+        );
+        assert!(matches!(p.url(&[]), Err(UrlError::MissingParam(_))));
+        assert!(matches!(
+            p.url(&[("id", "4/2")]),
+            Err(UrlError::InvalidParam(_))
+        ));
+    }
+
+    #[test]
+    fn test_url_glob() {
+        let p = Path::new().path("tree").param_regex("path", ".+");
+
+        assert_eq!(p.url(&[("path", "a/b")]).unwrap(), "/tree/a/b");
+        assert_eq!(Path::new().url(&[]).unwrap(), "/");
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Middleware;
+use crate::{Chain, Constraint, HandleFuture, Middleware, Request};
 
 use std::sync::Arc;
 
@@ -17,12 +17,14 @@ pub(crate) type MiddlewareItem = Box<dyn Middleware + Send + Sync + 'static>;
 /// ```
 pub struct Pipe {
     pub(crate) middlewares: Vec<Arc<MiddlewareItem>>,
+    pub(crate) constraint: Option<Arc<Constraint>>,
 }
 
 impl Pipe {
     pub(crate) fn new() -> Self {
         Self {
             middlewares: vec![],
+            constraint: None,
         }
     }
 
@@ -35,4 +37,60 @@ impl Pipe {
         self.middlewares.push(Arc::new(Box::new(middleware)));
         self
     }
+
+    /// Define the constraint that decides whether the middlewares in this pipe run.
+    ///
+    /// When the constraint returns false, the middlewares are skipped and the request
+    /// continues down the chain as if the pipe was empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{hyper::Method, middleware::HeadersDefault, Router};
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.pipe("cache")
+    ///         .constraint(|req| *req.method() == Method::GET)
+    ///         .add(HeadersDefault::empty().add("cache-control", "max-age=60"));
+    /// }
+    /// ```
+    pub fn constraint<C>(&mut self, constraint: C) -> &mut Self
+    where
+        C: Fn(&Request) -> bool + Send + Sync + 'static,
+    {
+        self.constraint = Some(Arc::new(Box::new(constraint)));
+        self
+    }
+
+    pub(crate) fn refs(&self) -> Vec<Arc<MiddlewareItem>> {
+        if let Some(constraint) = &self.constraint {
+            self.middlewares
+                .iter()
+                .map(|middleware| {
+                    Arc::new(Box::new(Guarded {
+                        constraint: constraint.clone(),
+                        middleware: middleware.clone(),
+                    }) as MiddlewareItem)
+                })
+                .collect()
+        } else {
+            self.middlewares.clone()
+        }
+    }
+}
+
+/// Runs the inner middleware only when the pipe constraint matches the request.
+struct Guarded {
+    constraint: Arc<Constraint>,
+    middleware: Arc<MiddlewareItem>,
+}
+
+impl Middleware for Guarded {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        if (self.constraint)(req) {
+            self.middleware.handle(req, chain)
+        } else {
+            chain.run(req)
+        }
+    }
 }
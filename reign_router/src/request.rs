@@ -1,19 +1,46 @@
+#[cfg(feature = "form")]
+use crate::form::from_urlencoded;
+#[cfg(feature = "cookie")]
+use crate::middleware::cookie::decode_value;
 #[cfg(feature = "session")]
 use crate::middleware::session::SessionData;
 use crate::{
+    accept::preferred,
     hyper::{
-        body::{to_bytes, Bytes},
+        body::{Bytes, HttpBody},
+        header::{ACCEPT, CONTENT_LENGTH},
         http::{request::Parts, Extensions},
         Body, HeaderMap, Method, Request as HyperRequest, Uri, Version,
     },
-    Error, ParamError,
+    path::{url_for, Names},
+    BodyError, Error, FromRequest, ParamError, State,
+};
+#[cfg(feature = "websocket")]
+use crate::{
+    hyper::Response as HyperResponse,
+    websocket::{upgrade, WebSocketUpgrade},
 };
 
+#[cfg(feature = "auth")]
+use base64::decode;
+#[cfg(feature = "auth")]
+use hyper::header::AUTHORIZATION;
+#[cfg(any(feature = "json", feature = "form"))]
+use hyper::header::CONTENT_TYPE;
+#[cfg(feature = "cookie")]
+use hyper::header::COOKIE;
+use mime::Mime;
+#[cfg(any(feature = "json", feature = "form"))]
+use serde::de::DeserializeOwned;
 #[cfg(feature = "session")]
 use serde::{Deserialize, Serialize};
 use url::form_urlencoded::parse;
 
-use std::{collections::HashMap as Map, net::SocketAddr, str::FromStr};
+#[cfg(feature = "auth")]
+use std::str::from_utf8;
+use std::{collections::HashMap as Map, net::SocketAddr, str::FromStr, sync::Arc};
+
+pub(crate) const DEFAULT_BODY_LIMIT: u64 = 2 * 1024 * 1024;
 
 /// Request denotes the incoming request to the server and also acts as a state.
 ///
@@ -31,9 +58,53 @@ pub struct Request {
     parts: Parts,
     ip: SocketAddr,
     pub(crate) params: Map<String, String>,
-    pub(crate) query: Map<String, String>,
+    pub(crate) matched_path: Option<String>,
+    pub(crate) query: Map<String, Vec<String>>,
+    pub(crate) state: Arc<State>,
+    pub(crate) names: Arc<Names>,
+    pub(crate) body_limit: u64,
+}
+
+/// Snapshot of the request metadata without the body and the extensions.
+///
+/// Useful in middlewares which need to inspect the request after handing it off to the
+/// rest of the chain, like for logging.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{prelude::*, router::{futures::FutureExt, Chain, HandleFuture, Middleware}};
+///
+/// pub struct Logger {}
+///
+/// impl Middleware for Logger {
+///     fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+///         let parts = req.to_parts();
+///
+///         async move {
+///             let response = chain.run(req).await?;
+///
+///             println!("{} {} - {}", parts.method, parts.uri, response.status());
+///             Ok(response)
+///         }
+///         .boxed()
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestParts {
+    pub method: Method,
+    pub uri: Uri,
+    pub version: Version,
+    pub headers: HeaderMap,
+    pub ip: SocketAddr,
 }
 
+// ID of the request given by the `RequestId` middleware
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "request-id"), allow(dead_code))]
+pub(crate) struct RequestIdValue(pub(crate) String);
+
 impl Request {
     pub(crate) fn new(ip: SocketAddr, req: HyperRequest<Body>) -> Self {
         let (parts, body) = req.into_parts();
@@ -42,15 +113,17 @@ impl Request {
             parts,
             ip,
             params: Map::new(),
+            matched_path: None,
             query: Map::new(),
+            state: Arc::default(),
+            names: Arc::default(),
+            body_limit: DEFAULT_BODY_LIMIT,
         };
 
-        if let Some(query) = ret
-            .uri()
-            .query()
-            .map(|v| parse(v.as_bytes()).into_owned().collect())
-        {
-            ret.query = query;
+        if let Some(query) = ret.parts.uri.query() {
+            for (name, value) in parse(query.as_bytes()).into_owned() {
+                ret.query.entry(name).or_insert_with(Vec::new).push(value);
+            }
         }
 
         ret.parts.extensions.insert(body);
@@ -59,6 +132,9 @@ impl Request {
 
     /// Returns a reference to the associated remote IP socket address.
     ///
+    /// This is `0.0.0.0:0` for requests served over a Unix domain socket, which have the
+    /// credentials of the client as [`UnixPeer`](crate::UnixPeer) in their extensions instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -121,6 +197,84 @@ impl Request {
         &self.parts.uri
     }
 
+    /// Returns a snapshot of the method, URI, version, headers and the remote address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.to_parts().method.to_string())
+    /// }
+    /// ```
+    pub fn to_parts(&self) -> RequestParts {
+        RequestParts {
+            method: self.parts.method.clone(),
+            uri: self.parts.uri.clone(),
+            version: self.parts.version,
+            headers: self.parts.headers.clone(),
+            ip: self.ip,
+        }
+    }
+
+    /// Returns the path of the associated URI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.path().to_string())
+    /// }
+    /// ```
+    #[inline]
+    pub fn path(&self) -> &str {
+        self.parts.uri.path()
+    }
+
+    /// Returns the path of the associated URI along with the query string if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.full_path().to_string())
+    /// }
+    /// ```
+    #[inline]
+    pub fn full_path(&self) -> &str {
+        self.parts
+            .uri
+            .path_and_query()
+            .map(|x| x.as_str())
+            .unwrap_or_else(|| self.path())
+    }
+
+    /// Returns the pattern of the route that matched the request, including the scope
+    /// prefixes, like `/users/{id}`.
+    ///
+    /// Optional params are shown as `{id?}`. This is `None` when no route matched, for
+    /// example in the `not_found` handler. Useful for labelling metrics without exploding
+    /// their cardinality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.matched_path().unwrap_or_default().to_string())
+    /// }
+    /// ```
+    #[inline]
+    pub fn matched_path(&self) -> Option<&str> {
+        self.matched_path.as_deref()
+    }
+
     /// Returns a reference to the associated HeaderMap.
     ///
     /// # Examples
@@ -186,11 +340,141 @@ impl Request {
         &mut self.parts.extensions
     }
 
+    /// Retrieve the value of the given type stored in the request using [`Request::set_ext`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// struct CurrentUser {
+    ///     name: String,
+    /// }
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     match req.ext::<CurrentUser>() {
+    ///         Some(user) => Ok(user.name.clone()),
+    ///         None => Ok("Anonymous".into()),
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn ext<T>(&self) -> Option<&T>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.extensions().get()
+    }
+
+    /// Store a value in the request so that the rest of the middlewares and the handler
+    /// can retrieve it using [`Request::ext`].
+    ///
+    /// Only one value of each type can be stored, and the previous value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::{prelude::*, router::{futures::FutureExt, Chain, HandleFuture, Middleware}};
+    ///
+    /// struct CurrentUser {
+    ///     name: String,
+    /// }
+    ///
+    /// pub struct Auth {}
+    ///
+    /// impl Middleware for Auth {
+    ///     fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+    ///         async move {
+    ///             req.set_ext(CurrentUser { name: "reign".into() });
+    ///
+    ///             chain.run(req).await
+    ///         }
+    ///         .boxed()
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn set_ext<T>(&mut self, value: T) -> Option<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.extensions_mut().insert(value)
+    }
+
+    /// Retrieve the ID of the request given by [`RequestId`](crate::middleware::RequestId).
+    ///
+    /// Returns `None` if the middleware, which needs the `request-id` feature, did not run for
+    /// the request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.request_id().unwrap_or_default().to_string())
+    /// }
+    /// ```
+    #[inline]
+    pub fn request_id(&self) -> Option<&str> {
+        self.ext::<RequestIdValue>().map(|x| x.0.as_str())
+    }
+
+    /// Retrieve the application state of the given type registered using [`Router::state`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// struct Config {
+    ///     name: String,
+    /// }
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     if let Some(config) = req.state::<Config>() {
+    ///         Ok(config.name.clone())
+    ///     } else {
+    ///         Ok("No config".into())
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Router::state`]: crate::Router::state
+    pub fn state<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.state.get()
+    }
+
+    /// Build the url of the route with the given name, including the paths of all the
+    /// scopes it is under.
+    ///
+    /// Returns an error if the route doesn't exist, a required param is not given or a
+    /// param doesn't match its path segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.url_for("user_show", &[("id", "42")])?)
+    /// }
+    /// ```
+    pub fn url_for(&self, name: &str, params: &[(&str, &str)]) -> Result<String, Error> {
+        url_for(&self.names, name, params)
+    }
+
     /// Retrieve the request body.
     ///
     /// This consumes the body from the request and it will not be available for
     /// any other handlers after this.
     ///
+    /// If the client sent `Expect: 100-continue`, the interim `100 Continue` response is
+    /// only sent once the body is read, so middlewares can reject uploads before they are sent.
+    ///
     /// # Examples
     ///
     /// ```
@@ -206,15 +490,239 @@ impl Request {
     /// }
     /// ```
     pub async fn body(&mut self) -> Result<Option<Bytes>, Error> {
-        if let Some(body) = self.extensions_mut().remove::<Body>() {
-            Ok(Some(to_bytes(body).await?))
+        if self.extensions().get::<Body>().is_some() {
+            Ok(Some(self.body_bytes().await?))
         } else {
             Ok(None)
         }
     }
 
+    /// Retrieve the request body, which is empty if it was already consumed.
+    ///
+    /// This stops reading the body and returns
+    /// [`BodyError::TooLarge`] if it is larger than the limit set with
+    /// [`Router::max_body_size`](crate::Router::max_body_size), which defaults to `2mb`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(format!("{} bytes", req.body_bytes().await?.len()))
+    /// }
+    /// ```
+    pub async fn body_bytes(&mut self) -> Result<Bytes, Error> {
+        let limit = self.body_limit;
+
+        let length = self
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|x| x.to_str().ok())
+            .and_then(|x| x.parse::<u64>().ok());
+
+        if length.map_or(false, |x| x > limit) {
+            return Err(BodyError::TooLarge(limit).into());
+        }
+
+        let mut body = match self.body_stream() {
+            Some(body) => body,
+            None => return Ok(Bytes::new()),
+        };
+
+        let mut bytes = Vec::new();
+
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk?;
+
+            if (bytes.len() + chunk.len()) as u64 > limit {
+                return Err(BodyError::TooLarge(limit).into());
+            }
+
+            bytes.extend_from_slice(&chunk);
+        }
+
+        Ok(bytes.into())
+    }
+
+    /// Deserialize the JSON request body into the given type.
+    ///
+    /// The body is read using [`Request::body_bytes`]. Responds with
+    /// `415 Unsupported Media Type` if the request has a content-type which is not JSON
+    /// and with `400 Bad Request` if the body is not valid JSON for the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// async fn create(req: &mut Request) -> Result<impl Response, Error> {
+    ///     let user = req.json::<User>().await?;
+    ///
+    ///     Ok(user.name)
+    /// }
+    /// ```
+    #[cfg(feature = "json")]
+    pub async fn json<T>(&mut self) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.check_content_type("application/json", |x| x.ends_with("+json"))?;
+
+        let bytes = self.body_bytes().await?;
+
+        serde_json::from_slice(&bytes).map_err(|err| BodyError::Json(err).into())
+    }
+
+    /// Deserialize the `application/x-www-form-urlencoded` request body into the given type.
+    ///
+    /// The body is read using [`Request::body_bytes`]. Repeated fields can be deserialized
+    /// into [`Vec`] fields, while other fields use the first value of a repeated field.
+    /// Checkboxes can be deserialized into `bool` fields with `#[serde(default)]`.
+    /// Responds with `415 Unsupported Media Type` if the request has a content-type which
+    /// is not a form and with `400 Bad Request` if the body can't be deserialized into the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Login {
+    ///     username: String,
+    ///     password: String,
+    ///     #[serde(default)]
+    ///     remember: bool,
+    /// }
+    ///
+    /// async fn login(req: &mut Request) -> Result<impl Response, Error> {
+    ///     let login = req.form::<Login>().await?;
+    ///
+    ///     Ok(login.username)
+    /// }
+    /// ```
+    #[cfg(feature = "form")]
+    pub async fn form<T>(&mut self) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.check_content_type("application/x-www-form-urlencoded", |_| false)?;
+
+        let bytes = self.body_bytes().await?;
+
+        Ok(from_urlencoded(&bytes)?)
+    }
+
+    // Content-type is optional, but the body needs to be of the given type when it is sent
+    #[cfg(any(feature = "json", feature = "form"))]
+    fn check_content_type<F>(&self, expected: &'static str, other: F) -> Result<(), Error>
+    where
+        F: Fn(&str) -> bool,
+    {
+        if let Some(content_type) = self.headers().get(CONTENT_TYPE) {
+            let content_type = content_type.to_str()?.to_ascii_lowercase();
+            let essence = content_type.split(';').next().unwrap_or("").trim();
+
+            if essence != expected && !other(essence) {
+                return Err(BodyError::UnsupportedContentType(expected).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retrieve the request body without buffering it.
+    ///
+    /// This takes the body from the request and it will not be available for any other
+    /// handlers after this. The returned [`Body`] can be forwarded as is, for example
+    /// as the body of an outbound request when proxying.
+    ///
+    /// If the client sent `Expect: 100-continue`, the interim `100 Continue` response is
+    /// only sent once the body is read, so middlewares can reject uploads before they are sent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::{prelude::*, router::hyper::Response as HyperResponse};
+    ///
+    /// async fn echo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(HyperResponse::new(req.body_stream().unwrap_or_default()))
+    /// }
+    /// ```
+    pub fn body_stream(&mut self) -> Option<Body> {
+        self.extensions_mut().remove::<Body>()
+    }
+
+    /// Extract a type that implements [`FromRequest`] from the request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::{prelude::*, router::hyper::Method};
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     let method = req.extract::<Method>().await?;
+    ///     let body = req.extract::<String>().await?;
+    ///
+    ///     Ok(format!("{} {}", method, body))
+    /// }
+    /// ```
+    pub async fn extract<T>(&mut self) -> Result<T, Error>
+    where
+        T: FromRequest,
+    {
+        T::from_request(self).await
+    }
+
+    /// Upgrade the request to a WebSocket connection.
+    ///
+    /// Returns the `101 Switching Protocols` response that needs to be returned from the
+    /// handler and a future that resolves to the connection once the response is sent.
+    /// Responds with `400 Bad Request` if the request is not a valid WebSocket handshake.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::{
+    ///     prelude::*,
+    ///     router::{
+    ///         futures::{SinkExt, StreamExt},
+    ///         websocket::Message,
+    ///     },
+    /// };
+    ///
+    /// async fn echo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     let (response, upgrade) = req.upgrade_websocket()?;
+    ///
+    ///     tokio::spawn(async move {
+    ///         if let Ok(mut ws) = upgrade.await {
+    ///             while let Some(Ok(message)) = ws.next().await {
+    ///                 if let Message::Text(text) = message {
+    ///                     let _ = ws.send(Message::Text(text)).await;
+    ///                 }
+    ///             }
+    ///         }
+    ///     });
+    ///
+    ///     Ok(response)
+    /// }
+    /// ```
+    #[cfg(feature = "websocket")]
+    pub fn upgrade_websocket(&mut self) -> Result<(HyperResponse<Body>, WebSocketUpgrade), Error> {
+        upgrade(self)
+    }
+
     /// Retrieve the value of a query string parameter.
     ///
+    /// Returns the first value if the parameter is repeated.
+    ///
     /// # Examples
     ///
     /// ```
@@ -230,7 +738,248 @@ impl Request {
     /// ```
     #[inline]
     pub fn query(&self, name: &str) -> Option<&String> {
-        self.query.get(name)
+        self.query.get(name).and_then(|x| x.first())
+    }
+
+    /// Retrieve all the values of a query string parameter in the order they were given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.query_all("tag").join(","))
+    /// }
+    /// ```
+    pub fn query_all(&self, name: &str) -> Vec<&str> {
+        self.query
+            .get(name)
+            .map(|x| x.iter().map(|x| x.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns all the query string parameters along with all their values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(format!("{} params", req.query_map().len()))
+    /// }
+    /// ```
+    #[inline]
+    pub fn query_map(&self) -> &Map<String, Vec<String>> {
+        &self.query
+    }
+
+    /// Deserialize the query string into the given type.
+    ///
+    /// Absent params can be deserialized into [`Option`] fields and repeated params into
+    /// [`Vec`] fields, while other fields use the first value of a repeated param, same as
+    /// [`query`](Request::query). Responds with `400 Bad Request` if the query string can't be
+    /// deserialized into the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Pagination {
+    ///     page: u32,
+    ///     limit: Option<u32>,
+    /// }
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     let pagination = req.query_into::<Pagination>()?;
+    ///
+    ///     Ok(format!("page {} of {}", pagination.page, pagination.limit.unwrap_or(10)))
+    /// }
+    /// ```
+    #[cfg(feature = "form")]
+    pub fn query_into<T>(&self) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(from_urlencoded(
+            self.uri().query().unwrap_or("").as_bytes(),
+        )?)
+    }
+
+    /// Retrieve all the cookies sent with the request.
+    ///
+    /// The values are percent-decoded. If a cookie is sent more than once, the first value
+    /// is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(format!("{} cookies", req.cookies().len()))
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn cookies(&self) -> Map<String, String> {
+        let mut cookies = Map::new();
+
+        let pairs = self
+            .headers()
+            .get_all(COOKIE)
+            .iter()
+            .flat_map(|x| x.to_str())
+            .flat_map(|x| x.split(';'))
+            .flat_map(|x| x.split_once('='));
+
+        for (name, value) in pairs {
+            let name = name.trim();
+
+            if name.is_empty() {
+                continue;
+            }
+
+            let value = value.trim().trim_matches('"');
+            let value = decode_value(value);
+
+            cookies
+                .entry(name.to_string())
+                .or_insert_with(|| value.into_owned());
+        }
+
+        cookies
+    }
+
+    /// Retrieve the value of a cookie sent with the request.
+    ///
+    /// Use [`ResponseExt::cookie`](crate::ResponseExt::cookie) to set cookies on the response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.cookie("theme").unwrap_or_else(|| "light".into()))
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn cookie(&self, name: &str) -> Option<String> {
+        self.cookies().remove(name)
+    }
+
+    /// Check whether the given mime type is acceptable to the client according to the
+    /// `Accept` header.
+    ///
+    /// Anything is acceptable if the header is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     if req.accepts("text/html") {
+    ///         Ok(render("<h1>foo</h1>", 200)?)
+    ///     } else {
+    ///         Ok(json("foo", 200)?)
+    ///     }
+    /// }
+    /// ```
+    pub fn accepts(&self, mime: &str) -> bool {
+        self.preferred(&[mime]).is_some()
+    }
+
+    /// Choose the mime type most preferred by the client from the given ones using the
+    /// quality values and the wildcards in the `Accept` header.
+    ///
+    /// The mime types given earlier are chosen when they are equally preferred. Returns
+    /// `None` if none of them are acceptable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::{prelude::*, router::hyper::StatusCode};
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     match req.preferred(&["application/json", "text/html"]) {
+    ///         Some(mime) if mime.subtype() == "json" => Ok(json("foo", 200)?),
+    ///         Some(_) => Ok(render("<h1>foo</h1>", 200)?),
+    ///         None => Err(Error::Status(StatusCode::NOT_ACCEPTABLE)),
+    ///     }
+    /// }
+    /// ```
+    pub fn preferred(&self, offered: &[&str]) -> Option<Mime> {
+        let header = self.headers().get(ACCEPT).and_then(|x| x.to_str().ok());
+
+        preferred(header, offered)
+    }
+
+    /// Retrieve the username and password sent with the `Basic` scheme in the
+    /// `Authorization` header.
+    ///
+    /// Returns `None` if the header is missing or malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     match req.basic_auth() {
+    ///         Some((username, _)) => Ok(username),
+    ///         None => Ok("Anonymous".into()),
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "auth")]
+    pub fn basic_auth(&self) -> Option<(String, String)> {
+        let credentials = decode(self.authorization("Basic")?).ok()?;
+        let (username, password) = from_utf8(&credentials).ok()?.split_once(':')?;
+
+        Some((username.to_string(), password.to_string()))
+    }
+
+    /// Retrieve the token sent with the `Bearer` scheme in the `Authorization` header.
+    ///
+    /// Returns `None` if the header is missing or malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::prelude::*;
+    ///
+    /// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.bearer_token().unwrap_or_default())
+    /// }
+    /// ```
+    #[cfg(feature = "auth")]
+    pub fn bearer_token(&self) -> Option<String> {
+        Some(self.authorization("Bearer")?.to_string())
+    }
+
+    // Credentials of the `Authorization` header if it uses the given scheme
+    #[cfg(feature = "auth")]
+    fn authorization(&self, scheme: &str) -> Option<&str> {
+        let (name, credentials) = self
+            .headers()
+            .get(AUTHORIZATION)?
+            .to_str()
+            .ok()?
+            .trim()
+            .split_once(' ')?;
+
+        let credentials = credentials.trim();
+
+        if !name.eq_ignore_ascii_case(scheme) || credentials.is_empty() {
+            return None;
+        }
+
+        Some(credentials)
     }
 
     /// Retrieve the value of a required path parameter.
@@ -434,6 +1183,7 @@ impl Request {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hyper::body::to_bytes;
 
     fn req_param(val: &str) -> Request {
         let mut req = Request::new(
@@ -447,6 +1197,83 @@ mod tests {
         req
     }
 
+    #[test]
+    fn test_to_parts() {
+        let mut req = Request::new(
+            "10.10.10.10:80".parse().unwrap(),
+            HyperRequest::post("https://reign.rs/users?page=2")
+                .header("x-version", "1")
+                .body(Body::from("hello"))
+                .unwrap(),
+        );
+
+        let parts = req.to_parts();
+
+        assert_eq!(parts.method, Method::POST);
+        assert_eq!(parts.uri, "https://reign.rs/users?page=2");
+        assert_eq!(parts.headers.get("x-version").unwrap(), "1");
+        assert_eq!(parts.ip, "10.10.10.10:80".parse().unwrap());
+        assert!(req.body_stream().is_some());
+    }
+
+    #[test]
+    fn test_path() {
+        let req = Request::new(
+            "10.10.10.10:80".parse().unwrap(),
+            HyperRequest::get("https://reign.rs/users/1?page=2")
+                .body(Body::empty())
+                .unwrap(),
+        );
+
+        assert_eq!(req.path(), "/users/1");
+        assert_eq!(req.full_path(), "/users/1?page=2");
+    }
+
+    #[test]
+    fn test_path_without_query() {
+        let req = Request::new(
+            "10.10.10.10:80".parse().unwrap(),
+            HyperRequest::get("https://reign.rs")
+                .body(Body::empty())
+                .unwrap(),
+        );
+
+        assert_eq!(req.path(), "/");
+        assert_eq!(req.full_path(), "/");
+    }
+
+    #[tokio::test]
+    async fn test_body_stream() {
+        let mut req = Request::new(
+            "10.10.10.10:80".parse().unwrap(),
+            HyperRequest::post("https://reign.rs")
+                .body(Body::from("hello"))
+                .unwrap(),
+        );
+
+        let body = req.body_stream();
+
+        assert!(body.is_some());
+        assert_eq!(to_bytes(body.unwrap()).await.unwrap(), "hello");
+        assert!(req.body_stream().is_none());
+        assert!(matches!(req.body().await, Ok(None)));
+    }
+
+    #[tokio::test]
+    async fn test_extract() {
+        let mut req = Request::new(
+            "10.10.10.10:80".parse().unwrap(),
+            HyperRequest::post("https://reign.rs")
+                .body(Body::from("hello"))
+                .unwrap(),
+        );
+
+        assert_eq!(req.extract::<Method>().await.unwrap(), Method::POST);
+        assert_eq!(req.extract::<String>().await.unwrap(), "hello");
+        assert_eq!(req.extract::<String>().await.unwrap(), "");
+        assert!(matches!(req.extract::<Option<Uri>>().await, Ok(Some(_))));
+    }
+
     #[test]
     fn test_param() {
         let req = req_param("hey");
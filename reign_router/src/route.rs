@@ -1,19 +1,68 @@
-use crate::{hyper::Method, Handle, Path, Request};
+use crate::{
+    futures::FutureExt,
+    hyper::{
+        header::{HeaderValue, CACHE_CONTROL},
+        Method,
+    },
+    Chain, Handle, HandleFuture, Middleware, MiddlewareItem, Path, Request,
+};
 
-use std::sync::Arc;
+use std::{mem::take, sync::Arc, time::Duration};
 
 pub(crate) type Constraint = Box<dyn Fn(&Request) -> bool + Send + Sync + 'static>;
 
+pub(crate) const ALL_METHODS: &[Method] = &[
+    Method::GET,
+    Method::POST,
+    Method::PUT,
+    Method::PATCH,
+    Method::DELETE,
+    Method::HEAD,
+    Method::OPTIONS,
+    Method::TRACE,
+    Method::CONNECT,
+];
+
+/// Endpoint definition that can be built at runtime and added to a router.
+///
+/// This is useful when the routes are data-driven, for example coming from a config file
+/// or a database, and can't be defined statically in the router function.
+///
+/// The endpoints defined by [`Router::get`](crate::Router::get) and the others can be
+/// configured further using the [`RouteMut`] returned by them.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::{hyper::Method, Route, Router};
+/// # use reign::prelude::*;
+/// #
+/// # async fn foo(req: &mut Request) -> Result<impl Response, Error> { Ok("foo") }
+///
+/// fn router(r: &mut Router) {
+///     let pages = vec!["about", "contact"];
+///
+///     r.extend(
+///         pages
+///             .into_iter()
+///             .map(|page| Route::new(page).methods(&[Method::GET]).handle(foo)),
+///     );
+/// }
+/// ```
 #[derive(Default, Clone)]
-pub(crate) struct Route {
+pub struct Route {
     pub(crate) path: Path,
     pub(crate) methods: Vec<Method>,
     pub(crate) handle: Option<Arc<Box<dyn Handle>>>,
     pub(crate) constraint: Option<Arc<Constraint>>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) middlewares: Vec<Arc<MiddlewareItem>>,
+    pub(crate) name: Option<String>,
 }
 
 impl Route {
-    pub(crate) fn new<P>(path: P) -> Self
+    /// Create a route with the given path that allows all HTTP methods.
+    pub fn new<P>(path: P) -> Self
     where
         P: Into<Path>,
     {
@@ -23,12 +72,14 @@ impl Route {
         }
     }
 
-    pub(crate) fn methods(mut self, methods: &[Method]) -> Self {
+    /// Define the HTTP methods allowed by this route.
+    pub fn methods(mut self, methods: &[Method]) -> Self {
         self.methods = methods.to_vec();
         self
     }
 
-    pub(crate) fn handle<H>(mut self, handle: H) -> Self
+    /// Define the endpoint handler for this route.
+    pub fn handle<H>(mut self, handle: H) -> Self
     where
         H: Handle,
     {
@@ -36,7 +87,8 @@ impl Route {
         self
     }
 
-    pub(crate) fn constraint<C>(mut self, constraint: C) -> Self
+    /// Define the constraint that needs to return true for this route to be matched.
+    pub fn constraint<C>(mut self, constraint: C) -> Self
     where
         C: Fn(&Request) -> bool + Send + Sync + 'static,
     {
@@ -44,24 +96,219 @@ impl Route {
         self
     }
 
+    /// Define the name of this route which can be used to build its url.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{path as p, Router};
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn show(req: &mut Request) -> Result<impl Response, Error> { Ok("show") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.scope("users").to(|r| {
+    ///         r.get(p!(id), show).name("user_show");
+    ///     });
+    /// }
+    /// ```
+    pub fn name<S>(mut self, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Add the given middlewares that only run for this route.
+    ///
+    /// Route middlewares run in the given order after the middlewares of the pipes used by
+    /// the enclosing scopes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{middleware::HeadersDefault, Router};
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn admin(req: &mut Request) -> Result<impl Response, Error> { Ok("admin") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.get("admin", admin)
+    ///         .through([HeadersDefault::empty().add("cache-control", "no-store")]);
+    /// }
+    /// ```
+    pub fn through<I, M>(mut self, middlewares: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: Middleware + Send + Sync + 'static,
+    {
+        self.middlewares.extend(
+            middlewares
+                .into_iter()
+                .map(|x| Arc::new(Box::new(x) as MiddlewareItem)),
+        );
+        self
+    }
+
+    /// Define the maximum duration this route is allowed to take for responding.
+    ///
+    /// If the middlewares and the handler don't finish in time, the request is aborted and
+    /// `503 Service Unavailable` is returned. The timeout applies to all the middlewares of
+    /// the route, so a [`Timeout`](crate::middleware::Timeout) middleware used by the route
+    /// only responds if its duration is shorter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{hyper::Method, Route, Router};
+    /// # use reign::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # async fn report(req: &mut Request) -> Result<impl Response, Error> { Ok("report") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.extend(vec![Route::new("report")
+    ///         .methods(&[Method::GET])
+    ///         .timeout(Duration::from_secs(60))
+    ///         .handle(report)]);
+    /// }
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Define the `Cache-Control` header for the successful responses of this route.
+    ///
+    /// The header is not changed if the handler has already set it.
+    ///
+    /// # Panics
+    ///
+    /// If the given value is not a valid header value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{hyper::Method, Route, Router};
+    /// # use reign::prelude::*;
+    /// #
+    /// # async fn config(req: &mut Request) -> Result<impl Response, Error> { Ok("config") }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.extend(vec![Route::new("config")
+    ///         .methods(&[Method::GET])
+    ///         .cache_control("public, max-age=3600")
+    ///         .handle(config)]);
+    /// }
+    /// ```
+    pub fn cache_control(mut self, value: &str) -> Self {
+        self.middlewares.push(Arc::new(Box::new(CacheControl(
+            HeaderValue::from_str(value).expect("invalid cache-control header value"),
+        ))));
+        self
+    }
+
     pub(crate) fn regex(&self) -> (String, String) {
         let methods = if self.methods.is_empty() {
-            "^(?:GET|POST|PUT|PATCH|DELETE|HEAD|OPTIONS|TRACE|CONNECT)".into()
+            ALL_METHODS
         } else {
-            format!(
-                "^(?:{})",
-                self.methods
-                    .iter()
-                    .map(|x| x.as_str())
-                    .collect::<Vec<_>>()
-                    .join("|")
-            )
+            self.methods.as_slice()
         };
 
+        let methods = format!(
+            "^(?:{})",
+            methods
+                .iter()
+                .map(|x| x.as_str())
+                .collect::<Vec<_>>()
+                .join("|")
+        );
+
         (methods, format!("{}$", self.path.regex()))
     }
 }
 
+/// Endpoint defined by [`Router::get`](crate::Router::get) and the others.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::Router;
+/// # use reign::prelude::*;
+/// # use std::time::Duration;
+/// #
+/// # async fn report(req: &mut Request) -> Result<impl Response, Error> { Ok("report") }
+///
+/// fn router(r: &mut Router) {
+///     r.get("report", report)
+///         .name("report")
+///         .timeout(Duration::from_secs(60));
+/// }
+/// ```
+pub struct RouteMut<'a>(pub(crate) &'a mut Route);
+
+impl RouteMut<'_> {
+    /// Define the name of this route. See [`Route::name`].
+    pub fn name<S>(self, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.map(|x| x.name(name))
+    }
+
+    /// Add the given middlewares that only run for this route. See [`Route::through`].
+    pub fn through<I, M>(self, middlewares: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: Middleware + Send + Sync + 'static,
+    {
+        self.map(|x| x.through(middlewares))
+    }
+
+    /// Define the maximum duration this route is allowed to take for responding. See
+    /// [`Route::timeout`].
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.map(|x| x.timeout(timeout))
+    }
+
+    /// Define the `Cache-Control` header for the successful responses of this route. See
+    /// [`Route::cache_control`].
+    ///
+    /// # Panics
+    ///
+    /// If the given value is not a valid header value.
+    pub fn cache_control(self, value: &str) -> Self {
+        self.map(|x| x.cache_control(value))
+    }
+
+    fn map<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Route) -> Route,
+    {
+        *self.0 = f(take(self.0));
+        self
+    }
+}
+
+/// Sets the `Cache-Control` header defined on a route.
+struct CacheControl(HeaderValue);
+
+impl Middleware for CacheControl {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            let mut response = chain.run(req).await?;
+
+            if response.status().is_success() && !response.headers().contains_key(CACHE_CONTROL) {
+                response.headers_mut().insert(CACHE_CONTROL, self.0.clone());
+            }
+
+            Ok(response)
+        }
+        .boxed()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,4 +1,4 @@
-use crate::{Constraint, Path, Pipe, Request, RouteRef, Router};
+use crate::{host::Host, Constraint, Path, Pipe, Request, RouteRef, Router};
 
 use std::{collections::HashMap as Map, sync::Arc};
 
@@ -40,6 +40,7 @@ pub struct Scope {
     pub(crate) pipes: Vec<String>,
     pub(crate) router: Router,
     pub(crate) constraint: Option<Arc<Constraint>>,
+    pub(crate) host: Option<Arc<Host>>,
 }
 
 impl Scope {
@@ -55,6 +56,9 @@ impl Scope {
 
     /// Define the middleware pipes that run for all the routes under this scope.
     ///
+    /// The pipes need to be defined in this scope or one of the enclosing scopes, otherwise
+    /// building the service panics.
+    ///
     /// # Examples
     ///
     /// ```
@@ -135,6 +139,33 @@ impl Scope {
         self
     }
 
+    /// Restrict the routes under this scope to requests with a matching `Host` header.
+    ///
+    /// A label of the pattern can be a named capture like `{tenant}` which is available
+    /// as a param, or `*` which matches any label. Matching is case-insensitive and
+    /// ignores the port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::Router;
+    /// # use reign::prelude::*;
+    ///
+    /// async fn dashboard(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(req.param::<String>("tenant")?)
+    /// }
+    ///
+    /// fn router(r: &mut Router) {
+    ///     r.scope("app").host("{tenant}.example.com").to(|r| {
+    ///         r.get("dashboard", dashboard);
+    ///     });
+    /// }
+    /// ```
+    pub fn host(&mut self, pattern: &str) -> &mut Self {
+        self.host = Some(Arc::new(Host::new(pattern)));
+        self
+    }
+
     pub(crate) fn regex(&self) -> (String, Vec<(String, String)>) {
         (self.path.regex(), self.router.regex())
     }
@@ -142,9 +173,15 @@ impl Scope {
     pub(crate) fn refs(
         &self,
         upper_pipes: Map<&String, &Pipe>,
-    ) -> (Option<Arc<Constraint>>, Vec<RouteRef>, Vec<String>) {
+    ) -> (
+        Option<Arc<Constraint>>,
+        Option<Arc<Host>>,
+        Vec<RouteRef>,
+        Vec<String>,
+    ) {
         (
             self.constraint.clone(),
+            self.host.clone(),
             self.router.refs(upper_pipes),
             self.pipes.clone(),
         )
@@ -1,44 +1,136 @@
 use crate::{
+    futures::{
+        future::{ok, BoxFuture, Ready},
+        FutureExt,
+    },
+    host::Host,
     hyper::{
-        http::Error as HttpError, Body, Request as HyperRequest, Response as HyperResponse,
-        StatusCode,
+        body::HttpBody,
+        header::{ALLOW, CONTENT_LENGTH, TRANSFER_ENCODING},
+        http::Error as HttpError,
+        server::{conn::AddrStream, Builder},
+        service::Service as HyperService,
+        Body, Method, Request as HyperRequest, Response as HyperResponse, StatusCode,
     },
-    Chain, Constraint, Handle, MiddlewareItem, Request, Response, Router, INTERNAL_ERR,
+    middleware::Timeout,
+    path::{url_for, Names},
+    request::DEFAULT_BODY_LIMIT,
+    route::ALL_METHODS,
+    Chain, Constraint, Error, Handle, MiddlewareItem, Path, Request, Response, Router, State,
+    INTERNAL_ERR,
 };
 
 use log::{debug, error, info, trace};
 use regex::{Regex, RegexSet};
 
-use std::{collections::HashMap as Map, net::SocketAddr, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashMap as Map, HashSet},
+    convert::Infallible,
+    net::SocketAddr,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 pub(crate) struct RouteRef {
+    pub(crate) path: Path,
     pub(crate) handle: Option<Arc<Box<dyn Handle>>>,
     pub(crate) middlewares: Vec<Arc<MiddlewareItem>>,
     pub(crate) constraints: Vec<Option<Arc<Constraint>>>,
+    pub(crate) methods: Vec<Method>,
+    pub(crate) hosts: Vec<Arc<Host>>,
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl RouteRef {
+    // The timeout of the route wraps all the middlewares it goes through
+    fn apply_timeout(mut self) -> Self {
+        if let Some(duration) = self.timeout {
+            self.middlewares
+                .insert(0, Arc::new(Box::new(Timeout::new(duration))));
+        }
+
+        self
+    }
+
+    // Host params are added to the request params when all the hosts match
+    fn matches_host(&self, request: &mut Request) -> bool {
+        for host in &self.hosts {
+            match host.captures(request) {
+                Some(params) => request.params.extend(params),
+                None => return false,
+            }
+        }
+
+        true
+    }
+
+    fn satisfies(&self, request: &Request) -> bool {
+        self.constraints
+            .iter()
+            .flatten()
+            .all(|constraint| constraint(request))
+    }
 }
 
 /// Thread safe structure that optimizes the given router for responding to requests.
+///
+/// It implements [`hyper::service::Service`] for incoming connections and can be given
+/// directly to a custom [`hyper::Server`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use reign::router::{hyper::Server, service, Router};
+///
+/// fn router(r: &mut Router) {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     Server::bind(&"127.0.0.1:8080".parse().unwrap())
+///         .serve(service(router))
+///         .await
+///         .unwrap();
+/// }
+/// ```
 #[derive(Clone)]
 pub struct Service {
     router: Arc<Router>,
     regexes: Arc<Vec<Regex>>,
     regex_set: Arc<RegexSet>,
+    path_set: Arc<RegexSet>,
     refs: Arc<Vec<RouteRef>>,
+    state: Arc<State>,
+    names: Arc<Names>,
 }
 
 impl Service {
     pub(crate) fn new(router: Router) -> Self {
-        let refs = router.refs(Map::new());
+        let refs = router
+            .refs(Map::new())
+            .into_iter()
+            .map(RouteRef::apply_timeout)
+            .collect::<Vec<_>>();
 
-        let regexes = router
-            .regex()
+        let regexes = router.regex();
+        let paths = regexes
+            .iter()
+            .map(|x| format!("^{}", x.1))
+            .collect::<Vec<_>>();
+        let regexes = regexes
             .iter()
             .map(|x| format!("{}{}", x.0, x.1))
             .collect::<Vec<_>>();
 
         debug!("Route regexes: {:?}", regexes);
 
+        if cfg!(debug_assertions) && !router.allow_duplicates {
+            check_duplicates(&regexes, &refs);
+        }
+
         Self {
+            state: Arc::new(router.state.clone()),
+            names: Arc::new(router.names()),
             router: Arc::new(router),
             regexes: Arc::new(
                 regexes
@@ -47,10 +139,44 @@ impl Service {
                     .collect(),
             ),
             regex_set: Arc::new(RegexSet::new(regexes).expect(INTERNAL_ERR)),
+            path_set: Arc::new(RegexSet::new(paths).expect(INTERNAL_ERR)),
             refs: Arc::new(refs),
         }
     }
 
+    // Apply the connection limits defined on the router to the server
+    pub(crate) fn configure<I>(&self, mut builder: Builder<I>) -> Builder<I> {
+        if let Some(size) = self.router.max_header_size {
+            builder = builder.http1_max_buf_size(size);
+        }
+
+        builder
+    }
+
+    /// Build the url of the route with the given name, including the paths of all the
+    /// scopes it is under.
+    pub fn url_for(&self, name: &str, params: &[(&str, &str)]) -> Result<String, Error> {
+        url_for(&self.names, name, params)
+    }
+
+    /// Bind the service to the remote address of a connection.
+    ///
+    /// The returned service implements [`hyper::service::Service`] for requests, which
+    /// makes it usable with `tower` layers or when nesting under another service.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::router::{service, Router};
+    ///
+    /// fn router(r: &mut Router) {}
+    ///
+    /// let service = service(router).with_remote_addr("10.10.10.10:80".parse().unwrap());
+    /// ```
+    pub fn with_remote_addr(self, ip: SocketAddr) -> RemoteService {
+        RemoteService { service: self, ip }
+    }
+
     /// Respond to a given [`hyper::Request`] and IP address.
     ///
     /// # Examples
@@ -82,66 +208,210 @@ impl Service {
     ) -> Result<HyperResponse<Body>, HttpError> {
         trace!("Incoming request to router");
 
-        let to_match = format!("{}{}", req.method().as_str(), req.uri().path());
+        if let Some(count) = self.router.max_headers {
+            if req.headers().len() > count {
+                info!(
+                    "{} {} - 431 Request Header Fields Too Large",
+                    req.method(),
+                    req.uri().path()
+                );
+
+                return HyperResponse::builder()
+                    .status(StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE)
+                    .body(Body::empty());
+            }
+        }
+
+        let body_limit = self.router.max_body_size.unwrap_or(DEFAULT_BODY_LIMIT);
+
+        let req = match &self.router.method_override {
+            Some(method_override) => match method_override.apply(req, body_limit).await {
+                Ok(req) => req,
+                Err(status) => {
+                    return HyperResponse::builder().status(status).body(Body::empty());
+                }
+            },
+            None => req,
+        };
+
+        let path = req.uri().path().trim_end_matches('/').to_string();
+        let to_match = format!("{}{}", req.method().as_str(), path);
         let to_match = to_match.trim_end_matches('/');
-        let matches = self.regex_set.matches(to_match);
 
         let mut request = Request::new(ip, req);
+        request.state = self.state.clone();
+        request.names = self.names.clone();
+
+        request.body_limit = body_limit;
+
+        if let Some((handle, route)) = self.find(to_match, &mut request) {
+            request.matched_path = Some(route.path.template());
 
-        for m in matches {
-            let regex = self.regexes.get(m).expect(INTERNAL_ERR);
+            return Self::run(handle, request, route).await;
+        }
+
+        if self.router.auto_head && request.method() == Method::HEAD {
+            let to_match = format!("{}{}", Method::GET.as_str(), path);
+
+            if let Some((handle, route)) = self.find(&to_match, &mut request) {
+                debug!("Responding to HEAD with the GET endpoint");
+
+                request.matched_path = Some(route.path.template());
+
+                let response = Self::run(handle, request, route).await?;
+
+                return Ok(strip_body(normalize_content_length(response, false)));
+            }
+        }
+
+        let mut allowed = self.allowed_methods(&path, &mut request);
+
+        if !allowed.is_empty() && self.router.auto_options && request.method() == Method::OPTIONS {
+            debug!("Responding to OPTIONS with allowed methods {:?}", allowed);
 
-            debug!("Checking regex: {:?}", regex);
+            allowed.push("OPTIONS");
+            allowed.sort_unstable();
+            allowed.dedup();
 
-            let mut params = Map::new();
-            let captures = regex.captures(to_match).expect(INTERNAL_ERR);
+            return HyperResponse::builder()
+                .status(StatusCode::NO_CONTENT)
+                .header(ALLOW, allowed.join(", "))
+                .body(Body::empty());
+        }
+
+        if !allowed.is_empty() {
+            info!(
+                "{} {} - 405 Method Not Allowed",
+                request.method(),
+                request.path()
+            );
+
+            let allowed = allowed.join(", ");
 
-            for name in regex.capture_names() {
-                if let Some(name) = name {
-                    if let Some(value) = captures.name(name) {
-                        params.insert(name.to_string(), value.as_str().to_string());
-                    }
+            let mut response = match &self.router.method_not_allowed {
+                Some(handle) => {
+                    Self::fallback(handle, request, StatusCode::METHOD_NOT_ALLOWED).await?
                 }
+                None => HyperResponse::builder()
+                    .status(StatusCode::METHOD_NOT_ALLOWED)
+                    .body(Body::empty())?,
+            };
+
+            if !response.headers().contains_key(ALLOW) {
+                response.headers_mut().insert(ALLOW, allowed.parse()?);
             }
 
-            debug!("Params extracted: {:?}", params);
+            return Ok(response);
+        }
 
-            request.params = params;
+        info!("{} {} - 404 Not Found", request.method(), request.path());
 
-            if let Some(route) = self.refs.get(m) {
-                let mut matched = true;
+        match &self.router.not_found {
+            Some(handle) => Self::fallback(handle, request, StatusCode::NOT_FOUND).await,
+            None => HyperResponse::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Body::empty()),
+        }
+    }
 
-                for constraint in &route.constraints {
-                    if let Some(constraint) = constraint {
-                        matched = constraint(&request);
+    // Run the handler registered for unmatched requests, using the given status
+    // unless the handler chose one
+    async fn fallback(
+        handle: &Arc<Box<dyn Handle>>,
+        mut request: Request,
+        status: StatusCode,
+    ) -> Result<HyperResponse<Body>, HttpError> {
+        let head = request.method() == Method::HEAD;
+
+        let mut response = match handle.call(&mut request).await {
+            Ok(r) => r,
+            Err(err) => {
+                error!("{}", err);
+                err.respond()?
+            }
+        };
+
+        if response.status() == StatusCode::OK {
+            *response.status_mut() = status;
+        }
+
+        Ok(normalize_content_length(response, head))
+    }
 
-                        if !matched {
-                            break;
-                        }
-                    }
+    fn params(&self, m: usize, to_match: &str) -> Map<String, String> {
+        let regex = self.regexes.get(m).expect(INTERNAL_ERR);
+
+        debug!("Checking regex: {:?}", regex);
+
+        let mut params = Map::new();
+        let captures = regex.captures(to_match).expect(INTERNAL_ERR);
+
+        for name in regex.capture_names() {
+            if let Some(name) = name {
+                if let Some(value) = captures.name(name) {
+                    params.insert(name.to_string(), value.as_str().to_string());
                 }
+            }
+        }
 
-                if !matched {
+        debug!("Params extracted: {:?}", params);
+
+        params
+    }
+
+    // First route matching the given method and path whose hosts and constraints are satisfied
+    fn find(
+        &self,
+        to_match: &str,
+        request: &mut Request,
+    ) -> Option<(&Arc<Box<dyn Handle>>, &RouteRef)> {
+        for m in self.regex_set.matches(to_match) {
+            request.params = self.params(m, to_match);
+
+            if let Some(route) = self.refs.get(m) {
+                if !route.matches_host(request) || !route.satisfies(request) {
                     continue;
                 }
 
                 if let Some(handle) = &route.handle {
-                    return Self::run(handle, request, route).await;
+                    return Some((handle, route));
                 }
             }
         }
 
-        info!(
-            "{} {} - 404 Not Found",
-            request.method(),
-            request.uri().path()
-        );
+        None
+    }
+
+    // Sorted union of the methods of all the routes that match the given path
+    fn allowed_methods(&self, path: &str, request: &mut Request) -> Vec<&str> {
+        let mut allowed = BTreeSet::new();
+
+        for m in self.path_set.matches(path) {
+            let route = self.refs.get(m).expect(INTERNAL_ERR);
+
+            if route.handle.is_none() {
+                continue;
+            }
+
+            let methods = if route.methods.is_empty() {
+                ALL_METHODS
+            } else {
+                route.methods.as_slice()
+            };
+
+            // Any of the methods of the route can be used to extract the params
+            request.params = self.params(m, &format!("{}{}", methods[0].as_str(), path));
+
+            if route.matches_host(request) && route.satisfies(request) {
+                allowed.extend(methods.iter().map(|x| x.as_str()));
+            }
+        }
 
-        // TODO: Check for 405 and support custom error handler through post middleware
-        // Can make this a special error or make a special middleware pipeline for errors
-        Ok(HyperResponse::builder()
-            .status(StatusCode::NOT_FOUND)
-            .body(Body::empty())?)
+        if self.router.auto_head && allowed.contains(Method::GET.as_str()) {
+            allowed.insert(Method::HEAD.as_str());
+        }
+
+        allowed.into_iter().collect()
     }
 
     async fn run(
@@ -154,13 +424,124 @@ impl Service {
             middlewares: &route.middlewares,
         };
 
-        match chain.run(&mut request).await {
-            Ok(r) => Ok(r),
+        let head = request.method() == Method::HEAD;
+
+        let response = match chain.run(&mut request).await {
+            Ok(r) => r,
             Err(err) => {
                 error!("{}", err);
-                err.respond()
+                err.respond()?
             }
+        };
+
+        Ok(normalize_content_length(response, head))
+    }
+}
+
+// Handlers can set a `Content-Length` that doesn't match the body they return, which
+// makes hyper fail the connection halfway through. Correct it when the body size is
+// known and drop it when the body is sent with a transfer encoding.
+fn normalize_content_length(mut response: HyperResponse<Body>, head: bool) -> HyperResponse<Body> {
+    let status = response.status();
+
+    if head || status == StatusCode::NO_CONTENT || status == StatusCode::NOT_MODIFIED {
+        return response;
+    }
+
+    if response.headers().contains_key(TRANSFER_ENCODING) {
+        if response.headers_mut().remove(CONTENT_LENGTH).is_some() {
+            debug!("Dropped content-length from response with transfer-encoding");
+        }
+
+        return response;
+    }
+
+    if let Some(size) = response.body().size_hint().exact() {
+        let expected = size.to_string();
+        let headers = response.headers_mut();
+
+        match headers.get(CONTENT_LENGTH) {
+            Some(value) if value.as_bytes() != expected.as_bytes() => {
+                debug!("Corrected content-length from {:?} to {}", value, expected);
+
+                headers.insert(CONTENT_LENGTH, size.into());
+            }
+            _ => {}
+        }
+    }
+
+    response
+}
+
+// Drop the body of a response generated for a `HEAD` request by a `GET` endpoint while
+// keeping the size of the body in `Content-Length`.
+fn strip_body(response: HyperResponse<Body>) -> HyperResponse<Body> {
+    let (mut parts, body) = response.into_parts();
+
+    if !parts.headers.contains_key(CONTENT_LENGTH) && !parts.headers.contains_key(TRANSFER_ENCODING)
+    {
+        if let Some(size) = body.size_hint().exact() {
+            parts.headers.insert(CONTENT_LENGTH, size.into());
+        }
+    }
+
+    HyperResponse::from_parts(parts, Body::empty())
+}
+
+// Routes without constraints or hosts that have the same method and path shadow each other
+fn check_duplicates(regexes: &[String], refs: &[RouteRef]) {
+    let mut seen = HashSet::new();
+
+    for (regex, route) in regexes.iter().zip(refs) {
+        if route.constraints.iter().any(|x| x.is_some()) || !route.hosts.is_empty() {
+            continue;
         }
+
+        if !seen.insert(regex) {
+            panic!(
+                "route `{}` is defined more than once, use `Router::allow_duplicates` if this is intended",
+                regex
+            );
+        }
+    }
+}
+
+impl<'a> HyperService<&'a AddrStream> for Service {
+    type Response = RemoteService;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, socket: &'a AddrStream) -> Self::Future {
+        trace!("Incoming request to service function");
+
+        ok(self.clone().with_remote_addr(socket.remote_addr()))
+    }
+}
+
+/// Router service bound to the remote address of a connection.
+///
+/// Created by [`Service::with_remote_addr`].
+#[derive(Clone)]
+pub struct RemoteService {
+    service: Service,
+    ip: SocketAddr,
+}
+
+impl HyperService<HyperRequest<Body>> for RemoteService {
+    type Response = HyperResponse<Body>;
+    type Error = HttpError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: HyperRequest<Body>) -> Self::Future {
+        self.service.clone().call(req, self.ip).boxed()
     }
 }
 
@@ -0,0 +1,193 @@
+use crate::{
+    futures::{
+        future::ready,
+        stream::{once, select, unfold, Stream, StreamExt},
+    },
+    hyper::{
+        header::{CACHE_CONTROL, CONTENT_TYPE},
+        http::Error as HttpError,
+        Body, Response as HyperResponse,
+    },
+    Response,
+};
+
+use tokio::time::{interval_at, Instant};
+
+use std::{
+    convert::Infallible,
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
+};
+
+/// Event sent to the client by [`Sse`].
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::SseEvent;
+/// use std::time::Duration;
+///
+/// let event = SseEvent::new()
+///     .event("message")
+///     .id("1")
+///     .retry(Duration::from_secs(5))
+///     .data("first line\nsecond line");
+///
+/// assert_eq!(
+///     event.to_string(),
+///     "event: message\nid: 1\nretry: 5000\ndata: first line\ndata: second line\n\n"
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SseEvent {
+    data: Option<String>,
+    event: Option<String>,
+    id: Option<String>,
+    retry: Option<Duration>,
+}
+
+impl SseEvent {
+    /// Create an empty event.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the data of the event. Each line of it is sent as a separate `data` field.
+    pub fn data<S>(mut self, data: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Set the type of the event which is used by the client to dispatch it.
+    pub fn event<S>(mut self, event: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.event = Some(event.into());
+        self
+    }
+
+    /// Set the id of the event which is sent back by the client when reconnecting.
+    pub fn id<S>(mut self, id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the time the client waits before reconnecting when the connection is lost.
+    pub fn retry(mut self, retry: Duration) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+}
+
+impl Display for SseEvent {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        // Line breaks would end the field early
+        let single = |x: &str| x.replace(['\r', '\n'], "");
+
+        if let Some(event) = &self.event {
+            writeln!(f, "event: {}", single(event))?;
+        }
+
+        if let Some(id) = &self.id {
+            writeln!(f, "id: {}", single(id))?;
+        }
+
+        if let Some(retry) = &self.retry {
+            writeln!(f, "retry: {}", retry.as_millis())?;
+        }
+
+        if let Some(data) = &self.data {
+            for line in data.lines() {
+                writeln!(f, "data: {}", line)?;
+            }
+        }
+
+        writeln!(f)
+    }
+}
+
+/// Server-Sent Events response which sends the events of the stream as they are produced.
+///
+/// The response is sent with content-type set as `text/event-stream`. A comment is sent
+/// every `15` seconds by default to keep the connection alive, and the response ends
+/// when the stream ends.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{
+///     prelude::*,
+///     router::{futures::stream::iter, Sse, SseEvent},
+/// };
+///
+/// async fn events(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(Sse::new(iter(vec![
+///         SseEvent::new().data("hello"),
+///         SseEvent::new().event("done").data("bye"),
+///     ])))
+/// }
+/// ```
+pub struct Sse<S> {
+    stream: S,
+    keep_alive: Option<Duration>,
+}
+
+impl<S> Sse<S>
+where
+    S: Stream<Item = SseEvent> + Send + 'static,
+{
+    /// Create the response from the given stream of events.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            keep_alive: Some(Duration::from_secs(15)),
+        }
+    }
+
+    /// Set the interval at which the keep alive comments are sent. Disabled if `None`.
+    pub fn keep_alive(mut self, keep_alive: Option<Duration>) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+}
+
+impl<S> Response for Sse<S>
+where
+    S: Stream<Item = SseEvent> + Send + 'static,
+{
+    fn respond(self) -> Result<HyperResponse<Body>, HttpError> {
+        let events = self.stream.map(|x| x.to_string());
+
+        let body = match self.keep_alive {
+            Some(period) => {
+                let keep_alive = unfold(
+                    interval_at(Instant::now() + period, period),
+                    |mut x| async move {
+                        x.tick().await;
+                        Some((Some(":\n\n".to_string()), x))
+                    },
+                );
+
+                // The keep alive stream never ends, so mark the end of the events with `None`
+                select(events.map(Some).chain(once(ready(None))), keep_alive)
+                    .take_while(|x| ready(x.is_some()))
+                    .map(|x| Ok::<_, Infallible>(x.unwrap_or_default()))
+                    .boxed()
+            }
+            None => events.map(Ok::<_, Infallible>).boxed(),
+        };
+
+        HyperResponse::builder()
+            .header(CONTENT_TYPE, mime::TEXT_EVENT_STREAM.as_ref())
+            .header(CACHE_CONTROL, "no-cache")
+            .body(Body::wrap_stream(body))
+    }
+}
@@ -0,0 +1,57 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap as Map,
+    fmt,
+    sync::Arc,
+};
+
+/// Application state shared by all the requests, keyed by its type.
+#[derive(Default, Clone)]
+pub(crate) struct State {
+    values: Map<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl State {
+    pub(crate) fn insert<T>(&mut self, value: Arc<T>)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.values.insert(TypeId::of::<T>(), value);
+    }
+
+    pub(crate) fn extend(&mut self, other: State) {
+        self.values.extend(other.values);
+    }
+
+    pub(crate) fn get<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.values
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|x| x.downcast::<T>().ok())
+    }
+}
+
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let mut state = State::default();
+        state.insert(Arc::new(String::from("config")));
+
+        assert_eq!(state.get::<String>().unwrap().as_str(), "config");
+        assert!(state.get::<u32>().is_none());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    futures::stream::{unfold, Stream},
+    hyper::{
+        body::Bytes,
+        header::{CONTENT_LENGTH, CONTENT_TYPE},
+        http::Error as HttpError,
+        Body, Response as HyperResponse, StatusCode,
+    },
+    Response,
+};
+
+use mime::Mime;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use std::{error::Error as StdError, io::Error as IoError};
+
+const BUF_SIZE: usize = 8 * 1024;
+
+/// Response whose body is sent in chunks from a stream instead of being buffered.
+///
+/// The body is sent with chunked transfer encoding unless a content length is given.
+///
+/// # Examples
+///
+/// ```
+/// use reign::{
+///     prelude::*,
+///     router::{futures::stream::iter, hyper::body::Bytes, StreamBody},
+/// };
+/// use std::io::Error as IoError;
+///
+/// async fn numbers(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(StreamBody::new(iter(vec![
+///         Ok::<_, IoError>(Bytes::from("1\n")),
+///         Ok(Bytes::from("2\n")),
+///     ])))
+/// }
+/// ```
+pub struct StreamBody<S> {
+    stream: S,
+    status: StatusCode,
+    content_type: Mime,
+    content_length: Option<u64>,
+}
+
+impl<S, E> StreamBody<S>
+where
+    S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+    E: Into<Box<dyn StdError + Send + Sync>> + 'static,
+{
+    /// Create the response from the given stream of chunks.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            status: StatusCode::OK,
+            content_type: mime::APPLICATION_OCTET_STREAM,
+            content_length: None,
+        }
+    }
+
+    /// Set the status of the response. Defaults to `200 OK`.
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set the `Content-Type` header. Defaults to `application/octet-stream`.
+    pub fn content_type(mut self, content_type: Mime) -> Self {
+        self.content_type = content_type;
+        self
+    }
+
+    /// Set the `Content-Length` header when the total size of the stream is known upfront.
+    pub fn content_length(mut self, content_length: u64) -> Self {
+        self.content_length = Some(content_length);
+        self
+    }
+}
+
+impl StreamBody<()> {
+    /// Create the response by reading the given reader until it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reign::{prelude::*, router::StreamBody};
+    /// use std::io::Cursor;
+    ///
+    /// async fn download(req: &mut Request) -> Result<impl Response, Error> {
+    ///     Ok(StreamBody::from_reader(Cursor::new("hello world")).content_length(11))
+    /// }
+    /// ```
+    pub fn from_reader<R>(
+        reader: R,
+    ) -> StreamBody<impl Stream<Item = Result<Bytes, IoError>> + Send + 'static>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        StreamBody::new(reader_stream(reader))
+    }
+}
+
+impl<S, E> Response for StreamBody<S>
+where
+    S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+    E: Into<Box<dyn StdError + Send + Sync>> + 'static,
+{
+    fn respond(self) -> Result<HyperResponse<Body>, HttpError> {
+        let mut builder = HyperResponse::builder()
+            .status(self.status)
+            .header(CONTENT_TYPE, self.content_type.as_ref());
+
+        if let Some(content_length) = self.content_length {
+            builder = builder.header(CONTENT_LENGTH, content_length);
+        }
+
+        builder.body(Body::wrap_stream(self.stream))
+    }
+}
+
+pub(crate) fn reader_stream<R>(reader: R) -> impl Stream<Item = Result<Bytes, IoError>>
+where
+    R: AsyncRead + Unpin,
+{
+    unfold(Some(reader), |reader| async move {
+        let mut reader = reader?;
+        let mut buf = vec![0; BUF_SIZE];
+
+        match reader.read(&mut buf).await {
+            Ok(0) => None,
+            Ok(n) => {
+                buf.truncate(n);
+                Some((Ok(Bytes::from(buf)), Some(reader)))
+            }
+            // Stop reading after an error
+            Err(err) => Some((Err(err), None)),
+        }
+    })
+}
@@ -0,0 +1,289 @@
+use crate::{
+    futures::{
+        future::{ready, Ready},
+        stream::{poll_fn, StreamExt},
+    },
+    hyper::{
+        server::{
+            accept::{from_stream, Accept},
+            conn::{AddrIncoming, AddrStream},
+            Server,
+        },
+        service::make_service_fn,
+    },
+    service, socket_addr, Router, ServeError, TlsError,
+};
+
+use log::debug;
+use rustls_pemfile::{certs, read_all, Item};
+use tokio::time::timeout;
+use tokio_rustls::{
+    rustls::{
+        sign::any_supported_type, Certificate, Error as RustlsError, PrivateKey, ServerConfig,
+        SignatureScheme,
+    },
+    server::TlsStream,
+    TlsAcceptor,
+};
+use webpki::{EndEntityCert, SignatureAlgorithm};
+
+use std::{
+    convert::{Infallible, TryFrom},
+    fs::read,
+    net::ToSocketAddrs,
+    path::PathBuf,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+
+// Number of TLS handshakes that can be in progress at the same time
+const HANDSHAKES: usize = 64;
+
+// Signature schemes used to check that the private key belongs to the certificate
+const SCHEMES: &[(SignatureScheme, &SignatureAlgorithm)] = &[
+    (
+        SignatureScheme::ECDSA_NISTP256_SHA256,
+        &webpki::ECDSA_P256_SHA256,
+    ),
+    (
+        SignatureScheme::ECDSA_NISTP384_SHA384,
+        &webpki::ECDSA_P384_SHA384,
+    ),
+    (SignatureScheme::ED25519, &webpki::ED25519),
+    (
+        SignatureScheme::RSA_PKCS1_SHA256,
+        &webpki::RSA_PKCS1_2048_8192_SHA256,
+    ),
+];
+
+#[derive(Debug, Clone)]
+enum Pem {
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+impl Pem {
+    fn read(&self) -> Result<Vec<u8>, TlsError> {
+        match self {
+            Self::Path(path) => read(path).map_err(|err| TlsError::Read(path.clone(), err)),
+            Self::Bytes(bytes) => Ok(bytes.clone()),
+        }
+    }
+}
+
+/// Certificate chain and private key used by [`serve_tls`](crate::serve_tls).
+///
+/// Both of them need to be PEM encoded. The private key can be in PKCS#8, PKCS#1 (RSA)
+/// or SEC1 (EC) format.
+///
+/// # Examples
+///
+/// ```
+/// use reign::router::TlsConfig;
+///
+/// let config = TlsConfig::new()
+///     .cert_path("certs/fullchain.pem")
+///     .key_path("certs/privkey.pem");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    cert: Option<Pem>,
+    key: Option<Pem>,
+    handshake_timeout: Duration,
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        Self {
+            cert: None,
+            key: None,
+            handshake_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl TlsConfig {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop connections which don't finish the TLS handshake in the given time, which
+    /// defaults to 10 seconds.
+    pub fn handshake_timeout(mut self, duration: Duration) -> Self {
+        self.handshake_timeout = duration;
+        self
+    }
+
+    /// Read the certificate chain from the given file.
+    pub fn cert_path<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.cert = Some(Pem::Path(path.into()));
+        self
+    }
+
+    /// Read the private key from the given file.
+    pub fn key_path<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.key = Some(Pem::Path(path.into()));
+        self
+    }
+
+    /// Use the given contents as the certificate chain.
+    pub fn cert_pem<B>(mut self, pem: B) -> Self
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.cert = Some(Pem::Bytes(pem.into()));
+        self
+    }
+
+    /// Use the given contents as the private key.
+    pub fn key_pem<B>(mut self, pem: B) -> Self
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.key = Some(Pem::Bytes(pem.into()));
+        self
+    }
+
+    pub(crate) fn build(&self) -> Result<ServerConfig, TlsError> {
+        let cert = self.cert.as_ref().ok_or(TlsError::MissingCert)?.read()?;
+        let key = self.key.as_ref().ok_or(TlsError::MissingKey)?.read()?;
+
+        let certs = certs(&mut cert.as_slice())
+            .map_err(TlsError::InvalidPem)?
+            .into_iter()
+            .map(Certificate)
+            .collect::<Vec<_>>();
+
+        if certs.is_empty() {
+            return Err(TlsError::MissingCert);
+        }
+
+        let key = read_all(&mut key.as_slice())
+            .map_err(TlsError::InvalidPem)?
+            .into_iter()
+            .find_map(|item| match item {
+                Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => Some(key),
+                _ => None,
+            })
+            .ok_or(TlsError::MissingKey)?;
+
+        let key = PrivateKey(key);
+
+        check_key(&certs[0], &key)?;
+
+        let mut config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(certs, key)?;
+
+        // Let the client choose HTTP/2 when it supports it
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+        Ok(config)
+    }
+}
+
+// Checks that the key belongs to the leaf certificate by verifying a signature made with it
+fn check_key(cert: &Certificate, key: &PrivateKey) -> Result<(), TlsError> {
+    let signing_key = any_supported_type(key)
+        .map_err(|_| RustlsError::General("unsupported private key type".into()))?;
+    let schemes = SCHEMES.iter().map(|x| x.0).collect::<Vec<_>>();
+
+    let signer = signing_key
+        .choose_scheme(&schemes)
+        .ok_or(TlsError::KeyMismatch)?;
+
+    let algorithm = SCHEMES
+        .iter()
+        .find(|x| x.0 == signer.scheme())
+        .ok_or(TlsError::KeyMismatch)?
+        .1;
+
+    let message = b"reign";
+    let signature = signer.sign(message)?;
+
+    EndEntityCert::try_from(cert.0.as_slice())
+        .and_then(|cert| cert.verify_signature(algorithm, message, &signature))
+        .map_err(|_| TlsError::KeyMismatch)
+}
+
+/// Create the server using the given router definition which only accepts HTTPS connections.
+///
+/// Both HTTP/1.1 and HTTP/2 are supported and negotiated with the client using ALPN.
+/// Connections which fail or don't finish the TLS handshake in time are dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reign::router::{serve_tls, Router, TlsConfig};
+///
+/// fn router(r: &mut Router) {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = TlsConfig::new()
+///         .cert_path("certs/fullchain.pem")
+///         .key_path("certs/privkey.pem");
+///
+///     serve_tls("0.0.0.0:8443", router, config).await.unwrap();
+/// }
+/// ```
+pub async fn serve_tls<A, R>(addr: A, f: R, config: TlsConfig) -> Result<(), ServeError>
+where
+    A: ToSocketAddrs + Send + 'static,
+    R: FnOnce(&mut Router),
+{
+    let acceptor = TlsAcceptor::from(Arc::new(config.build().map_err(ServeError::Tls)?));
+    let handshake_timeout = config.handshake_timeout;
+    let mut incoming = AddrIncoming::bind(&socket_addr(addr)?).map_err(ServeError::Bind)?;
+
+    let connections = poll_fn(move |cx| Pin::new(&mut incoming).poll_accept(cx))
+        .map(move |conn| {
+            let acceptor = acceptor.clone();
+
+            async move {
+                match conn {
+                    Ok(conn) => match timeout(handshake_timeout, acceptor.accept(conn)).await {
+                        Ok(Ok(conn)) => Some(conn),
+                        Ok(Err(err)) => {
+                            debug!("TLS handshake failed: {}", err);
+                            None
+                        }
+                        Err(_) => {
+                            debug!("TLS handshake timed out");
+                            None
+                        }
+                    },
+                    Err(err) => {
+                        debug!("Unable to accept connection: {}", err);
+                        None
+                    }
+                }
+            }
+        })
+        .buffer_unordered(HANDSHAKES)
+        .filter_map(|conn| ready(conn.map(Ok::<_, Infallible>)));
+
+    let router_service = service(f);
+    let make_service = make_service_fn(
+        |conn: &TlsStream<AddrStream>| -> Ready<Result<_, Infallible>> {
+            ready(Ok(router_service
+                .clone()
+                .with_remote_addr(conn.get_ref().0.remote_addr())))
+        },
+    );
+
+    router_service
+        .configure(Server::builder(from_stream(connections)))
+        .serve(make_service)
+        .await
+        .map_err(ServeError::serving)
+}
@@ -0,0 +1,204 @@
+use crate::{
+    futures::{
+        future::{pending, ready},
+        stream::unfold,
+    },
+    hyper::{
+        server::{accept::from_stream, Server},
+        service::{make_service_fn, service_fn, Service as HyperService},
+    },
+    service, Router, ServeError,
+};
+
+use log::{debug, error};
+use tokio::{
+    net::{UnixListener, UnixStream},
+    time::sleep,
+};
+
+use std::{
+    convert::Infallible,
+    fs::{remove_file, symlink_metadata},
+    future::Future,
+    io::{Error as IoError, ErrorKind},
+    net::Ipv4Addr,
+    os::unix::{fs::FileTypeExt, net::UnixStream as StdUnixStream},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Credentials of the process on the other end of a Unix domain socket connection.
+///
+/// Requests served by [`serve_unix`] have it in their extensions.
+///
+/// # Examples
+///
+/// ```
+/// use reign::prelude::*;
+/// use reign::router::UnixPeer;
+///
+/// async fn foo(req: &mut Request) -> Result<impl Response, Error> {
+///     Ok(req.ext::<UnixPeer>().map(|x| x.uid.to_string()).unwrap_or_default())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnixPeer {
+    pub uid: u32,
+    pub gid: u32,
+    pub pid: Option<i32>,
+}
+
+// Removes the socket file when the server stops
+struct SocketFile(PathBuf);
+
+impl Drop for SocketFile {
+    fn drop(&mut self) {
+        if let Err(err) = remove_file(&self.0) {
+            debug!("Unable to remove socket file {:?}: {}", self.0, err);
+        }
+    }
+}
+
+/// Create the server using the given router definition which listens on a Unix domain
+/// socket at the given path.
+///
+/// A stale socket file left at the path, one which nobody is listening on, is removed before
+/// binding, and the socket file is removed once the server stops. Anything else at the path
+/// is an error. Connections over the socket don't have a remote address, so
+/// [`Request::ip`](crate::Request::ip) is `0.0.0.0:0` for them and the credentials of the
+/// client are available as [`UnixPeer`] instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reign::router::{serve_unix, Router};
+///
+/// fn router(r: &mut Router) {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     serve_unix("/tmp/reign.sock", router).await.unwrap();
+/// }
+/// ```
+pub async fn serve_unix<P, R>(path: P, f: R) -> Result<(), ServeError>
+where
+    P: AsRef<Path>,
+    R: FnOnce(&mut Router),
+{
+    serve_unix_with_shutdown(path, f, pending()).await
+}
+
+/// Create the server using the given router definition which listens on a Unix domain
+/// socket at the given path, and is gracefully shut down when the given `signal` completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reign::router::{serve_unix_with_shutdown, Router};
+///
+/// fn router(r: &mut Router) {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     serve_unix_with_shutdown("/tmp/reign.sock", router, async {
+///         tokio::signal::ctrl_c().await.unwrap();
+///     })
+///     .await
+///     .unwrap();
+/// }
+/// ```
+pub async fn serve_unix_with_shutdown<P, R, S>(path: P, f: R, signal: S) -> Result<(), ServeError>
+where
+    P: AsRef<Path>,
+    R: FnOnce(&mut Router),
+    S: Future<Output = ()>,
+{
+    let path = path.as_ref();
+
+    remove_stale(path).map_err(|err| ServeError::Unix(path.to_path_buf(), err))?;
+
+    let listener =
+        UnixListener::bind(path).map_err(|err| ServeError::Unix(path.to_path_buf(), err))?;
+    let _socket_file = SocketFile(path.to_path_buf());
+
+    let connections = unfold(listener, |listener| async move {
+        let stream = accept(&listener).await;
+        Some((Ok::<_, Infallible>(stream), listener))
+    });
+
+    let router_service = service(f);
+    let make_service = make_service_fn(|stream: &UnixStream| {
+        let peer = stream.peer_cred().ok().map(|cred| UnixPeer {
+            uid: cred.uid(),
+            gid: cred.gid(),
+            pid: cred.pid(),
+        });
+
+        let mut remote = router_service
+            .clone()
+            .with_remote_addr((Ipv4Addr::UNSPECIFIED, 0).into());
+
+        ready(Ok::<_, Infallible>(service_fn(move |mut req| {
+            if let Some(peer) = peer {
+                req.extensions_mut().insert(peer);
+            }
+
+            remote.call(req)
+        })))
+    });
+
+    router_service
+        .configure(Server::builder(from_stream(connections)))
+        .serve(make_service)
+        .with_graceful_shutdown(signal)
+        .await
+        .map_err(ServeError::serving)
+}
+
+// Removes the socket file at the path only if nobody is listening on it anymore
+fn remove_stale(path: &Path) -> Result<(), IoError> {
+    match symlink_metadata(path) {
+        Ok(metadata) if !metadata.file_type().is_socket() => {
+            return Err(IoError::new(
+                ErrorKind::AlreadyExists,
+                "path already exists and is not a socket",
+            ))
+        }
+        Ok(_) => {}
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    }
+
+    if StdUnixStream::connect(path).is_ok() {
+        return Err(IoError::new(
+            ErrorKind::AddrInUse,
+            "socket is being listened on by another server",
+        ));
+    }
+
+    remove_file(path)?;
+    debug!("Removed stale socket file {:?}", path);
+    Ok(())
+}
+
+// Like hyper's `AddrIncoming`, accept errors don't stop the server
+async fn accept(listener: &UnixListener) -> UnixStream {
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => return stream,
+            Err(err) if is_connection_error(&err) => debug!("Unix socket accept error: {}", err),
+            Err(err) => {
+                // Most likely running out of file descriptors, so wait for some to be freed
+                error!("Unix socket accept error: {}", err);
+                sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+}
+
+fn is_connection_error(err: &IoError) -> bool {
+    matches!(
+        err.kind(),
+        ErrorKind::ConnectionRefused | ErrorKind::ConnectionAborted | ErrorKind::ConnectionReset
+    )
+}
@@ -0,0 +1,83 @@
+//! Contains types needed for upgrading requests to WebSocket connections
+
+use crate::{
+    futures::{future::BoxFuture, FutureExt},
+    hyper::{
+        header::{
+            HeaderValue, CONNECTION, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY,
+            SEC_WEBSOCKET_VERSION, UPGRADE,
+        },
+        upgrade::{OnUpgrade, Upgraded},
+        Body, Method, Response as HyperResponse, StatusCode,
+    },
+    Error, Request,
+};
+
+use tokio_tungstenite::{
+    tungstenite::{handshake::derive_accept_key, protocol::Role},
+    WebSocketStream,
+};
+
+pub use tokio_tungstenite::tungstenite::{Error as WebSocketError, Message};
+
+/// Bidirectional stream of [`Message`] over an upgraded connection.
+///
+/// Use [`StreamExt`](crate::futures::StreamExt) to read and
+/// [`SinkExt`](crate::futures::SinkExt) to write messages.
+pub type WebSocket = WebSocketStream<Upgraded>;
+
+/// Resolves to the [`WebSocket`] once the `101 Switching Protocols` response is sent.
+pub type WebSocketUpgrade = BoxFuture<'static, Result<WebSocket, Error>>;
+
+pub(crate) fn upgrade(req: &mut Request) -> Result<(HyperResponse<Body>, WebSocketUpgrade), Error> {
+    let bad_request = || Error::Status(StatusCode::BAD_REQUEST);
+
+    if req.method() != Method::GET {
+        return Err(bad_request());
+    }
+
+    let headers = req.headers();
+
+    if !header_contains(headers.get(UPGRADE), "websocket")
+        || !header_contains(headers.get(CONNECTION), "upgrade")
+        || headers
+            .get(SEC_WEBSOCKET_VERSION)
+            .map(HeaderValue::as_bytes)
+            != Some(&b"13"[..])
+    {
+        return Err(bad_request());
+    }
+
+    let accept = match headers.get(SEC_WEBSOCKET_KEY) {
+        Some(key) => derive_accept_key(key.as_bytes()),
+        None => return Err(bad_request()),
+    };
+
+    let on_upgrade = req
+        .extensions_mut()
+        .remove::<OnUpgrade>()
+        .ok_or_else(bad_request)?;
+
+    let response = HyperResponse::builder()
+        .status(StatusCode::SWITCHING_PROTOCOLS)
+        .header(UPGRADE, "websocket")
+        .header(CONNECTION, "Upgrade")
+        .header(SEC_WEBSOCKET_ACCEPT, accept)
+        .body(Body::empty())?;
+
+    let upgrade = async move {
+        let upgraded = on_upgrade.await?;
+
+        Ok::<_, Error>(WebSocketStream::from_raw_socket(upgraded, Role::Server, None).await)
+    }
+    .boxed();
+
+    Ok((response, upgrade))
+}
+
+// Header values like `Connection` can be a comma separated list
+fn header_contains(value: Option<&HeaderValue>, token: &str) -> bool {
+    value.and_then(|x| x.to_str().ok()).map_or(false, |x| {
+        x.split(',').any(|x| x.trim().eq_ignore_ascii_case(token))
+    })
+}
@@ -0,0 +1,98 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    service, Error, Request, Response,
+};
+
+async fn negotiate(req: &mut Request) -> Result<impl Response, Error> {
+    match req.preferred(&["application/json", "text/html"]) {
+        Some(mime) => Ok(mime.to_string()),
+        None => Err(Error::Status(StatusCode::NOT_ACCEPTABLE)),
+    }
+}
+
+async fn call(accept: Option<&str>) -> (StatusCode, String) {
+    let service = service(|r| {
+        r.get("negotiate", negotiate);
+    });
+
+    let mut req = Req::get("https://reign.rs/negotiate");
+
+    if let Some(accept) = accept {
+        req = req.header("accept", accept);
+    }
+
+    let response = service
+        .call(
+            req.body(Body::empty()).unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = to_bytes(response.into_body()).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn test_preferred_quality() {
+    let (status, body) = call(Some("application/json;q=0.5, text/html;q=0.9")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "text/html");
+}
+
+#[tokio::test]
+async fn test_preferred_wildcard() {
+    let (status, body) = call(Some("text/*, */*;q=0.1")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "text/html");
+
+    let (status, body) = call(Some("*/*")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "application/json");
+}
+
+#[tokio::test]
+async fn test_preferred_missing() {
+    let (status, body) = call(None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "application/json");
+}
+
+#[tokio::test]
+async fn test_preferred_none() {
+    let (status, _) = call(Some("image/png, text/html;q=0")).await;
+
+    assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+}
+
+#[tokio::test]
+async fn test_accepts() {
+    async fn html(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(format!("{}", req.accepts("text/html")))
+    }
+
+    let service = service(|r| {
+        r.get("html", html);
+    });
+
+    let response = service
+        .call(
+            Req::get("https://reign.rs/html")
+                .header("accept", "application/json")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body()).await.unwrap();
+
+    assert_eq!(body, "false");
+}
@@ -0,0 +1,148 @@
+use reign::prelude::action;
+use reign_router::{
+    hyper::{body::to_bytes, Body, Method, Request as Req, StatusCode},
+    service, Error, Json, Request, Response,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct User {
+    name: String,
+}
+
+#[tokio::test]
+async fn test_action() {
+    #[action]
+    async fn create(method: Method, user: Json<User>) -> Result<impl Response, Error> {
+        Ok(format!("{} {}", method, user.0.name))
+    }
+
+    #[action]
+    async fn echo(req: &mut Request, body: String) -> Result<impl Response, Error> {
+        Ok(format!("{} {}", req.uri().path(), body))
+    }
+
+    let service = service(|r| {
+        r.post("create", create);
+        r.post("echo", echo);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/create")
+                .body(Body::from(r#"{"name":"Reign"}"#))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "POST Reign");
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/create")
+                .body(Body::from("name"))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/echo")
+                .body(Body::from("hello"))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "/echo hello");
+}
+
+#[tokio::test]
+async fn test_action_body_limit() {
+    #[action]
+    async fn echo(body: String) -> Result<impl Response, Error> {
+        Ok(body)
+    }
+
+    let service = service(|r| {
+        r.max_body_size(8);
+
+        r.post("echo", echo);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/echo")
+                .body(Body::from("1234567890"))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/echo")
+                .body(Body::from("1234"))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "1234");
+}
+
+#[tokio::test]
+async fn test_action_json_content_type() {
+    #[action]
+    async fn create(user: Json<User>) -> Result<impl Response, Error> {
+        Ok(user.0.name)
+    }
+
+    let service = service(|r| {
+        r.post("create", create);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/create")
+                .header("content-type", "application/json; charset=utf-8")
+                .body(Body::from(r#"{"name":"Reign"}"#))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/create")
+                .header("content-type", "text/plain")
+                .body(Body::from(r#"{"name":"Reign"}"#))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
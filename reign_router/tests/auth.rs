@@ -0,0 +1,138 @@
+use reign_router::{
+    hyper::{
+        body::to_bytes,
+        header::{AUTHORIZATION, WWW_AUTHENTICATE},
+        Body, Request as Req, StatusCode,
+    },
+    middleware::RequireAuth,
+    service, Error, Request, Response, Service,
+};
+
+async fn username(req: &mut Request) -> Result<impl Response, Error> {
+    Ok(req.basic_auth().map(|x| x.0).unwrap_or_default())
+}
+
+async fn token(req: &mut Request) -> Result<impl Response, Error> {
+    Ok(req.bearer_token().unwrap_or_default())
+}
+
+fn auth() -> Service {
+    service(|r| {
+        r.pipe("admin")
+            .add(RequireAuth::basic("admin", |username, password| {
+                username == "admin" && password == "secret"
+            }));
+        r.pipe("api")
+            .add(RequireAuth::bearer("api", |token| token == "abc123"));
+
+        r.get("username", username);
+        r.get("token", token);
+
+        r.scope("admin").through(&["admin"]).to(|r| {
+            r.get("", username);
+        });
+
+        r.scope("api").through(&["api"]).to(|r| {
+            r.get("", token);
+        });
+    })
+}
+
+macro_rules! call {
+    ($service:ident, $path:expr) => {
+        $service
+            .clone()
+            .call(
+                Req::get($path).body(Body::empty()).unwrap(),
+                "10.10.10.10:80".parse().unwrap(),
+            )
+            .await
+            .unwrap()
+    };
+    ($service:ident, $path:expr, $auth:expr) => {
+        $service
+            .clone()
+            .call(
+                Req::get($path)
+                    .header(AUTHORIZATION, $auth)
+                    .body(Body::empty())
+                    .unwrap(),
+                "10.10.10.10:80".parse().unwrap(),
+            )
+            .await
+            .unwrap()
+    };
+}
+
+#[tokio::test]
+async fn test_basic_auth() {
+    let service = auth();
+
+    let res = call!(
+        service,
+        "https://reign.rs/username",
+        "Basic YWRtaW46c2VjcmV0"
+    );
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "admin");
+
+    let res = call!(
+        service,
+        "https://reign.rs/username",
+        "basic  YWRtaW46c2VjcmV0 "
+    );
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "admin");
+
+    for auth in [
+        "Basic !!!",
+        "Basic YWRtaW4=",
+        "Basic",
+        "Bearer YWRtaW46c2VjcmV0",
+        "YWRtaW46c2VjcmV0",
+    ] {
+        let res = call!(service, "https://reign.rs/username", auth);
+        assert_eq!(to_bytes(res.into_body()).await.unwrap(), "", "{}", auth);
+    }
+}
+
+#[tokio::test]
+async fn test_bearer_token() {
+    let service = auth();
+
+    let res = call!(service, "https://reign.rs/token", "Bearer abc123");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "abc123");
+
+    let res = call!(service, "https://reign.rs/token", "Bearer ");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+}
+
+#[tokio::test]
+async fn test_require_auth() {
+    let service = auth();
+
+    let res = call!(service, "https://reign.rs/admin");
+
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        res.headers().get(WWW_AUTHENTICATE).unwrap(),
+        "Basic realm=\"admin\", charset=\"UTF-8\""
+    );
+
+    let res = call!(service, "https://reign.rs/admin", "Basic YWRtaW46d3Jvbmc=");
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    let res = call!(service, "https://reign.rs/admin", "Basic YWRtaW46c2VjcmV0");
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "admin");
+
+    let res = call!(service, "https://reign.rs/api", "Bearer wrong");
+
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        res.headers().get(WWW_AUTHENTICATE).unwrap(),
+        "Bearer realm=\"api\""
+    );
+
+    let res = call!(service, "https://reign.rs/api", "Bearer abc123");
+    assert_eq!(res.status(), StatusCode::OK);
+}
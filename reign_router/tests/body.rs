@@ -0,0 +1,142 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    service, Error, Request, Response,
+};
+
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Deserialize)]
+struct User {
+    name: String,
+}
+
+macro_rules! call {
+    ($service:ident, $content_type:expr, $body:expr) => {
+        $service
+            .clone()
+            .call(
+                Req::post("https://reign.rs/users")
+                    .header("content-type", $content_type)
+                    .body(Body::from($body))
+                    .unwrap(),
+                "10.10.10.10:80".parse().unwrap(),
+            )
+            .await
+            .unwrap()
+    };
+}
+
+#[tokio::test]
+async fn test_json() {
+    async fn create(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req.json::<User>().await?.name)
+    }
+
+    let service = service(|r| {
+        r.max_body_size(32);
+        r.post("users", create);
+    });
+
+    let res = call!(service, "application/json", r#"{"name":"reign"}"#);
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "reign");
+
+    let res = call!(service, "application/vnd.api+json", r#"{"name":"reign"}"#);
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = call!(service, "text/plain", r#"{"name":"reign"}"#);
+
+    assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let res = call!(service, "application/json", r#"{"name":"#);
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let res = call!(service, "application/json", r#"{"name":1}"#);
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let res = call!(
+        service,
+        "application/json",
+        r#"{"name":"reign reign reign reign"}"#
+    );
+
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn test_json_error() {
+    async fn parse(req: &mut Request) -> Result<impl Response, Error> {
+        let value = serde_json::from_slice::<Value>(&req.body_bytes().await?)?;
+
+        Ok(value.to_string())
+    }
+
+    async fn serialize(_: &mut Request) -> Result<impl Response, Error> {
+        let map = HashMap::from([((1, 2), 3)]);
+
+        Ok(serde_json::to_string(&map)?)
+    }
+
+    let service = service(|r| {
+        r.post("users", parse);
+        r.post("map", serialize);
+    });
+
+    // Only request bodies parsed by `Request::json` are client errors
+    let res = call!(service, "application/json", r#"{"name":"#);
+
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/map")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_body_bytes_chunked() {
+    async fn create(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(format!("{}", req.body_bytes().await?.len()))
+    }
+
+    let service = service(|r| {
+        r.max_body_size(8);
+        r.post("users", create);
+    });
+
+    let (mut sender, body) = Body::channel();
+
+    tokio::spawn(async move {
+        let _ = sender.send_data("12345".into()).await;
+        let _ = sender.send_data("67890".into()).await;
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/users").body(body).unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let res = call!(service, "text/plain", "1234");
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "4");
+}
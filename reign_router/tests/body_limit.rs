@@ -0,0 +1,102 @@
+use reign_router::{
+    hyper::{body::to_bytes, header::CONTENT_LENGTH, Body, Request as Req, StatusCode},
+    middleware::BodyLimit,
+    service, Error, Request, Response, Service,
+};
+
+use std::str::from_utf8;
+
+async fn echo(req: &mut Request) -> Result<impl Response, Error> {
+    let body = req.body().await?.unwrap_or_default();
+
+    Ok(from_utf8(&body)?.to_string())
+}
+
+async fn length(req: &mut Request) -> Result<impl Response, Error> {
+    Ok(req.body_bytes().await?.len().to_string())
+}
+
+fn limited() -> Service {
+    service(|r| {
+        r.pipe("api").add(BodyLimit::new(8));
+        r.pipe("upload").add(BodyLimit::new(16));
+
+        r.scope("api").through(&["api"]).to(|r| {
+            r.post("echo", echo);
+            r.post("length", length);
+        });
+
+        r.scope("upload").through(&["upload"]).to(|r| {
+            r.post("echo", echo);
+        });
+    })
+}
+
+fn chunked() -> Body {
+    let (mut sender, body) = Body::channel();
+
+    tokio::spawn(async move {
+        let _ = sender.send_data("12345".into()).await;
+        let _ = sender.send_data("67890".into()).await;
+    });
+
+    body
+}
+
+macro_rules! call {
+    ($service:ident, $path:expr, $body:expr) => {
+        $service
+            .clone()
+            .call(
+                Req::post($path).body($body).unwrap(),
+                "10.10.10.10:80".parse().unwrap(),
+            )
+            .await
+            .unwrap()
+    };
+}
+
+#[tokio::test]
+async fn test_body_limit_content_length() {
+    let service = limited();
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/api/echo")
+                .header(CONTENT_LENGTH, "10")
+                .body(Body::from("1234567890"))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let res = call!(service, "https://reign.rs/api/echo", Body::from("1234"));
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "1234");
+}
+
+#[tokio::test]
+async fn test_body_limit_stream() {
+    let service = limited();
+
+    let res = call!(service, "https://reign.rs/api/echo", chunked());
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let res = call!(service, "https://reign.rs/api/length", chunked());
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn test_body_limit_scope() {
+    let service = limited();
+
+    let res = call!(service, "https://reign.rs/upload/echo", chunked());
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "1234567890");
+}
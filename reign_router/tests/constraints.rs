@@ -1,4 +1,5 @@
 use reign_router::{
+    constraints::{and, has_port, header_equals, not, or, query_present},
     hyper::{body::to_bytes, Body, Method, Request as Req, StatusCode},
     service, Error, Request, Response,
 };
@@ -45,3 +46,56 @@ async fn test_constraint() {
     assert_eq!(res.status(), StatusCode::OK);
     assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
 }
+
+#[tokio::test]
+async fn test_constraint_combinators() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index")
+    }
+
+    let service = service(|r| {
+        r.any_with_constraint(
+            &[Method::GET],
+            "foo",
+            and(header_equals("x-env", "prod"), query_present("debug")),
+            index,
+        );
+        r.any_with_constraint(
+            &[Method::GET],
+            "bar",
+            or(has_port(), not(query_present("debug"))),
+            index,
+        );
+    });
+
+    let call = |uri: &str, env: &str| {
+        service.clone().call(
+            Req::get(uri)
+                .header("x-env", env)
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+    };
+
+    let res = call("https://reign.rs/foo?debug", "prod").await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+
+    let res = call("https://reign.rs/foo?debug", "dev").await.unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = call("https://reign.rs/foo", "prod").await.unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = call("https://reign.rs/bar", "prod").await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = call("https://reign.rs/bar?debug", "prod").await.unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = call("https://reign.rs:8080/bar?debug", "prod")
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+}
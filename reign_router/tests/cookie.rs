@@ -0,0 +1,160 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, Response as HyperResponse, StatusCode},
+    middleware::cookie::{Cookie, CookieJar, CookieParser, SameSite},
+    service, Error, Request, Response, ResponseExt,
+};
+
+#[tokio::test]
+async fn test_cookie_round_trip() {
+    async fn login(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(HyperResponse::new(Body::empty())
+            .cookie(Cookie::new("user", "reign rs; admin"))
+            .cookie(
+                Cookie::build("theme", "dark")
+                    .path("/")
+                    .domain("reign.rs")
+                    .http_only(true)
+                    .secure(true)
+                    .same_site(SameSite::Strict)
+                    .finish(),
+            ))
+    }
+
+    async fn me(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(format!(
+            "{} {} {}",
+            req.cookie("user").unwrap_or_default(),
+            req.cookie("theme").unwrap_or_default(),
+            req.cookies().len()
+        ))
+    }
+
+    let service = service(|r| {
+        r.get("login", login);
+        r.get("me", me);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/login")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let cookies = res
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .map(|x| x.to_str().unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(cookies.len(), 2);
+    assert_eq!(cookies[0], "user=reign%20rs%3B%20admin");
+    assert!(cookies[1].starts_with("theme=dark;"));
+    assert!(cookies[1].contains("HttpOnly"));
+    assert!(cookies[1].contains("SameSite=Strict"));
+    assert!(cookies[1].contains("Secure"));
+    assert!(cookies[1].contains("Path=/"));
+    assert!(cookies[1].contains("Domain=reign.rs"));
+
+    let header = cookies
+        .iter()
+        .map(|x| x.split(';').next().unwrap())
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/me")
+                .header("cookie", header)
+                .header("cookie", "user=other; lang=en")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "reign rs; admin dark 3"
+    );
+}
+
+#[tokio::test]
+async fn test_cookie_jar_round_trip() {
+    async fn login(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(HyperResponse::new(Body::empty())
+            .cookie(Cookie::new("user", "reign rs; admin"))
+            .cookie(Cookie::new("coupon", "100%25off"))
+            .cookie(Cookie::new("theme", "dark")))
+    }
+
+    async fn me(req: &mut Request) -> Result<impl Response, Error> {
+        let jar = req.extensions().get::<CookieJar>().unwrap();
+        let value = |name| jar.get(name).map(|x| x.value().to_string());
+
+        Ok(format!(
+            "{}|{}|{}|{}",
+            value("user").unwrap_or_default(),
+            value("coupon").unwrap_or_default(),
+            value("theme").unwrap_or_default(),
+            req.cookie("coupon").unwrap_or_default(),
+        ))
+    }
+
+    let service = service(|r| {
+        r.pipe("cookies").add(CookieParser::new());
+
+        r.get("login", login);
+        r.scope("").through(&["cookies"]).to(|r| {
+            r.get("me", me);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/login")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let cookies = res
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .map(|x| x.to_str().unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(cookies[0], "user=reign%20rs%3B%20admin");
+    assert_eq!(cookies[1], "coupon=100%25off");
+    assert_eq!(cookies[2], "theme=dark");
+
+    let header = cookies.join("; ");
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/me")
+                .header("cookie", header)
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "reign rs; admin|100%off|dark|100%off"
+    );
+}
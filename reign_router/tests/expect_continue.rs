@@ -0,0 +1,84 @@
+use reign_router::{
+    bind,
+    hyper::{Body, Response as HyperResponse, StatusCode},
+    Error, Request, Response, Router,
+};
+
+use std::net::SocketAddr;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+// Reads from the stream until the response has the given number of complete heads
+async fn read_heads(stream: &mut TcpStream, response: &mut String, heads: usize) {
+    let mut buf = [0; 1024];
+
+    while response.matches("\r\n\r\n").count() < heads {
+        let n = stream.read(&mut buf).await.unwrap();
+
+        if n == 0 {
+            break;
+        }
+
+        response.push_str(&String::from_utf8_lossy(&buf[..n]));
+    }
+}
+
+async fn send(addr: SocketAddr, path: &str, body: Option<&str>) -> String {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    stream
+        .write_all(
+            format!(
+                "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\nExpect: 100-continue\r\n\r\n",
+                path
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+
+    let mut response = String::new();
+    read_heads(&mut stream, &mut response, 1).await;
+
+    if let Some(body) = body {
+        stream.write_all(body.as_bytes()).await.unwrap();
+        read_heads(&mut stream, &mut response, 2).await;
+    }
+
+    response
+}
+
+#[tokio::test]
+async fn test_expect_continue() {
+    async fn reject(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(HyperResponse::builder()
+            .status(StatusCode::PAYLOAD_TOO_LARGE)
+            .body(Body::empty())
+            .unwrap())
+    }
+
+    async fn accept(req: &mut Request) -> Result<impl Response, Error> {
+        let body = req.body().await?.unwrap_or_default();
+        Ok(String::from_utf8_lossy(&body).to_string())
+    }
+
+    fn router(r: &mut Router) {
+        r.post("reject", reject);
+        r.post("accept", accept);
+    }
+
+    let (addr, server) = bind("127.0.0.1:0", router).unwrap();
+    let server = tokio::spawn(server);
+
+    let response = send(addr, "/reject", None).await;
+    assert!(response.starts_with("HTTP/1.1 413 Payload Too Large"));
+    assert!(!response.contains("100 Continue"));
+
+    let response = send(addr, "/accept", Some("hello")).await;
+    assert!(response.starts_with("HTTP/1.1 100 Continue"));
+    assert!(response.contains("HTTP/1.1 200 OK"));
+
+    server.abort();
+}
@@ -0,0 +1,61 @@
+use reign_router::{
+    futures::FutureExt,
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    service, Chain, Error, HandleFuture, Middleware, Request, Response,
+};
+
+struct CurrentUser {
+    name: String,
+}
+
+struct Auth {}
+
+impl Middleware for Auth {
+    fn handle<'m>(&'m self, req: &'m mut Request, chain: Chain<'m>) -> HandleFuture<'m> {
+        async move {
+            if let Some(name) = req.query("user") {
+                let name = name.clone();
+                req.set_ext(CurrentUser { name });
+            }
+
+            chain.run(req).await
+        }
+        .boxed()
+    }
+}
+
+#[tokio::test]
+async fn test_ext() {
+    async fn user(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req
+            .ext::<CurrentUser>()
+            .map_or("anonymous".to_string(), |x| x.name.clone()))
+    }
+
+    let service = service(|r| {
+        r.pipe("auth").add(Auth {});
+
+        r.scope("").through(&["auth"]).to(|r| {
+            r.get("user", user);
+        });
+    });
+
+    macro_rules! call {
+        ($path:expr, $body:expr) => {
+            let res = service
+                .clone()
+                .call(
+                    Req::get($path).body(Body::empty()).unwrap(),
+                    "10.10.10.10:80".parse().unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(res.status(), StatusCode::OK);
+            assert_eq!(to_bytes(res.into_body()).await.unwrap(), $body);
+        };
+    }
+
+    call!("https://reign.rs/user?user=reign", "reign");
+    call!("https://reign.rs/user", "anonymous");
+}
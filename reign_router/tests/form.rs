@@ -0,0 +1,133 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    service, Error, Request, Response,
+};
+
+use serde::Deserialize;
+
+macro_rules! call {
+    ($service:ident, $content_type:expr, $body:expr) => {
+        $service
+            .clone()
+            .call(
+                Req::post("https://reign.rs/form")
+                    .header("content-type", $content_type)
+                    .body(Body::from($body))
+                    .unwrap(),
+                "10.10.10.10:80".parse().unwrap(),
+            )
+            .await
+            .unwrap()
+    };
+}
+
+#[tokio::test]
+async fn test_form_login() {
+    #[derive(Deserialize)]
+    struct Login {
+        username: String,
+        password: String,
+        #[serde(default)]
+        remember: bool,
+    }
+
+    async fn login(req: &mut Request) -> Result<impl Response, Error> {
+        let login = req.form::<Login>().await?;
+
+        Ok(format!(
+            "{} {} {}",
+            login.username, login.password, login.remember
+        ))
+    }
+
+    let service = service(|r| {
+        r.post("form", login);
+    });
+
+    let res = call!(
+        service,
+        "application/x-www-form-urlencoded",
+        "username=reign&password=p%40ss+word&remember=on"
+    );
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "reign p@ss word true"
+    );
+
+    let res = call!(
+        service,
+        "application/x-www-form-urlencoded; charset=utf-8",
+        "username=reign&password=secret"
+    );
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "reign secret false"
+    );
+
+    let res = call!(
+        service,
+        "application/x-www-form-urlencoded",
+        "username=reign"
+    );
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let res = call!(
+        service,
+        "application/json",
+        "username=reign&password=secret"
+    );
+
+    assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn test_form_multiple_values() {
+    #[derive(Deserialize)]
+    struct Survey {
+        colors: Vec<String>,
+        #[serde(default)]
+        ids: Vec<u32>,
+        comment: Option<String>,
+    }
+
+    async fn survey(req: &mut Request) -> Result<impl Response, Error> {
+        let survey = req.form::<Survey>().await?;
+
+        Ok(format!(
+            "{} {:?} {:?}",
+            survey.colors.join(","),
+            survey.ids,
+            survey.comment
+        ))
+    }
+
+    let service = service(|r| {
+        r.max_body_size(64);
+        r.post("form", survey);
+    });
+
+    let res = call!(
+        service,
+        "application/x-www-form-urlencoded",
+        "colors=red&ids=1&colors=blue&ids=2&comment="
+    );
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "red,blue [1, 2] None"
+    );
+
+    let res = call!(
+        service,
+        "application/x-www-form-urlencoded",
+        "colors=a".repeat(10)
+    );
+
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
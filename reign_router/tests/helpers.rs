@@ -0,0 +1,115 @@
+use reign::view::AsyncRender;
+use reign_router::{
+    helpers::{
+        redirect, redirect_permanent, redirect_see_other, redirect_temporary, render, render_as,
+        render_async,
+    },
+    hyper::{body::to_bytes, header::HeaderValue, Body, Request as Req, StatusCode},
+    path as p, service, Error, Request, Response,
+};
+use tokio::task::yield_now;
+
+use std::{
+    fmt::{Result as FmtResult, Write},
+    future::Future,
+    pin::Pin,
+};
+
+struct Greeting<'a> {
+    name: &'a str,
+}
+
+impl Greeting<'_> {
+    async fn name(&self) -> &str {
+        yield_now().await;
+        self.name
+    }
+}
+
+impl AsyncRender for Greeting<'_> {
+    fn render<'b>(
+        &'b self,
+        buf: &'b mut String,
+    ) -> Pin<Box<dyn Future<Output = FmtResult> + Send + 'b>> {
+        Box::pin(async move { write!(buf, "<h1>Hello {}</h1>", self.name().await) })
+    }
+}
+
+#[tokio::test]
+async fn test_render_as() {
+    let res = render_as(
+        "<h1>Hello</h1>",
+        201,
+        HeaderValue::from_static("text/html; charset=iso-8859-1"),
+    )
+    .unwrap();
+
+    assert_eq!(res.status(), 201);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "text/html; charset=iso-8859-1"
+    );
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "<h1>Hello</h1>");
+}
+
+#[test]
+fn test_render_default_content_type() {
+    let res = render("<h1>Hello</h1>", 200).unwrap();
+
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+}
+
+#[test]
+fn test_redirect() {
+    let cases = vec![
+        (redirect("/a").unwrap(), StatusCode::FOUND),
+        (
+            redirect_permanent("/a").unwrap(),
+            StatusCode::PERMANENT_REDIRECT,
+        ),
+        (
+            redirect_temporary("/a").unwrap(),
+            StatusCode::TEMPORARY_REDIRECT,
+        ),
+        (redirect_see_other("/a").unwrap(), StatusCode::SEE_OTHER),
+    ];
+
+    for (res, status) in cases {
+        assert_eq!(res.status(), status);
+        assert_eq!(res.headers().get("location").unwrap(), "/a");
+    }
+
+    assert!(redirect("/a\nb").is_err());
+}
+
+#[tokio::test]
+async fn test_render_async() {
+    async fn greet(req: &mut Request) -> Result<impl Response, Error> {
+        let name = req.param::<String>("name")?;
+
+        Ok(render_async(Greeting { name: &name }, 200).await?)
+    }
+
+    let service = service(|r| {
+        r.get(p!(name), greet);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/Reign")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "<h1>Hello Reign</h1>"
+    );
+}
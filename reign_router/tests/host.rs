@@ -0,0 +1,54 @@
+use reign_router::{
+    hyper::{body::to_bytes, header::HOST, Body, Request as Req, StatusCode},
+    path as p, service, Error, Request, Response,
+};
+
+async fn tenant(req: &mut Request) -> Result<impl Response, Error> {
+    Ok(format!(
+        "{}:{}",
+        req.param::<String>("tenant")?,
+        req.param::<String>("id")?
+    ))
+}
+
+async fn admin(_: &mut Request) -> Result<impl Response, Error> {
+    Ok("admin")
+}
+
+#[tokio::test]
+async fn test_scope_host() {
+    let service = service(|r| {
+        r.scope_host("admin.app.test").to(|r| {
+            r.get(p!("users" / id), admin);
+        });
+
+        r.scope("").host("{tenant}.app.test").to(|r| {
+            r.get(p!("users" / id), tenant);
+        });
+    });
+
+    macro_rules! call {
+        ($host:expr, $status:expr, $body:expr) => {
+            let res = service
+                .clone()
+                .call(
+                    Req::get("/users/1")
+                        .header(HOST, $host)
+                        .body(Body::empty())
+                        .unwrap(),
+                    "10.10.10.10:80".parse().unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(res.status(), $status);
+            assert_eq!(to_bytes(res.into_body()).await.unwrap(), $body);
+        };
+    }
+
+    call!("tenant1.app.test", StatusCode::OK, "tenant1:1");
+    call!("Tenant2.APP.test:8080", StatusCode::OK, "tenant2:1");
+    call!("admin.app.test", StatusCode::OK, "admin");
+    call!("app.test", StatusCode::NOT_FOUND, "");
+    call!("tenant1.other.test", StatusCode::NOT_FOUND, "");
+}
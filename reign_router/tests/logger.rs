@@ -0,0 +1,110 @@
+use reign_router::{
+    hyper::{Body, Request as Req, StatusCode},
+    middleware::{Logger, RequestId},
+    service, Error, Request, Response,
+};
+
+use log::{set_logger, set_max_level, Level, LevelFilter, Log, Metadata, Record};
+use std::sync::{Mutex, Once};
+
+struct Sink(Mutex<Vec<(Level, String)>>);
+
+impl Log for Sink {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        if record.target() == "reign_router" {
+            self.0
+                .lock()
+                .unwrap()
+                .push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static SINK: Sink = Sink(Mutex::new(Vec::new()));
+static INIT: Once = Once::new();
+
+fn init() {
+    INIT.call_once(|| {
+        set_logger(&SINK).unwrap();
+        set_max_level(LevelFilter::Trace);
+    });
+}
+
+#[tokio::test]
+async fn test_logger() {
+    async fn hello(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("hello")
+    }
+
+    init();
+
+    let service = service(|r| {
+        r.pipe("common").add(RequestId::default()).add(
+            Logger::new(Level::Warn).format("{id} {ip} {method} {path}?{query} {status} {size}"),
+        );
+
+        r.scope("").through(&["common"]).to(|r| {
+            r.post("hello", hello);
+        });
+    });
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/hello?name=reign")
+                .header("x-request-id", "abc")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let records = SINK.0.lock().unwrap();
+
+    assert!(records.contains(&(
+        Level::Warn,
+        "abc 10.10.10.10:80 POST /hello?name=reign 200 5".to_string()
+    )));
+}
+
+#[tokio::test]
+async fn test_logger_error() {
+    async fn missing(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req.param::<String>("id")?)
+    }
+
+    init();
+
+    let service = service(|r| {
+        r.pipe("common")
+            .add(Logger::new(Level::Warn).format("{method} {path} {status} {size}"));
+
+        r.scope("").through(&["common"]).to(|r| {
+            r.get("missing", missing);
+        });
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/missing")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let records = SINK.0.lock().unwrap();
+
+    assert!(records.contains(&(Level::Warn, "GET /missing 404 0".to_string())));
+}
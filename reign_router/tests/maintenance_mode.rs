@@ -0,0 +1,58 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    middleware::MaintenanceMode,
+    service, Error, Request, Response,
+};
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+async fn index(_: &mut Request) -> Result<impl Response, Error> {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok("index")
+}
+
+#[tokio::test]
+async fn test_maintenance_mode() {
+    let maintenance = MaintenanceMode::new(true).retry_after(Duration::from_secs(120));
+
+    let service = service(|r| {
+        r.pipe("common").add(maintenance.clone());
+
+        r.scope("").through(&["common"]).to(|r| {
+            r.get("", index);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs").body(Body::empty()).unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(res.headers().get("retry-after").unwrap(), "120");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+    maintenance.disable();
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs").body(Body::empty()).unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,64 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    path as p, service, Error, Request, Response,
+};
+
+async fn matched(req: &mut Request) -> Result<impl Response, Error> {
+    Ok(req.matched_path().unwrap_or("none").to_string())
+}
+
+#[tokio::test]
+async fn test_matched_path() {
+    let service = service(|r| {
+        r.scope("users").to(|r| {
+            r.get(p!(id), matched);
+            r.get(p!(id / "posts" / post?), matched);
+        });
+
+        r.not_found(matched);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/users/7")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "/users/{id}");
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/users/7/posts/3")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "/users/{id}/posts/{post?}"
+    );
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/posts")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "none");
+}
@@ -0,0 +1,141 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    middleware::MethodOverride,
+    service, Error, Request, Response,
+};
+
+#[tokio::test]
+async fn test_method_override() {
+    async fn destroy(req: &mut Request) -> Result<impl Response, Error> {
+        let body = req.body().await?.unwrap_or_default();
+        Ok(format!("destroy {}", String::from_utf8_lossy(&body)))
+    }
+
+    async fn get(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("get")
+    }
+
+    let service = service(|r| {
+        r.method_override(MethodOverride::default());
+
+        r.delete("articles", destroy);
+        r.get("articles", get);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/articles")
+                .header("x-http-method-override", "DELETE")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "destroy ");
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/articles")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_method=delete&id=1"))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "destroy _method=delete&id=1"
+    );
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/articles")
+                .header("x-http-method-override", "GET")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn test_method_override_body_limit() {
+    async fn destroy(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("destroy")
+    }
+
+    let service = service(|r| {
+        r.method_override(MethodOverride::default());
+        r.max_body_size(16);
+
+        r.delete("articles", destroy);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs/articles")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_method=delete&title=too+long+for+the+limit"))
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let (sender, body) = Body::channel();
+    sender.abort();
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/articles")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(body)
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_method_override_custom_header() {
+    async fn destroy(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("destroy")
+    }
+
+    let service = service(|r| {
+        r.method_override(MethodOverride::default().header("X-Method"));
+
+        r.delete("articles", destroy);
+    });
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/articles")
+                .header("x-method", "DELETE")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "destroy");
+}
@@ -1,6 +1,6 @@
 use reign_router::{
     hyper::{body::to_bytes, Body, Method, Request as Req, StatusCode},
-    service, Error, Request, Response,
+    path as p, service, Error, Request, Response, ResponseExt,
 };
 
 macro_rules! call {
@@ -16,7 +16,7 @@ macro_rules! call {
             .await
             .unwrap();
 
-        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
         assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
     };
     ($service:ident, $path:expr, $method:ident, $($others:ident),+) => {
@@ -407,3 +407,237 @@ async fn test_mutliple_methods() {
     assert_eq!(res.status(), StatusCode::OK);
     assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
 }
+
+#[tokio::test]
+async fn test_rest_methods() {
+    async fn widgets(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("widgets")
+    }
+
+    let service = service(|r| {
+        r.rest("widgets", widgets);
+    });
+
+    call!(service, "https://reign.rs/widgets", trace, connect);
+
+    let res = service
+        .clone()
+        .call(
+            Req::trace("https://reign.rs/widgets")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(
+        res.headers().get("allow").unwrap(),
+        "DELETE, GET, HEAD, OPTIONS, PATCH, POST, PUT"
+    );
+
+    let res = service
+        .call(
+            Req::patch("https://reign.rs/widgets")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "widgets");
+}
+
+#[tokio::test]
+async fn test_method_not_allowed() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index")
+    }
+
+    let service = service(|r| {
+        r.post("index", index);
+        r.get("index", index);
+        r.scope("api").to(|r| {
+            r.put(p!(id), index);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::delete("https://reign.rs/index")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(res.headers().get("allow").unwrap(), "GET, POST");
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/api/1")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(res.headers().get("allow").unwrap(), "PUT");
+
+    let res = service
+        .call(
+            Req::delete("https://reign.rs/missing")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_auto_options() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index")
+    }
+
+    let service = service(|r| {
+        r.auto_options(true);
+
+        r.scope("api").to(|r| {
+            r.get(p!(id), index);
+            r.delete(p!(id), index);
+            r.options("custom", index);
+            r.get("custom", index);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::options("https://reign.rs/api/1")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    assert_eq!(res.headers().get("allow").unwrap(), "DELETE, GET, OPTIONS");
+
+    let res = service
+        .clone()
+        .call(
+            Req::options("https://reign.rs/api/custom")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+
+    let res = service
+        .call(
+            Req::options("https://reign.rs/missing")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_auto_head() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index".respond()?.header("x-version", "1")?)
+    }
+
+    async fn head(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("head")
+    }
+
+    let service = service(|r| {
+        r.auto_head(true);
+
+        r.get("index", index);
+        r.post("create", index);
+        r.get("custom", index);
+        r.head("custom", head);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::head("https://reign.rs/index")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get("content-length").unwrap(), "5");
+    assert_eq!(res.headers().get("content-type").unwrap(), "text/plain");
+    assert_eq!(res.headers().get("x-version").unwrap(), "1");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+
+    let res = service
+        .clone()
+        .call(
+            Req::head("https://reign.rs/custom")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "head");
+
+    let res = service
+        .clone()
+        .call(
+            Req::head("https://reign.rs/create")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(res.headers().get("allow").unwrap(), "POST");
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/index")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(res.headers().get("allow").unwrap(), "GET, HEAD");
+}
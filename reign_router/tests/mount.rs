@@ -0,0 +1,81 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    middleware::HeadersDefault,
+    service, Error, Request, Response, Router,
+};
+
+async fn users(_: &mut Request) -> Result<impl Response, Error> {
+    Ok("users")
+}
+
+async fn posts(_: &mut Request) -> Result<impl Response, Error> {
+    Ok("posts")
+}
+
+fn users_routes(r: &mut Router) {
+    r.pipe("common")
+        .add(HeadersDefault::empty().add("x-module", "users"));
+
+    r.scope("").through(&["common"]).to(|r| {
+        r.get("", users);
+    });
+}
+
+fn posts_routes(r: &mut Router) {
+    r.pipe("common")
+        .add(HeadersDefault::empty().add("x-module", "posts"));
+
+    r.scope("").through(&["common"]).to(|r| {
+        r.get("", posts);
+    });
+}
+
+macro_rules! call {
+    ($service:ident, $path:expr, $body:expr) => {
+        let res = $service
+            .clone()
+            .call(
+                Req::get($path).body(Body::empty()).unwrap(),
+                "10.10.10.10:80".parse().unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get("x-module").unwrap(), $body);
+        assert_eq!(to_bytes(res.into_body()).await.unwrap(), $body);
+    };
+}
+
+#[tokio::test]
+async fn test_mount() {
+    let service = service(|r| {
+        r.mount("users", users_routes);
+        r.mount("posts", posts_routes);
+    });
+
+    call!(service, "https://reign.rs/users", "users");
+    call!(service, "https://reign.rs/posts", "posts");
+}
+
+#[tokio::test]
+async fn test_merge() {
+    let service = service(|r| {
+        r.merge(users_routes);
+        r.scope("posts").to(|r| {
+            r.merge(posts_routes);
+        });
+    });
+
+    call!(service, "https://reign.rs", "users");
+    call!(service, "https://reign.rs/posts", "posts");
+}
+
+#[test]
+#[should_panic(expected = "pipe `common` is already defined")]
+fn test_merge_pipe_collision() {
+    service(|r| {
+        r.merge(users_routes);
+        r.merge(posts_routes);
+    });
+}
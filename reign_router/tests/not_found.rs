@@ -0,0 +1,108 @@
+use reign_router::{
+    helpers::json,
+    hyper::{
+        body::to_bytes,
+        header::{ALLOW, CONTENT_TYPE},
+        Body, Request as Req, StatusCode,
+    },
+    service, Error, Request, Response,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Envelope {
+    error: String,
+}
+
+async fn foo(_: &mut Request) -> Result<impl Response, Error> {
+    Ok("foo")
+}
+
+async fn not_found(req: &mut Request) -> Result<impl Response, Error> {
+    Ok(json(
+        Envelope {
+            error: format!("{} not found", req.path()),
+        },
+        200,
+    )?)
+}
+
+async fn method_not_allowed(_: &mut Request) -> Result<impl Response, Error> {
+    Ok("not allowed")
+}
+
+#[tokio::test]
+async fn test_not_found() {
+    let service = service(|r| {
+        r.not_found(not_found);
+        r.get("foo", foo);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/bar")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        r#"{"error":"/bar not found"}"#
+    );
+}
+
+#[tokio::test]
+async fn test_not_found_status() {
+    async fn gone(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(json(
+            Envelope {
+                error: "gone".into(),
+            },
+            410,
+        )?)
+    }
+
+    let service = service(|r| {
+        r.not_found(gone);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/bar")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::GONE);
+}
+
+#[tokio::test]
+async fn test_method_not_allowed() {
+    let service = service(|r| {
+        r.not_found(not_found);
+        r.method_not_allowed(method_not_allowed);
+        r.get("foo", foo);
+    });
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/foo")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(res.headers().get(ALLOW).unwrap(), "GET");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "not allowed");
+}
@@ -0,0 +1,82 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, Response as HyperResponse, StatusCode},
+    middleware::NotModified,
+    service, Error, Request, Response,
+};
+
+#[tokio::test]
+async fn test_not_modified() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(HyperResponse::builder()
+            .header("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+            .body(Body::from("index"))?)
+    }
+
+    let service = service(|r| {
+        r.pipe("app").add(NotModified::default());
+
+        r.scope("").through(&["app"]).to(|r| {
+            r.get("foo", index);
+            r.post("foo", index);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/foo")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/foo")
+                .header("if-modified-since", "Thu, 22 Oct 2015 07:28:00 GMT")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+    assert!(res.headers().contains_key("last-modified"));
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/foo")
+                .header("if-modified-since", "Tue, 20 Oct 2015 07:28:00 GMT")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/foo")
+                .header("if-modified-since", "Thu, 22 Oct 2015 07:28:00 GMT")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+}
@@ -1,5 +1,5 @@
 use reign_router::{
-    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    hyper::{body::to_bytes, Body, Method, Request as Req, StatusCode},
     middleware::HeadersDefault,
     service, Error, Request, Response,
 };
@@ -18,6 +18,27 @@ async fn test_invalid_pipe() {
     });
 }
 
+#[tokio::test]
+#[should_panic(expected = "can't find pipe with name `app`")]
+async fn test_invalid_pipe_empty_scope() {
+    service(|r| {
+        r.scope("").through(&["app"]).to(|_| {});
+    });
+}
+
+#[tokio::test]
+#[should_panic(expected = "can't find pipe with name `comon`")]
+async fn test_misspelled_pipe() {
+    service(|r| {
+        r.pipe("common")
+            .add(HeadersDefault::empty().add("x-powered-by", "reign"));
+
+        r.scope("").through(&["comon"]).to(|r| {
+            r.get("", index);
+        });
+    });
+}
+
 #[tokio::test]
 #[should_panic(expected = "can't find pipe with name `secret`")]
 async fn test_scope_pipe_not_visible() {
@@ -107,3 +128,91 @@ async fn test_pipe_in_upper_scope() {
     assert!(res.headers().contains_key("x-powered-by"));
     assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
 }
+
+#[tokio::test]
+async fn test_pipe_constraint() {
+    let service = service(|r| {
+        r.pipe("cache")
+            .constraint(|req| *req.method() == Method::GET)
+            .add(HeadersDefault::empty().add("cache-control", "max-age=60"));
+
+        r.scope("").through(&["cache"]).to(|r| {
+            r.any(&[Method::GET, Method::POST], "", index);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs").body(Body::empty()).unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get("cache-control").unwrap(), "max-age=60");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+
+    let res = service
+        .clone()
+        .call(
+            Req::post("https://reign.rs").body(Body::empty()).unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(!res.headers().contains_key("cache-control"));
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+}
+
+#[tokio::test]
+async fn test_route_middleware() {
+    let service = service(|r| {
+        r.pipe("common").add(
+            HeadersDefault::empty()
+                .add("x-powered-by", "reign")
+                .add("x-layer", "scope"),
+        );
+
+        r.scope("").through(&["common"]).to(|r| {
+            r.get("admin", index).through([HeadersDefault::empty()
+                .add("cache-control", "no-store")
+                .add("x-layer", "route")]);
+            r.get("", index);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/admin")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["cache-control"], "no-store");
+    assert_eq!(res.headers()["x-powered-by"], "reign");
+    assert_eq!(res.headers()["x-layer"], "scope");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs").body(Body::empty()).unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(!res.headers().contains_key("cache-control"));
+    assert_eq!(res.headers()["x-powered-by"], "reign");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+}
@@ -0,0 +1,179 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    service, Error, Request, Response,
+};
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Pagination {
+    page: u32,
+    limit: Option<u32>,
+    #[serde(default)]
+    sort: Vec<String>,
+}
+
+#[tokio::test]
+async fn test_query_into() {
+    async fn list(req: &mut Request) -> Result<impl Response, Error> {
+        let pagination = req.query_into::<Pagination>()?;
+
+        Ok(format!(
+            "{} {:?} {}",
+            pagination.page,
+            pagination.limit,
+            pagination.sort.join(",")
+        ))
+    }
+
+    let service = service(|r| {
+        r.get("list", list);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/list?page=2&sort=name&sort=age")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "2 None name,age");
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/list?limit=50")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_query_into_missing_field() {
+    async fn list(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req.query_into::<Pagination>().err().unwrap().to_string())
+    }
+
+    let service = service(|r| {
+        r.get("list", list);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/list?limit=50")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "missing field `page`"
+    );
+}
+
+#[tokio::test]
+async fn test_query_all() {
+    async fn tags(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(format!(
+            "{} {} {:?}",
+            req.query("tag").unwrap(),
+            req.query_all("tag").join(","),
+            req.query_all("missing")
+        ))
+    }
+
+    let service = service(|r| {
+        r.get("tags", tags);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/tags?tag=a&tag=b&other=c&tag=d")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "a a,b,d []");
+}
+
+#[tokio::test]
+async fn test_query_map_decoding() {
+    async fn search(req: &mut Request) -> Result<impl Response, Error> {
+        let map = req.query_map();
+
+        Ok(format!(
+            "{:?} {:?}",
+            map.get("q").unwrap(),
+            map.get("first name").unwrap()
+        ))
+    }
+
+    let service = service(|r| {
+        r.get("search", search);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/search?q=hello%20world&q=a+b%2Bc&first+name=reign")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        r#"["hello world", "a b+c"] ["reign"]"#
+    );
+}
+
+#[tokio::test]
+async fn test_query_repeated_first() {
+    #[derive(Deserialize)]
+    struct Page {
+        page: u32,
+    }
+
+    async fn page(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(format!(
+            "{} {}",
+            req.query("page").unwrap(),
+            req.query_into::<Page>()?.page
+        ))
+    }
+
+    let service = service(|r| {
+        r.get("page", page);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/page?page=1&page=2")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "1 1");
+}
@@ -0,0 +1,98 @@
+use reign_router::{
+    hyper::{header::RETRY_AFTER, Body, Request as Req, StatusCode},
+    middleware::RateLimit,
+    service, Error, Request, Response,
+};
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+async fn index(_: &mut Request) -> Result<impl Response, Error> {
+    Ok("index")
+}
+
+#[tokio::test]
+async fn test_rate_limit() {
+    let service = service(|r| {
+        r.pipe("api")
+            .add(RateLimit::new(2, Duration::from_millis(200)));
+
+        r.scope("").through(&["api"]).to(|r| {
+            r.get("", index);
+        });
+    });
+
+    macro_rules! call {
+        ($ip:expr) => {
+            service
+                .clone()
+                .call(
+                    Req::get("https://reign.rs").body(Body::empty()).unwrap(),
+                    $ip.parse().unwrap(),
+                )
+                .await
+                .unwrap()
+        };
+    }
+
+    assert_eq!(call!("10.10.10.10:80").status(), StatusCode::OK);
+    assert_eq!(call!("10.10.10.10:81").status(), StatusCode::OK);
+
+    let res = call!("10.10.10.10:80");
+
+    assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(res.headers().get(RETRY_AFTER).unwrap(), "1");
+
+    // Other clients have their own bucket
+    assert_eq!(call!("10.10.10.11:80").status(), StatusCode::OK);
+
+    // One token is refilled every 100ms
+    sleep(Duration::from_millis(150)).await;
+
+    assert_eq!(call!("10.10.10.10:80").status(), StatusCode::OK);
+    assert_eq!(
+        call!("10.10.10.10:80").status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+}
+
+#[tokio::test]
+async fn test_rate_limit_key_by() {
+    let service = service(|r| {
+        r.pipe("api").add(RateLimit::per_minute(1).key_by(|req| {
+            req.headers()
+                .get("x-api-token")
+                .and_then(|x| x.to_str().ok())
+                .unwrap_or_default()
+                .to_string()
+        }));
+
+        r.scope("").through(&["api"]).to(|r| {
+            r.get("", index);
+        });
+    });
+
+    macro_rules! call {
+        ($token:expr) => {
+            service
+                .clone()
+                .call(
+                    Req::get("https://reign.rs")
+                        .header("x-api-token", $token)
+                        .body(Body::empty())
+                        .unwrap(),
+                    "10.10.10.10:80".parse().unwrap(),
+                )
+                .await
+                .unwrap()
+        };
+    }
+
+    assert_eq!(call!("one").status(), StatusCode::OK);
+    assert_eq!(call!("two").status(), StatusCode::OK);
+
+    let res = call!("one");
+
+    assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(res.headers().get(RETRY_AFTER).unwrap(), "60");
+}
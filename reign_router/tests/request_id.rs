@@ -0,0 +1,90 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    middleware::RequestId,
+    service, Error, Request, Response,
+};
+
+async fn id(req: &mut Request) -> Result<impl Response, Error> {
+    Ok(req.request_id().unwrap_or_default().to_string())
+}
+
+#[tokio::test]
+async fn test_request_id_incoming() {
+    let service = service(|r| {
+        r.pipe("common").add(RequestId::default());
+
+        r.scope("").through(&["common"]).to(|r| {
+            r.get("id", id);
+        });
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/id")
+                .header("x-request-id", "abc-123")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get("x-request-id").unwrap(), "abc-123");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "abc-123");
+}
+
+#[tokio::test]
+async fn test_request_id_generated() {
+    let service = service(|r| {
+        r.pipe("common").add(
+            RequestId::default()
+                .header("x-correlation-id")
+                .trust_incoming(false),
+        );
+
+        r.scope("").through(&["common"]).to(|r| {
+            r.get("id", id);
+        });
+
+        r.get("none", id);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/id")
+                .header("x-correlation-id", "abc-123")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let header = res
+        .headers()
+        .get("x-correlation-id")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_ne!(header, "abc-123");
+    assert_eq!(header.len(), 36);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), header);
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/none")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert!(!res.headers().contains_key("x-correlation-id"));
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+}
@@ -0,0 +1,93 @@
+use reign_router::{
+    hyper::{Body, Request as Req, StatusCode},
+    middleware::RequestLogger,
+    path as p, service, Error, Request, Response,
+};
+
+use log::{set_logger, set_max_level, Level, LevelFilter, Log, Metadata, Record};
+use std::{sync::Mutex, time::Duration};
+use tokio::time::sleep;
+
+struct Sink(Mutex<Vec<(Level, String)>>);
+
+impl Log for Sink {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        if record.target() == "reign_router" {
+            self.0
+                .lock()
+                .unwrap()
+                .push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static SINK: Sink = Sink(Mutex::new(Vec::new()));
+
+#[tokio::test]
+async fn test_request_logger_slow() {
+    async fn slow(req: &mut Request) -> Result<impl Response, Error> {
+        sleep(Duration::from_millis(50)).await;
+        Ok(req.param::<String>("id")?)
+    }
+
+    async fn failing(_: &mut Request) -> Result<String, Error> {
+        sleep(Duration::from_millis(50)).await;
+        Err(Error::Status(StatusCode::BAD_GATEWAY))
+    }
+
+    set_logger(&SINK).unwrap();
+    set_max_level(LevelFilter::Warn);
+
+    let service = service(|r| {
+        r.pipe("common")
+            .add(RequestLogger::new(Level::Debug).slow(Duration::from_millis(10)));
+
+        r.scope("").through(&["common"]).to(|r| {
+            r.get(p!("users" / id), slow);
+            r.get("failing", failing);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/users/1")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/failing")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_GATEWAY);
+
+    let records = SINK.0.lock().unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].0, Level::Warn);
+    assert!(records[0]
+        .1
+        .starts_with("Slow request GET /users/1 (/users/{id}) - 200 OK - "));
+    assert_eq!(records[1].0, Level::Warn);
+    assert!(records[1]
+        .1
+        .starts_with("Slow request GET /failing (/failing) - 502 Bad Gateway - "));
+}
@@ -1,4 +1,12 @@
-use reign_router::{hyper::Body, Response};
+use reign_router::{
+    helpers::redirect,
+    hyper::{
+        body::to_bytes, header::CONTENT_LENGTH, Body, Request as Req, Response as HyperResponse,
+        StatusCode,
+    },
+    middleware::cookie::Cookie,
+    service, Error, Request, Response, ResponseExt,
+};
 
 #[test]
 fn test_invalid_status_code() {
@@ -8,3 +16,111 @@ fn test_invalid_status_code() {
 
     assert!(response.is_err());
 }
+
+#[tokio::test]
+async fn test_content_length_mismatch() {
+    async fn wrong(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(HyperResponse::builder()
+            .header(CONTENT_LENGTH, "100")
+            .body(Body::from("hello"))?)
+    }
+
+    let service = service(|r| {
+        r.get("wrong", wrong);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/wrong")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "5");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "hello");
+}
+
+#[tokio::test]
+async fn test_content_length_head() {
+    async fn head(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(HyperResponse::builder()
+            .header(CONTENT_LENGTH, "100")
+            .body(Body::empty())?)
+    }
+
+    let service = service(|r| {
+        r.head("head", head);
+    });
+
+    let res = service
+        .call(
+            Req::head("https://reign.rs/head")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "100");
+}
+
+#[tokio::test]
+async fn test_content_length_transfer_encoding() {
+    async fn chunked(_: &mut Request) -> Result<impl Response, Error> {
+        let (mut sender, body) = Body::channel();
+
+        tokio::spawn(async move {
+            sender.send_data("hello".into()).await.unwrap();
+        });
+
+        Ok(HyperResponse::builder()
+            .header(CONTENT_LENGTH, "5")
+            .header("transfer-encoding", "chunked")
+            .body(body)?)
+    }
+
+    let service = service(|r| {
+        r.get("chunked", chunked);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/chunked")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(res.headers().get(CONTENT_LENGTH).is_none());
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "hello");
+}
+
+#[test]
+fn test_response_ext_headers() {
+    let response = redirect("/dashboard")
+        .unwrap()
+        .header("set-cookie", "user=reign")
+        .unwrap()
+        .cookie(Cookie::new("theme", "dark"))
+        .header("x-powered-by", "reign")
+        .unwrap();
+
+    let cookies = response
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(cookies, vec!["user=reign", "theme=dark"]);
+    assert_eq!(response.headers().get("location").unwrap(), "/dashboard");
+    assert_eq!(response.headers().get("x-powered-by").unwrap(), "reign");
+}
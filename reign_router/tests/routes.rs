@@ -0,0 +1,327 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Method, Request as Req, StatusCode},
+    path as p, service, Error, Path, Request, Response, Route,
+};
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_extend() {
+    async fn page(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req.uri().path().to_string())
+    }
+
+    let service = service(|r| {
+        r.extend(
+            vec!["about", "contact"]
+                .into_iter()
+                .map(|p| Route::new(p).methods(&[Method::GET]).handle(page)),
+        );
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/about")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "/about");
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/contact")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "/contact");
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/about")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn test_extend_constraint() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index")
+    }
+
+    let service = service(|r| {
+        r.extend(Some(
+            Route::new("foo")
+                .constraint(|req| req.uri().port().is_some())
+                .handle(index),
+        ));
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/foo")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs:8080/foo")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "index");
+}
+
+#[test]
+#[should_panic(expected = "is defined more than once")]
+fn test_duplicate() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index")
+    }
+
+    service(|r| {
+        r.get("foo", index);
+        r.scope("").to(|r| {
+            r.get("foo", index);
+        });
+    });
+}
+
+#[tokio::test]
+async fn test_duplicate_allowed() {
+    async fn foo(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("foo")
+    }
+
+    async fn bar(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("bar")
+    }
+
+    let service = service(|r| {
+        r.allow_duplicates();
+
+        r.get("foo", foo);
+        r.get("foo", bar);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/foo")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "foo");
+}
+
+#[test]
+fn test_duplicate_with_constraint() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index")
+    }
+
+    service(|r| {
+        r.any_with_constraint(
+            &[Method::GET],
+            "foo",
+            |req| req.uri().port().is_some(),
+            index,
+        );
+        r.get("foo", index);
+    });
+}
+
+#[tokio::test]
+async fn test_timeout() {
+    async fn report(req: &mut Request) -> Result<impl Response, Error> {
+        sleep(Duration::from_millis(req.param("ms")?)).await;
+        Ok("report")
+    }
+
+    let service = service(|r| {
+        r.extend(vec![Route::new(Path::new().path("report").param("ms"))
+            .methods(&[Method::GET])
+            .timeout(Duration::from_millis(50))
+            .handle(report)]);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/report/10")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "report");
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/report/200")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[tokio::test]
+async fn test_cache_control() {
+    async fn config(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("config")
+    }
+
+    let service = service(|r| {
+        r.extend(vec![Route::new("config")
+            .methods(&[Method::GET])
+            .cache_control("public, max-age=3600")
+            .handle(config)]);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/config")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get("cache-control").unwrap(),
+        "public, max-age=3600"
+    );
+}
+
+#[tokio::test]
+async fn test_url_for() {
+    async fn show(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req.url_for("user_show", &[("id", "42")])?)
+    }
+
+    let service = service(|r| {
+        r.scope("api").to(|r| {
+            r.scope("users").to(|r| {
+                r.get(p!(id), show).name("user_show");
+            });
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/api/users/1")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "/api/users/42");
+    assert_eq!(
+        service.url_for("user_show", &[("id", "7")]).unwrap(),
+        "/api/users/7"
+    );
+    assert!(service.url_for("user_list", &[]).is_err());
+}
+
+#[tokio::test]
+async fn test_route_mut() {
+    async fn report(req: &mut Request) -> Result<impl Response, Error> {
+        sleep(Duration::from_millis(req.param("ms")?)).await;
+        Ok(req.url_for("config", &[])?)
+    }
+
+    async fn config(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("config")
+    }
+
+    let service = service(|r| {
+        r.get(p!("report" / ms), report)
+            .timeout(Duration::from_millis(50))
+            .cache_control("no-store")
+            .name("report");
+
+        r.extend(vec![Route::new("config")
+            .methods(&[Method::GET])
+            .name("config")
+            .handle(config)]);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/report/10")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get("cache-control").unwrap(), "no-store");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "/config");
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/report/200")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(
+        service.url_for("report", &[("ms", "5")]).unwrap(),
+        "/report/5"
+    );
+}
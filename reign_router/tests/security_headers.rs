@@ -0,0 +1,65 @@
+use reign_router::{
+    helpers::render,
+    hyper::{Body, Request as Req, StatusCode},
+    middleware::SecurityHeaders,
+    service, Error, Request, Response,
+};
+
+#[tokio::test]
+async fn test_security_headers() {
+    async fn html(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(render("<h1>reign</h1>", 200)?)
+    }
+
+    async fn json(_: &mut Request) -> Result<impl Response, Error> {
+        Ok((mime::APPLICATION_JSON, "{}"))
+    }
+
+    let service = service(|r| {
+        r.pipe("common").add(SecurityHeaders::default());
+
+        r.scope("").through(&["common"]).to(|r| {
+            r.get("html", html);
+            r.get("json", json);
+        });
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/html")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get("content-security-policy").unwrap(),
+        "default-src 'self'"
+    );
+    assert_eq!(
+        res.headers().get("x-content-type-options").unwrap(),
+        "nosniff"
+    );
+    assert_eq!(res.headers().get("x-frame-options").unwrap(), "DENY");
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/json")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(!res.headers().contains_key("content-security-policy"));
+    assert_eq!(
+        res.headers().get("x-content-type-options").unwrap(),
+        "nosniff"
+    );
+}
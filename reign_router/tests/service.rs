@@ -0,0 +1,241 @@
+use reign_router::{
+    bind,
+    futures::{channel::oneshot, FutureExt},
+    hyper::{body::to_bytes, service::Service as _, Body, Request as Req, StatusCode},
+    serve, serve_incoming, serve_many, serve_with_shutdown, service, Error, Request, Response,
+    Router, ServeError,
+};
+
+use std::{
+    net::{SocketAddr, TcpListener},
+    sync::Mutex,
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    time::sleep,
+};
+
+// Address with a port assigned by the OS, for the functions which bind it themselves
+fn free_addr() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+}
+
+async fn listening(addr: SocketAddr) {
+    while TcpStream::connect(addr).await.is_err() {
+        sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_remote_service() {
+    async fn ip(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req.ip().to_string())
+    }
+
+    let mut service = service(|r| {
+        r.get("ip", ip);
+    })
+    .with_remote_addr("10.10.10.10:80".parse().unwrap());
+
+    let res = service
+        .call(Req::get("https://reign.rs/ip").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "10.10.10.10:80");
+}
+
+#[tokio::test]
+async fn test_serve_many() {
+    async fn public(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("public")
+    }
+
+    async fn admin(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("admin")
+    }
+
+    fn public_router(r: &mut Router) {
+        r.get("", public);
+    }
+
+    fn admin_router(r: &mut Router) {
+        r.get("", admin);
+    }
+
+    let (public_addr, admin_addr) = (free_addr(), free_addr());
+    let (sender, receiver) = oneshot::channel::<()>();
+
+    let server = tokio::spawn(serve_many(
+        vec![
+            (public_addr, public_router as fn(&mut Router)),
+            (admin_addr, admin_router),
+        ],
+        receiver.map(|_| ()),
+    ));
+
+    listening(public_addr).await;
+    listening(admin_addr).await;
+
+    let response = reqwest::get(format!("http://{}", public_addr))
+        .await
+        .unwrap();
+    assert_eq!(response.text().await.unwrap(), "public");
+
+    let response = reqwest::get(format!("http://{}", admin_addr))
+        .await
+        .unwrap();
+    assert_eq!(response.text().await.unwrap(), "admin");
+
+    sender.send(()).unwrap();
+
+    assert!(server.await.unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn test_serve_addr_error() {
+    let err = serve("not an address", |_| {}).await.unwrap_err();
+
+    assert!(matches!(err, ServeError::Addr(_)));
+}
+
+#[tokio::test]
+async fn test_serve_bind_error() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+    let err = serve(listener.local_addr().unwrap(), |_| {})
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ServeError::Bind(_)));
+}
+
+#[tokio::test]
+async fn test_serve_max_headers() {
+    async fn ok(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("ok")
+    }
+
+    fn router(r: &mut Router) {
+        r.max_headers(10);
+        r.get("", ok);
+    }
+
+    let (addr, server) = bind("127.0.0.1:0", router).unwrap();
+    let server = tokio::spawn(server);
+
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    let headers = (0..20)
+        .map(|i| format!("x-header-{}: {}\r\n", i, i))
+        .collect::<String>();
+
+    stream
+        .write_all(format!("GET / HTTP/1.1\r\nHost: localhost\r\n{}\r\n", headers).as_bytes())
+        .await
+        .unwrap();
+
+    let mut buf = [0; 1024];
+    let n = stream.read(&mut buf).await.unwrap();
+
+    assert!(String::from_utf8_lossy(&buf[..n])
+        .starts_with("HTTP/1.1 431 Request Header Fields Too Large"));
+
+    let response = reqwest::get(format!("http://{}", addr)).await.unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), "ok");
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn test_serve_with_shutdown() {
+    struct Accepted(Mutex<Option<oneshot::Sender<()>>>);
+
+    // Shuts down the server once the request has been accepted
+    async fn slow(req: &mut Request) -> Result<impl Response, Error> {
+        let accepted = req.state::<Accepted>().unwrap();
+        let sender = accepted.0.lock().unwrap().take();
+
+        sender.unwrap().send(()).unwrap();
+        sleep(Duration::from_millis(200)).await;
+
+        Ok("slow")
+    }
+
+    let addr = free_addr();
+    let (sender, receiver) = oneshot::channel::<()>();
+
+    let server = tokio::spawn(serve_with_shutdown(
+        addr,
+        |r: &mut Router| {
+            r.state(Accepted(Mutex::new(Some(sender))));
+            r.get("", slow);
+        },
+        receiver.map(|_| ()),
+    ));
+
+    listening(addr).await;
+
+    let client = tokio::spawn(reqwest::get(format!("http://{}", addr)));
+
+    // In-flight requests are finished before the server stops
+    let res = client.await.unwrap().unwrap();
+
+    assert_eq!(res.status(), reqwest::StatusCode::OK);
+    assert_eq!(res.text().await.unwrap(), "slow");
+
+    server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_serve_incoming() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index")
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    assert_ne!(addr.port(), 0);
+
+    let server = tokio::spawn(serve_incoming(listener, |r: &mut Router| {
+        r.get("", index);
+    }));
+
+    let response = reqwest::get(format!("http://{}", addr)).await.unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), "index");
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn test_bind() {
+    async fn index(_: &mut Request) -> Result<impl Response, Error> {
+        Ok("index")
+    }
+
+    let (addr, server) = bind("127.0.0.1:0", |r: &mut Router| {
+        r.get("", index);
+    })
+    .unwrap();
+
+    assert_ne!(addr.port(), 0);
+
+    let server = tokio::spawn(server);
+
+    let response = reqwest::get(format!("http://{}", addr)).await.unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), "index");
+
+    server.abort();
+}
@@ -0,0 +1,74 @@
+use reign_router::{
+    futures::stream::{iter, once},
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    service, Error, Request, Response, Sse, SseEvent,
+};
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_sse() {
+    async fn events(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(Sse::new(iter(vec![
+            SseEvent::new().id("1").data("hello"),
+            SseEvent::new().event("update").data("line one\nline two"),
+        ]))
+        .keep_alive(None))
+    }
+
+    let service = service(|r| {
+        r.get("events", events);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/events")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+    assert_eq!(res.headers().get("cache-control").unwrap(), "no-cache");
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "id: 1\ndata: hello\n\nevent: update\ndata: line one\ndata: line two\n\n"
+    );
+}
+
+#[tokio::test]
+async fn test_sse_keep_alive() {
+    async fn events(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(Sse::new(once(async {
+            sleep(Duration::from_millis(50)).await;
+            SseEvent::new().data("late")
+        }))
+        .keep_alive(Some(Duration::from_millis(20))))
+    }
+
+    let service = service(|r| {
+        r.get("events", events);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/events")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(res.into_body()).await.unwrap();
+
+    assert!(body.starts_with(b":\n\n"));
+    assert!(body.ends_with(b"data: late\n\n"));
+}
@@ -0,0 +1,36 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    service, Error, Request, Response,
+};
+
+struct Config {
+    name: &'static str,
+}
+
+#[tokio::test]
+async fn test_state() {
+    async fn name(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req.state::<Config>().map(|x| x.name).unwrap_or("none"))
+    }
+
+    let service = service(|r| {
+        r.state(Config { name: "reign" });
+
+        r.scope("api").to(|r| {
+            r.get("name", name);
+        });
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/api/name")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "reign");
+}
@@ -0,0 +1,240 @@
+use reign_router::{
+    handlers::static_files,
+    hyper::{
+        body::to_bytes,
+        header::{
+            ALLOW, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE,
+            IF_NONE_MATCH, LAST_MODIFIED, RANGE,
+        },
+        Body, Method, Request as Req, StatusCode,
+    },
+    path as p, service, Service,
+};
+
+fn static_service() -> Service {
+    service(|r| {
+        r.any(
+            &[Method::GET, Method::HEAD, Method::POST],
+            p!("assets" / path*),
+            static_files("tests/static/public"),
+        );
+    })
+}
+
+macro_rules! call {
+    ($service:ident, $method:ident, $path:expr) => {
+        $service
+            .clone()
+            .call(
+                Req::$method($path).body(Body::empty()).unwrap(),
+                "10.10.10.10:80".parse().unwrap(),
+            )
+            .await
+            .unwrap()
+    };
+}
+
+#[tokio::test]
+async fn test_static_files() {
+    let service = static_service();
+
+    let res = call!(service, get, "https://reign.rs/assets/index.html");
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "text/html");
+    assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "34");
+    assert!(res.headers().contains_key(LAST_MODIFIED));
+    assert!(res
+        .headers()
+        .get(ETAG)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .starts_with("W/\"22-"));
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "<html>\n  <body>Hey</body>\n</html>\n"
+    );
+
+    let res = call!(service, head, "https://reign.rs/assets/css/app.css");
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "25");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+}
+
+#[tokio::test]
+async fn test_static_files_content_type() {
+    let service = static_service();
+
+    for (path, content_type) in [
+        ("index.html", "text/html"),
+        ("css/app.css", "text/css"),
+        ("notes.txt", "text/plain"),
+        ("data.json", "application/json"),
+    ] {
+        let res = call!(service, get, format!("https://reign.rs/assets/{}", path));
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), content_type);
+    }
+}
+
+#[tokio::test]
+async fn test_static_files_not_found() {
+    let service = static_service();
+
+    let res = call!(service, get, "https://reign.rs/assets/missing.html");
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = call!(service, get, "https://reign.rs/assets/css");
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_static_files_traversal() {
+    let service = static_service();
+
+    for path in [
+        "../secret.txt",
+        "css/../../secret.txt",
+        "%2e%2e/secret.txt",
+        "%2e%2e%2fsecret.txt",
+        "..%5csecret.txt",
+        "%2fetc%2fpasswd",
+    ] {
+        let res = call!(service, get, format!("https://reign.rs/assets/{}", path));
+
+        assert_eq!(res.status(), StatusCode::NOT_FOUND, "{}", path);
+        assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+    }
+}
+
+#[tokio::test]
+async fn test_static_files_method_not_allowed() {
+    let service = static_service();
+
+    let res = call!(service, post, "https://reign.rs/assets/index.html");
+
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(res.headers().get(ALLOW).unwrap(), "GET, HEAD");
+}
+
+#[tokio::test]
+async fn test_static_files_if_none_match() {
+    let service = static_service();
+
+    let res = call!(service, get, "https://reign.rs/assets/index.html");
+    let etag = res.headers().get(ETAG).unwrap().clone();
+
+    macro_rules! conditional {
+        ($value:expr) => {
+            service
+                .clone()
+                .call(
+                    Req::get("https://reign.rs/assets/index.html")
+                        .header(IF_NONE_MATCH, $value)
+                        .body(Body::empty())
+                        .unwrap(),
+                    "10.10.10.10:80".parse().unwrap(),
+                )
+                .await
+                .unwrap()
+        };
+    }
+
+    let res = conditional!(etag.clone());
+
+    assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(res.headers().get(ETAG).unwrap(), etag);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+
+    let res = conditional!("W/\"other\", \"another\"");
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        "<html>\n  <body>Hey</body>\n</html>\n"
+    );
+}
+
+#[tokio::test]
+async fn test_static_files_if_modified_since() {
+    let service = static_service();
+
+    let res = call!(service, get, "https://reign.rs/assets/index.html");
+    let modified = res.headers().get(LAST_MODIFIED).unwrap().clone();
+
+    macro_rules! conditional {
+        ($value:expr) => {
+            service
+                .clone()
+                .call(
+                    Req::get("https://reign.rs/assets/index.html")
+                        .header(IF_MODIFIED_SINCE, $value)
+                        .body(Body::empty())
+                        .unwrap(),
+                    "10.10.10.10:80".parse().unwrap(),
+                )
+                .await
+                .unwrap()
+        };
+    }
+
+    assert_eq!(conditional!(modified).status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(
+        conditional!("Thu, 01 Jan 1970 00:00:00 GMT").status(),
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn test_static_files_range() {
+    let service = static_service();
+
+    macro_rules! range {
+        ($value:expr) => {
+            service
+                .clone()
+                .call(
+                    Req::get("https://reign.rs/assets/index.html")
+                        .header(RANGE, $value)
+                        .body(Body::empty())
+                        .unwrap(),
+                    "10.10.10.10:80".parse().unwrap(),
+                )
+                .await
+                .unwrap()
+        };
+    }
+
+    let res = range!("bytes=9-15");
+
+    assert_eq!(res.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(res.headers().get(CONTENT_RANGE).unwrap(), "bytes 9-15/34");
+    assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "7");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "<body>H");
+
+    let res = range!("bytes=-8");
+
+    assert_eq!(res.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(res.headers().get(CONTENT_RANGE).unwrap(), "bytes 26-33/34");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "</html>\n");
+
+    let res = range!("bytes=26-");
+
+    assert_eq!(res.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "</html>\n");
+
+    let res = range!("bytes=100-");
+
+    assert_eq!(res.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(res.headers().get(CONTENT_RANGE).unwrap(), "bytes */34");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "");
+
+    // Multiple ranges respond with the whole file
+    let res = range!("bytes=0-1,5-6");
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "34");
+}
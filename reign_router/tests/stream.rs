@@ -0,0 +1,68 @@
+use reign_router::{
+    futures::stream::iter,
+    hyper::{
+        body::{to_bytes, Bytes},
+        header::{CONTENT_LENGTH, CONTENT_TYPE},
+        Body, Request as Req, StatusCode,
+    },
+    service, Error, Request, Response, StreamBody,
+};
+
+use std::io::{Cursor, Error as IoError};
+
+#[tokio::test]
+async fn test_stream() {
+    async fn chunks(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(StreamBody::new(iter(vec![
+            Ok::<_, IoError>(Bytes::from("one ")),
+            Ok(Bytes::from("two ")),
+            Ok(Bytes::from("three")),
+        ]))
+        .content_type(mime::TEXT_PLAIN))
+    }
+
+    let service = service(|r| {
+        r.get("chunks", chunks);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/chunks")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()[CONTENT_TYPE], "text/plain");
+    assert!(!res.headers().contains_key(CONTENT_LENGTH));
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "one two three");
+}
+
+#[tokio::test]
+async fn test_stream_from_reader() {
+    async fn reader(_: &mut Request) -> Result<impl Response, Error> {
+        Ok(StreamBody::from_reader(Cursor::new("hello world")).content_length(11))
+    }
+
+    let service = service(|r| {
+        r.get("reader", reader);
+    });
+
+    let res = service
+        .call(
+            Req::get("https://reign.rs/reader")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()[CONTENT_TYPE], "application/octet-stream");
+    assert_eq!(res.headers()[CONTENT_LENGTH], "11");
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "hello world");
+}
@@ -0,0 +1,125 @@
+use reign_router::{
+    hyper::{body::to_bytes, Body, Request as Req, StatusCode},
+    middleware::Timeout,
+    service, Error, Request, Response,
+};
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::time::sleep;
+
+struct Dropped(Arc<AtomicBool>);
+
+impl Drop for Dropped {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+async fn slow(req: &mut Request) -> Result<impl Response, Error> {
+    let _guard = Dropped(req.state::<AtomicBool>().unwrap());
+
+    sleep(Duration::from_millis(200)).await;
+    Ok("slow")
+}
+
+async fn fast(_: &mut Request) -> Result<impl Response, Error> {
+    Ok("fast")
+}
+
+#[tokio::test]
+async fn test_timeout() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let state = dropped.clone();
+
+    let service = service(|r| {
+        r.state_arc(state);
+
+        r.pipe("api").add(Timeout::new(Duration::from_millis(50)));
+        r.pipe("custom")
+            .add(Timeout::new(Duration::from_millis(50)).status(StatusCode::GATEWAY_TIMEOUT));
+
+        r.scope("").through(&["api"]).to(|r| {
+            r.get("slow", slow);
+            r.get("fast", fast);
+        });
+
+        r.scope("custom").through(&["custom"]).to(|r| {
+            r.get("slow", slow);
+        });
+    });
+
+    macro_rules! call {
+        ($path:expr) => {
+            service
+                .clone()
+                .call(
+                    Req::get($path).body(Body::empty()).unwrap(),
+                    "10.10.10.10:80".parse().unwrap(),
+                )
+                .await
+                .unwrap()
+        };
+    }
+
+    let res = call!("https://reign.rs/slow");
+
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(dropped.load(Ordering::SeqCst));
+
+    let res = call!("https://reign.rs/fast");
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "fast");
+
+    let res = call!("https://reign.rs/custom/slow");
+
+    assert_eq!(res.status(), StatusCode::GATEWAY_TIMEOUT);
+}
+
+#[tokio::test]
+async fn test_route_timeout_with_middleware() {
+    let service = service(|r| {
+        r.state(AtomicBool::new(false));
+
+        r.pipe("api").add(Timeout::new(Duration::from_millis(50)));
+        r.pipe("custom")
+            .add(Timeout::new(Duration::from_secs(5)).status(StatusCode::GATEWAY_TIMEOUT));
+
+        r.scope("").through(&["api"]).to(|r| {
+            r.get("slow", slow).timeout(Duration::from_secs(1));
+        });
+
+        r.scope("custom").through(&["custom"]).to(|r| {
+            r.get("slow", slow).timeout(Duration::from_millis(50));
+        });
+    });
+
+    macro_rules! call {
+        ($path:expr) => {
+            service
+                .clone()
+                .call(
+                    Req::get($path).body(Body::empty()).unwrap(),
+                    "10.10.10.10:80".parse().unwrap(),
+                )
+                .await
+                .unwrap()
+        };
+    }
+
+    // The shorter timeout of the middleware still applies
+    let res = call!("https://reign.rs/slow");
+
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    // The timeout of the route wraps the middleware
+    let res = call!("https://reign.rs/custom/slow");
+
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
@@ -0,0 +1,126 @@
+use reign_router::{serve_tls, Error, Request, Response, Router, ServeError, TlsConfig, TlsError};
+
+use std::{
+    net::{SocketAddr, TcpListener},
+    time::Duration,
+};
+use tokio::{
+    net::TcpStream,
+    time::{sleep, timeout},
+};
+
+async fn hello(_: &mut Request) -> Result<impl Response, Error> {
+    Ok("hello")
+}
+
+fn router(r: &mut Router) {
+    r.get("", hello);
+}
+
+// Address with a port assigned by the OS, since `serve_tls` binds it itself
+fn free_addr() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+}
+
+async fn listening(addr: SocketAddr) {
+    while TcpStream::connect(addr).await.is_err() {
+        sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_serve_tls() {
+    let config = TlsConfig::new()
+        .cert_path("tests/tls/cert.pem")
+        .key_path("tests/tls/key.pem");
+
+    let addr = free_addr();
+
+    tokio::spawn(serve_tls(addr, router, config));
+
+    listening(addr).await;
+
+    let client = reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap();
+
+    let res = client
+        .get(format!("https://{}", addr))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), reqwest::StatusCode::OK);
+    assert_eq!(res.text().await.unwrap(), "hello");
+}
+
+#[tokio::test]
+async fn test_serve_tls_missing_key() {
+    let config = TlsConfig::new().cert_path("tests/tls/cert.pem");
+
+    let err = serve_tls("127.0.0.1:0", router, config).await;
+
+    assert!(matches!(err, Err(ServeError::Tls(_))));
+}
+
+#[tokio::test]
+async fn test_serve_tls_invalid_key() {
+    let config = TlsConfig::new()
+        .cert_path("tests/tls/cert.pem")
+        .key_pem("not a key");
+
+    let err = serve_tls("127.0.0.1:0", router, config).await;
+
+    assert!(matches!(err, Err(ServeError::Tls(_))));
+}
+
+#[tokio::test]
+async fn test_serve_tls_key_mismatch() {
+    let config = TlsConfig::new()
+        .cert_path("tests/tls/cert.pem")
+        .key_path("tests/tls/other_key.pem");
+
+    let err = serve_tls("127.0.0.1:0", router, config).await;
+
+    assert!(matches!(err, Err(ServeError::Tls(TlsError::KeyMismatch))));
+}
+
+#[tokio::test]
+async fn test_serve_tls_handshake_timeout() {
+    let config = TlsConfig::new()
+        .cert_path("tests/tls/cert.pem")
+        .key_path("tests/tls/key.pem")
+        .handshake_timeout(Duration::from_millis(200));
+
+    let addr = free_addr();
+
+    tokio::spawn(serve_tls(addr, router, config));
+
+    listening(addr).await;
+
+    // Connections which never start the handshake
+    let mut idle = vec![];
+
+    for _ in 0..100 {
+        idle.push(TcpStream::connect(addr).await.unwrap());
+    }
+
+    let client = reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap();
+
+    let res = timeout(
+        Duration::from_secs(5),
+        client.get(format!("https://{}", addr)).send(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(res.status(), reqwest::StatusCode::OK);
+}
@@ -0,0 +1,134 @@
+#![cfg(unix)]
+
+use reign_router::{
+    futures::{channel::oneshot, FutureExt},
+    hyper::{body::to_bytes, client::conn::handshake, Body, Request as Req, StatusCode},
+    serve_unix_with_shutdown, Error, Request, Response, Router, ServeError, UnixPeer,
+};
+
+use std::{
+    env::temp_dir,
+    fs::{read_to_string, remove_file, write},
+    os::unix::{fs::MetadataExt, net::UnixListener},
+    process::id,
+    time::Duration,
+};
+use tokio::{net::UnixStream, time::sleep};
+
+#[tokio::test]
+async fn test_serve_unix() {
+    async fn ip(req: &mut Request) -> Result<impl Response, Error> {
+        Ok(req.ip().to_string())
+    }
+
+    let path = temp_dir().join("reign_router_test_serve_unix.sock");
+
+    // Stale socket file from a previous run
+    drop(UnixListener::bind(&path).unwrap());
+
+    let (sender, receiver) = oneshot::channel::<()>();
+
+    let server = tokio::spawn(serve_unix_with_shutdown(
+        path.clone(),
+        |r: &mut Router| {
+            r.get("", ip);
+        },
+        receiver.map(|_| ()),
+    ));
+
+    sleep(Duration::from_millis(100)).await;
+
+    let stream = UnixStream::connect(&path).await.unwrap();
+    let (mut client, conn) = handshake(stream).await.unwrap();
+
+    tokio::spawn(conn);
+
+    let res = client
+        .send_request(Req::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(to_bytes(res.into_body()).await.unwrap(), "0.0.0.0:0");
+
+    drop(client);
+    sender.send(()).unwrap();
+    server.await.unwrap().unwrap();
+
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn test_serve_unix_peer() {
+    async fn peer(req: &mut Request) -> Result<impl Response, Error> {
+        let peer = req.ext::<UnixPeer>().unwrap();
+        Ok(format!("{}:{}", peer.uid, peer.pid.unwrap()))
+    }
+
+    let path = temp_dir().join("reign_router_test_serve_unix_peer.sock");
+    let (sender, receiver) = oneshot::channel::<()>();
+
+    let server = tokio::spawn(serve_unix_with_shutdown(
+        path.clone(),
+        |r: &mut Router| {
+            r.get("", peer);
+        },
+        receiver.map(|_| ()),
+    ));
+
+    sleep(Duration::from_millis(100)).await;
+
+    // The socket file is owned by the same user as the test
+    let uid = path.metadata().unwrap().uid();
+
+    let stream = UnixStream::connect(&path).await.unwrap();
+    let (mut client, conn) = handshake(stream).await.unwrap();
+
+    tokio::spawn(conn);
+
+    let res = client
+        .send_request(Req::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        to_bytes(res.into_body()).await.unwrap(),
+        format!("{}:{}", uid, id())
+    );
+
+    drop(client);
+    sender.send(()).unwrap();
+    server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_serve_unix_not_socket() {
+    let path = temp_dir().join("reign_router_test_serve_unix_not_socket.sock");
+
+    write(&path, "data").unwrap();
+
+    let err = serve_unix_with_shutdown(path.clone(), |_: &mut Router| {}, async {})
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ServeError::Unix(..)));
+    assert_eq!(read_to_string(&path).unwrap(), "data");
+
+    remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_serve_unix_in_use() {
+    let path = temp_dir().join("reign_router_test_serve_unix_in_use.sock");
+    let _ = remove_file(&path);
+    let _listener = UnixListener::bind(&path).unwrap();
+
+    let err = serve_unix_with_shutdown(path.clone(), |_: &mut Router| {}, async {})
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ServeError::Unix(..)));
+    assert!(path.exists());
+
+    remove_file(&path).unwrap();
+}
@@ -0,0 +1,96 @@
+use reign_router::{
+    bind,
+    futures::{SinkExt, StreamExt},
+    hyper::{Body, Method, Request as Req, StatusCode},
+    service,
+    websocket::Message,
+    Error, Request, Response, Router,
+};
+
+use tokio::net::TcpStream;
+use tokio_tungstenite::client_async;
+
+async fn echo(req: &mut Request) -> Result<impl Response, Error> {
+    let (response, upgrade) = req.upgrade_websocket()?;
+
+    tokio::spawn(async move {
+        let mut ws = upgrade.await.unwrap();
+
+        while let Some(Ok(message)) = ws.next().await {
+            if let Message::Text(text) = message {
+                ws.send(Message::Text(format!("echo {}", text)))
+                    .await
+                    .unwrap();
+            }
+        }
+    });
+
+    Ok(response)
+}
+
+#[tokio::test]
+async fn test_websocket_echo() {
+    fn router(r: &mut Router) {
+        r.get("ws", echo);
+    }
+
+    let (addr, server) = bind("127.0.0.1:0", router).unwrap();
+    let server = tokio::spawn(server);
+
+    let stream = TcpStream::connect(addr).await.unwrap();
+    let (mut ws, res) = client_async(format!("ws://{}/ws", addr), stream)
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::SWITCHING_PROTOCOLS);
+
+    ws.send(Message::Text("hello".into())).await.unwrap();
+
+    assert_eq!(
+        ws.next().await.unwrap().unwrap(),
+        Message::Text("echo hello".into())
+    );
+
+    ws.close(None).await.unwrap();
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn test_websocket_bad_request() {
+    let service = service(|r| {
+        r.any(&[Method::GET, Method::POST], "ws", echo);
+    });
+
+    let res = service
+        .clone()
+        .call(
+            Req::get("https://reign.rs/ws")
+                .header("upgrade", "websocket")
+                .header("connection", "Upgrade")
+                .header("sec-websocket-version", "13")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let res = service
+        .call(
+            Req::post("https://reign.rs/ws")
+                .header("upgrade", "websocket")
+                .header("connection", "Upgrade")
+                .header("sec-websocket-version", "13")
+                .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+                .body(Body::empty())
+                .unwrap(),
+            "10.10.10.10:80".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
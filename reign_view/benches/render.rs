@@ -0,0 +1,70 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use reign::{prelude::*, view::Escape};
+
+use std::fmt::{Display, Formatter, Result};
+
+views!("benches", "views");
+
+const ROWS: usize = 100;
+
+// Renders the same output as the `table` view with a `write!` for every node, which is
+// how views were generated before the adjacent writes were coalesced
+struct PerNode<'a> {
+    title: &'a str,
+    count: u32,
+    active: bool,
+}
+
+impl Display for PerNode<'_> {
+    #[allow(clippy::write_literal)]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", "<ul>")?;
+
+        for _ in 0..ROWS {
+            write!(f, "{}", "\n  ")?;
+            write!(f, "{}", "<li>")?;
+            write!(f, "{}", "<span>")?;
+            write!(f, "{}", Escape(&self.title))?;
+            write!(f, "{}", "</span>")?;
+            write!(f, "{}", " has ")?;
+            write!(f, "{}", "<b>")?;
+            write!(f, "{}", Escape(&self.count))?;
+            write!(f, "{}", "</b>")?;
+            write!(f, "{}", " items and is ")?;
+            write!(f, "{}", "<i>")?;
+            write!(f, "{}", Escape(&self.active))?;
+            write!(f, "{}", "</i>")?;
+            write!(f, "{}", "</li>")?;
+        }
+
+        write!(f, "{}", "\n</ul>")
+    }
+}
+
+fn bench_render(c: &mut Criterion) {
+    let coalesced = views::Table {
+        _slots: Default::default(),
+        title: "Reign",
+        count: 42,
+        active: true,
+    };
+
+    let per_node = PerNode {
+        title: "Reign",
+        count: 42,
+        active: true,
+    };
+
+    assert_eq!(coalesced.to_string(), per_node.to_string());
+
+    let mut group = c.benchmark_group("render_table");
+
+    group.bench_function("per_node", |b| b.iter(|| black_box(&per_node).to_string()));
+    group.bench_function("coalesced", |b| {
+        b.iter(|| black_box(&coalesced).to_string())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_render);
+criterion_main!(benches);
@@ -1,11 +1,11 @@
 use crate::{
-    parse::{parse, tokenize},
+    parse::{lint, parse, tokenize, Level},
     INTERNAL_ERR,
 };
 use inflector::cases::pascalcase::to_pascal_case;
 use once_cell::sync::Lazy;
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use proc_macro2::{Group, Ident, Span, TokenStream, TokenTree};
+use quote::quote_spanned;
 use regex::Regex;
 use std::{collections::HashMap, fs::read_to_string, io::Error, path::Path};
 
@@ -17,28 +17,87 @@ pub static FILE_REGEX: Lazy<Regex> = Lazy::new(|| {
 pub static FOLDER_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^([[:alpha:]]([[:word:]]*[[:alnum:]])?)").expect(INTERNAL_ERR));
 
-pub fn tokenize_view(path: &Path, file_base_name: &str) -> (TokenStream, Vec<(Ident, bool)>) {
+/// Generates the view struct for the template at `path`.
+///
+/// Proc macros can't point spans into the template file itself, so the generated fields and
+/// expressions are spanned with `span`, which should be the location that declared the views.
+/// The exact template positions are kept in the field docs.
+pub fn tokenize_view(
+    path: &Path,
+    file_base_name: &str,
+    span: Span,
+) -> (TokenStream, Vec<(Ident, bool)>) {
     let cased = to_pascal_case(file_base_name);
-    let ident = Ident::new(&cased, Span::call_site());
-
-    let (tokens, idents, types) = tokenize(
-        parse(
-            read_to_string(path)
-                .expect(INTERNAL_ERR)
-                .replace("\r\n", "\n"),
-        )
-        .expect(INTERNAL_ERR),
-    );
+    let ident = Ident::new(&cased, span);
 
-    let new_idents: Vec<Ident> = idents.iter().map(|x| x.0.clone()).collect();
+    let node = parse(
+        read_to_string(path)
+            .expect(INTERNAL_ERR)
+            .replace("\r\n", "\n"),
+    )
+    .unwrap_or_else(|err| panic!("`{}` {}", path.display(), err));
+
+    let (errors, warnings): (Vec<_>, Vec<_>) = lint(&node)
+        .into_iter()
+        .partition(|x| x.level == Level::Error);
+
+    if !errors.is_empty() {
+        panic!(
+            "{}",
+            errors
+                .iter()
+                .map(|x| format!("`{}` {}", path.display(), x))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
 
-    (
-        quote! {
-            pub struct #ident<'a> {
-                pub _slots: ::reign::view::Slots<'a>,
-                #(pub #new_idents: #types),*
+    let (tokens, idents, types, positions, capacity) = tokenize(node);
+    let tokens = respan(tokens, span);
+
+    let new_idents: Vec<Ident> = idents
+        .iter()
+        .map(|x| {
+            let mut ident = x.0.clone();
+            ident.set_span(span);
+            ident
+        })
+        .collect();
+
+    // Point the fields back to the template so that type errors on them are easier to trace
+    let docs: Vec<String> = positions
+        .iter()
+        .map(|p| match p {
+            Some((line, column)) => format!(
+                "Used in `{}` at line {}, column {}",
+                path.display(),
+                line,
+                column
+            ),
+            None => format!("Used in `{}`", path.display()),
+        })
+        .collect();
+
+    let render = if is_async(&tokens) {
+        quote_spanned! {span=>
+            #[allow(unused_variables)]
+            impl<'a> ::reign::view::AsyncRender for #ident<'a> {
+                fn render<'b>(
+                    &'b self,
+                    buf: &'b mut String,
+                ) -> ::std::pin::Pin<Box<dyn ::std::future::Future<Output = std::fmt::Result> + Send + 'b>> {
+                    Box::pin(async move {
+                        use std::fmt::Write;
+
+                        let f = buf;
+                        #tokens
+                        Ok(())
+                    })
+                }
             }
-
+        }
+    } else {
+        quote_spanned! {span=>
             #[allow(unused_variables)]
             impl<'a> std::fmt::Display for #ident<'a> {
                 fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -46,11 +105,75 @@ pub fn tokenize_view(path: &Path, file_base_name: &str) -> (TokenStream, Vec<(Id
                     Ok(())
                 }
             }
+        }
+    };
+
+    let types = types.into_iter().map(|x| respan(x, span));
+
+    // Proc macros can't emit warnings on stable, so each one is generated as the use of a
+    // deprecated item, which the compiler reports at the views declaration
+    let warnings = warnings.iter().map(|x| {
+        let note = format!("`{}` {}", path.display(), x);
+
+        quote_spanned! {span=>
+            const _: () = {
+                #[deprecated(note = #note)]
+                #[allow(non_camel_case_types)]
+                struct template_warning;
+
+                let _ = template_warning;
+            };
+        }
+    });
+
+    (
+        quote_spanned! {span=>
+            pub struct #ident<'a> {
+                pub _slots: ::reign::view::Slots<'a>,
+                #(
+                    #[doc = #docs]
+                    pub #new_idents: #types
+                ),*
+            }
+
+            impl<'a> #ident<'a> {
+                /// Length of the static content of the view, used to pre-size the rendered output.
+                pub const CAPACITY: usize = #capacity;
+            }
+
+            #render
+
+            #(#warnings)*
         },
         idents,
     )
 }
 
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut tt| {
+            if let TokenTree::Group(group) = &tt {
+                let mut new = Group::new(group.delimiter(), respan(group.stream(), span));
+                new.set_span(span);
+                return TokenTree::Group(new);
+            }
+
+            tt.set_span(span);
+            tt
+        })
+        .collect()
+}
+
+// Views that await in their expressions are rendered asynchronously
+fn is_async(tokens: &TokenStream) -> bool {
+    tokens.clone().into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => ident == "await",
+        TokenTree::Group(group) => is_async(&group.stream()),
+        _ => false,
+    })
+}
+
 pub fn recurse<O, I, P>(
     path: &Path,
     relative_path: &str,
@@ -58,6 +181,7 @@ pub fn recurse<O, I, P>(
     folder_hook: O,
     file_hook: I,
     path_hook: P,
+    span: Span,
 ) -> Result<Vec<TokenStream>, Error>
 where
     O: Fn(Ident, Vec<TokenStream>) -> Result<TokenStream, Error> + Copy,
@@ -87,6 +211,7 @@ where
                     folder_hook,
                     file_hook,
                     path_hook,
+                    span,
                 )?;
 
                 views.push(folder_hook(ident, sub_views)?);
@@ -98,7 +223,7 @@ where
             }
 
             let file_base_name = file_name.trim_end_matches(".html");
-            let (file_view, idents) = tokenize_view(&new_path, file_base_name);
+            let (file_view, idents) = tokenize_view(&new_path, file_base_name, span);
 
             let file_key = format!("{}:{}", relative_path, file_base_name)
                 .trim_start_matches(':')
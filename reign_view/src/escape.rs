@@ -0,0 +1,76 @@
+use std::fmt::{Display, Formatter, Result, Write};
+
+/// Escapes the displayed value so that it can be written as HTML text content.
+pub struct Escape<T>(pub T);
+
+/// Escapes the displayed value so that it can be written inside a quoted attribute.
+pub struct EscapeAttr<T>(pub T);
+
+struct EscapeWriter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    attr: bool,
+}
+
+impl<'a, 'b> Write for EscapeWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let mut last = 0;
+
+        for (i, c) in s.char_indices() {
+            let escaped = match c {
+                '&' => "&amp;",
+                '<' => "&lt;",
+                '>' => "&gt;",
+                '"' if self.attr => "&quot;",
+                '\'' if self.attr => "&#39;",
+                _ => continue,
+            };
+
+            self.f.write_str(&s[last..i])?;
+            self.f.write_str(escaped)?;
+            last = i + 1;
+        }
+
+        self.f.write_str(&s[last..])
+    }
+}
+
+impl<T> Display for Escape<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(EscapeWriter { f, attr: false }, "{}", self.0)
+    }
+}
+
+impl<T> Display for EscapeAttr<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(EscapeWriter { f, attr: true }, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            format!("{}", Escape("<script>\"a\" & 'b'</script>")),
+            "&lt;script&gt;\"a\" &amp; 'b'&lt;/script&gt;"
+        );
+        assert_eq!(format!("{}", Escape(12)), "12");
+    }
+
+    #[test]
+    fn test_escape_attr() {
+        assert_eq!(
+            format!("{}", EscapeAttr("a\"b'<c>&d")),
+            "a&quot;b&#39;&lt;c&gt;&amp;d"
+        );
+        assert_eq!(format!("{}", EscapeAttr(12)), "12");
+    }
+}
@@ -6,10 +6,14 @@ pub use maplit;
 
 #[doc(hidden)]
 pub mod common;
-#[doc(hidden)]
+mod escape;
 pub mod parse;
+mod render;
 mod slots;
 
+#[doc(hidden)]
+pub use escape::{Escape, EscapeAttr};
+pub use render::AsyncRender;
 #[doc(hidden)]
 pub use slots::{slot_render, Slots};
 
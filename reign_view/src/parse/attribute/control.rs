@@ -1,14 +1,16 @@
 use super::super::consts::*;
-use super::{Code, Error, Parse, ParseStream};
+use super::{Code, Error, Parse, ParseStream, SourceSpan};
 
 #[derive(Debug)]
 pub struct ControlAttribute {
     pub name: String,
     pub value: Code,
+    pub span: SourceSpan,
 }
 
 impl Parse for ControlAttribute {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
+        let start = input.cursor;
         let name = input.capture(CTRL_ATTR, 1)?;
 
         Ok(ControlAttribute {
@@ -20,6 +22,7 @@ impl Parse for ControlAttribute {
                     Code::parse_expr(input)?
                 }
             },
+            span: input.span_from(start),
         })
     }
 }
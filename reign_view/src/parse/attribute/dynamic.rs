@@ -1,5 +1,5 @@
 use super::super::consts::*;
-use super::{Code, Error, Parse, ParseStream, Tokenize, ViewFields};
+use super::{Code, Error, Parse, ParseStream, SourceSpan, Tokenize, ViewFields};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::LitStr;
@@ -11,10 +11,12 @@ pub struct DynamicAttribute {
     pub name: Code,
     pub suffix: String,
     pub value: Code,
+    pub span: SourceSpan,
 }
 
 impl Parse for DynamicAttribute {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
+        let start = input.cursor;
         Ok(DynamicAttribute {
             symbol: input.step(":")?,
             prefix: input.matched(DY_ATTR_NAME_PART)?,
@@ -24,6 +26,7 @@ impl Parse for DynamicAttribute {
             },
             suffix: input.matched(DY_ATTR_NAME_PART)?,
             value: Code::parse_expr(input)?,
+            span: input.span_from(start),
         })
     }
 }
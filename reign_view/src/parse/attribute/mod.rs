@@ -1,5 +1,5 @@
 use super::consts::*;
-use super::{Code, Error, Parse, ParseStream, Tokenize, ViewFields};
+use super::{Code, Error, Parse, ParseStream, SourceSpan, Tokenize, ViewFields};
 use proc_macro2::TokenStream;
 
 mod control;
@@ -9,10 +9,10 @@ mod value;
 mod variable;
 
 pub use control::ControlAttribute;
-use dynamic::DynamicAttribute;
+pub use dynamic::DynamicAttribute;
 pub use normal::NormalAttribute;
 pub use value::AttributeValue;
-use variable::VariableAttribute;
+pub use variable::VariableAttribute;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
@@ -23,6 +23,18 @@ pub enum Attribute {
     Control(ControlAttribute),
 }
 
+impl Attribute {
+    /// Location of this attribute in the template source.
+    pub fn span(&self) -> SourceSpan {
+        match self {
+            Attribute::Normal(n) => n.span,
+            Attribute::Dynamic(d) => d.span,
+            Attribute::Variable(v) => v.span,
+            Attribute::Control(c) => c.span,
+        }
+    }
+}
+
 impl Parse for Attribute {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
         if input.is_match(&dy_attr_regex()) {
@@ -1,5 +1,5 @@
-use super::super::consts::*;
-use super::{AttributeValue, Error, Parse, ParseStream, Tokenize, ViewFields};
+use super::super::{consts::*, StringPart};
+use super::{AttributeValue, Error, Parse, ParseStream, SourceSpan, Tokenize, ViewFields};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::LitStr;
@@ -8,13 +8,16 @@ use syn::LitStr;
 pub struct NormalAttribute {
     pub name: String,
     pub value: AttributeValue,
+    pub span: SourceSpan,
 }
 
 impl Parse for NormalAttribute {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
+        let start = input.cursor;
         Ok(NormalAttribute {
             name: input.matched(ATTR_NAME)?,
             value: input.parse()?,
+            span: input.span_from(start),
         })
     }
 }
@@ -22,13 +25,44 @@ impl Parse for NormalAttribute {
 impl Tokenize for NormalAttribute {
     fn tokenize(&self, tokens: &mut TokenStream, idents: &mut ViewFields, scopes: &ViewFields) {
         let name = LitStr::new(&self.name, Span::call_site());
-        let mut value = TokenStream::new();
 
-        self.value.tokenize(&mut value, idents, scopes);
+        if !self.value.has_expr() {
+            let mut value = TokenStream::new();
+
+            self.value.tokenize(&mut value, idents, scopes);
+
+            tokens.append_all(quote! {
+                write!(f, " {}=\"{}\"", #name, #value)?;
+            });
+
+            return;
+        }
+
+        // Write the interpolated parts directly instead of building the value with `format!`
+        let format_arg_lit = LitStr::new(
+            &format!(" {{}}=\"{}\"", "{}".repeat(self.value.parts.len())),
+            Span::call_site(),
+        );
+
+        let parts: Vec<TokenStream> = self
+            .value
+            .parts
+            .iter()
+            .map(|part| {
+                let mut ts = TokenStream::new();
+
+                part.tokenize(&mut ts, idents, scopes);
+
+                if let StringPart::Expr(_) = part {
+                    quote! { ::reign::view::EscapeAttr(&(#ts)) }
+                } else {
+                    ts
+                }
+            })
+            .collect();
 
-        // TODO:(view:html-escape) value
         tokens.append_all(quote! {
-            write!(f, " {}=\"{}\"", #name, #value)?;
+            write!(f, #format_arg_lit, #name, #(#parts),*)?;
         });
     }
 }
@@ -26,7 +26,7 @@ impl AttributeValue {
 
     pub fn has_expr(&self) -> bool {
         for part in &self.parts {
-            if let StringPart::Expr(_) = part {
+            if let StringPart::Expr(_) | StringPart::Raw(_) = part {
                 return true;
             }
         }
@@ -1,4 +1,4 @@
-use super::{var_attr_regex, Code, Error, Parse, ParseStream, Tokenize, ViewFields};
+use super::{var_attr_regex, Code, Error, Parse, ParseStream, SourceSpan, Tokenize, ViewFields};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::LitStr;
@@ -7,13 +7,16 @@ use syn::LitStr;
 pub struct VariableAttribute {
     pub name: String,
     pub value: Code,
+    pub span: SourceSpan,
 }
 
 impl Parse for VariableAttribute {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
+        let start = input.cursor;
         Ok(VariableAttribute {
             name: input.capture(&var_attr_regex(), 1)?,
             value: Code::parse_expr(input)?,
+            span: input.span_from(start),
         })
     }
 }
@@ -0,0 +1,129 @@
+use proc_macro2::{Group, Literal, Span, TokenStream, TokenTree};
+use quote::{quote, TokenStreamExt};
+use syn::LitStr;
+
+// Merges consecutive writes of static strings into a single `write_str` call so that
+// big views don't go through the formatting machinery for every node.
+//
+// Also returns the total length of the static strings which is used as a capacity hint.
+pub fn coalesce(tokens: TokenStream) -> (TokenStream, usize) {
+    let mut len = 0;
+
+    let trees = tokens
+        .into_iter()
+        .map(|tree| match tree {
+            TokenTree::Group(group) => {
+                let (stream, inner_len) = coalesce(group.stream());
+                let mut inner = Group::new(group.delimiter(), stream);
+
+                len += inner_len;
+
+                inner.set_span(group.span());
+                TokenTree::Group(inner)
+            }
+            tree => tree,
+        })
+        .collect::<Vec<_>>();
+
+    let mut output = TokenStream::new();
+    let mut text = String::new();
+    let mut i = 0;
+
+    while i < trees.len() {
+        if let Some(value) = static_write(&trees[i..]) {
+            len += value.len();
+            text.push_str(&value);
+            i += 5;
+        } else {
+            flush(&mut output, &mut text);
+            output.append(trees[i].clone());
+            i += 1;
+        }
+    }
+
+    flush(&mut output, &mut text);
+    (output, len)
+}
+
+fn flush(output: &mut TokenStream, text: &mut String) {
+    if text.is_empty() {
+        return;
+    }
+
+    let text_lit = LitStr::new(text, Span::call_site());
+
+    output.append_all(quote! {
+        f.write_str(#text_lit)?;
+    });
+    text.clear();
+}
+
+// Returns the string written by `write!(f, "{}", "..")?;` or `write!(f, "..")?;`
+fn static_write(trees: &[TokenTree]) -> Option<String> {
+    match trees {
+        [TokenTree::Ident(name), TokenTree::Punct(bang), TokenTree::Group(args), TokenTree::Punct(question), TokenTree::Punct(semi), ..]
+            if name == "write"
+                && bang.as_char() == '!'
+                && question.as_char() == '?'
+                && semi.as_char() == ';' =>
+        {
+            let args = args.stream().into_iter().collect::<Vec<_>>();
+
+            match args.as_slice() {
+                [TokenTree::Ident(f), TokenTree::Punct(comma), TokenTree::Literal(format)]
+                    if f == "f" && comma.as_char() == ',' =>
+                {
+                    lit_str(format).filter(|x| !x.contains('{') && !x.contains('}'))
+                }
+                [TokenTree::Ident(f), TokenTree::Punct(comma), TokenTree::Literal(format), TokenTree::Punct(comma2), TokenTree::Literal(value)]
+                    if f == "f" && comma.as_char() == ',' && comma2.as_char() == ',' =>
+                {
+                    if lit_str(format)? == "{}" {
+                        lit_str(value)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn lit_str(lit: &Literal) -> Option<String> {
+    syn::parse2::<LitStr>(TokenTree::Literal(lit.clone()).into())
+        .ok()
+        .map(|x| x.value())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_coalesce() {
+        let (tokens, len) = coalesce(quote! {
+            write!(f, "{}", "<div")?;
+            write!(f, ">")?;
+            if self.a {
+                write!(f, "{}", "a")?;
+                write!(f, "{}", "b")?;
+            }
+            write!(f, "{}", self.b)?;
+            write!(f, "{}", "</div>")?;
+        });
+
+        let expected = quote! {
+            f.write_str("<div>")?;
+            if self.a {
+                f.write_str("ab")?;
+            }
+            write!(f, "{}", self.b)?;
+            f.write_str("</div>")?;
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+        assert_eq!(len, 13);
+    }
+}
@@ -3,9 +3,12 @@ use proc_macro2::TokenStream;
 use std::fmt::{Debug, Error as FError, Formatter};
 use syn::parse_str;
 
+/// Line and column in the template where the code starts
+pub type Position = (usize, usize);
+
 pub enum Code {
-    For(For),
-    Expr(Expr),
+    For(For, Position),
+    Expr(Expr, Position),
 }
 
 impl Code {
@@ -23,17 +26,25 @@ impl Code {
         let parsed = parse_str::<For>(text);
 
         if let Ok(code) = parsed {
-            Ok(Code::For(code))
+            Ok(Code::For(code, input.position()))
         } else {
             Err(input.error("expected pattern in expression"))
         }
     }
 
     pub fn parse_expr_from_str(input: &ParseStream, text: &str) -> Result<Self, Error> {
+        Self::parse_expr_at(input, text, input.position())
+    }
+
+    pub fn parse_expr_at(
+        input: &ParseStream,
+        text: &str,
+        position: Position,
+    ) -> Result<Self, Error> {
         let parsed = parse_str::<Expr>(text);
 
         if let Ok(code) = parsed {
-            Ok(Code::Expr(code))
+            Ok(Code::Expr(code, position))
         } else {
             Err(input.error("expected expression"))
         }
@@ -42,10 +53,17 @@ impl Code {
 
 impl Tokenize for Code {
     fn tokenize(&self, tokens: &mut TokenStream, idents: &mut ViewFields, scopes: &ViewFields) {
+        // Remember where the code is so that the fields it uses can point back to the template
+        let previous = match self {
+            Code::For(_, p) | Code::Expr(_, p) => idents.position.replace(*p),
+        };
+
         match self {
-            Code::For(f) => f.tokenize(tokens, idents, scopes),
-            Code::Expr(e) => e.tokenize(tokens, idents, scopes),
+            Code::For(f, _) => f.tokenize(tokens, idents, scopes),
+            Code::Expr(e, _) => e.tokenize(tokens, idents, scopes),
         }
+
+        idents.position = previous;
     }
 }
 
@@ -1,4 +1,4 @@
-use super::{Error, Parse, ParseStream, Tokenize, ViewFields};
+use super::{Error, Parse, ParseStream, SourceSpan, Tokenize, ViewFields};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::LitStr;
@@ -6,20 +6,35 @@ use syn::LitStr;
 #[derive(Debug)]
 pub struct Comment {
     pub content: String,
+    pub span: SourceSpan,
+}
+
+impl Comment {
+    /// Whether this is a server comment (`<!--! ... -->`) which is not rendered.
+    pub fn is_server(&self) -> bool {
+        self.content.starts_with('!')
+    }
 }
 
 impl Parse for Comment {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
+        let start = input.cursor;
+
         input.step("<!--")?;
 
         Ok(Comment {
             content: input.until("-->", true)?,
+            span: input.span_from(start),
         })
     }
 }
 
 impl Tokenize for Comment {
     fn tokenize(&self, tokens: &mut TokenStream, _: &mut ViewFields, _: &ViewFields) {
+        if self.is_server() {
+            return;
+        }
+
         let content = format!("<!--{}-->", self.content);
         let comment_str = LitStr::new(&content, Span::call_site());
 
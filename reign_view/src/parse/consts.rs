@@ -3,6 +3,7 @@ pub const TAG_NAME: &str = "[[:alpha:]](:?[a-zA-Z0-9\\-]*[[:alnum:]])?";
 pub const ATTR_NAME: &str = "[^\\s\"\'>/=]+";
 pub const VAR_ATTR_SYMBOL: &str = ":";
 pub const CTRL_ATTR: &str = "!(for|if|else-if|else)";
+pub const TRIM_ATTR: &str = "!trim(\\s|/?>)";
 pub const DY_ATTR_NAME_PART: &str = "[^\\{}\\s\"\'>/=]*";
 pub const DY_ATTR_EXPR: &str = "\\{\\{([^=]+)}}";
 pub const ATTR_VALUE_DOUBLE_QUOTED: &str = "\"([^\"]*)\"";
@@ -1,5 +1,5 @@
 use super::consts::DOCTYPE;
-use super::{Error, Parse, ParseStream, Tokenize, ViewFields};
+use super::{Error, Parse, ParseStream, SourceSpan, Tokenize, ViewFields};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::LitStr;
@@ -7,12 +7,16 @@ use syn::LitStr;
 #[derive(Debug)]
 pub struct Doctype {
     pub content: String,
+    pub span: SourceSpan,
 }
 
 impl Parse for Doctype {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
+        let start = input.cursor;
+
         Ok(Doctype {
             content: input.matched(DOCTYPE)?,
+            span: input.span_from(start),
         })
     }
 }
@@ -1,7 +1,8 @@
 use super::consts::*;
 use super::{
     attribute::{ControlAttribute, NormalAttribute},
-    tag_name_regex, Attribute, Code, Error, Node, Parse, ParseStream, Tokenize, ViewFields,
+    tag_name_regex, Attribute, Code, Error, Node, Parse, ParseStream, SourceSpan, Tokenize,
+    ViewFields,
 };
 use inflector::cases::{pascalcase::to_pascal_case, snakecase::to_snake_case};
 use proc_macro2::{Punct, Spacing, Span, TokenStream};
@@ -13,6 +14,7 @@ pub struct Element {
     pub name: String,
     pub attrs: Vec<Attribute>,
     pub children: Vec<Node>,
+    pub span: SourceSpan,
 }
 
 impl Element {
@@ -53,12 +55,22 @@ impl Element {
         "default".to_string()
     }
 
+    // Named slot content can be given either as `<template #name>` or `<template slot="name">`
     fn template_name(&self) -> Option<String> {
         if self.name == "template" {
             for attr in &self.attrs {
                 if let Attribute::Normal(n) = attr {
-                    if n.name.starts_with('#') {
-                        return Some(n.name.clone());
+                    if let Some(name) = n.name.strip_prefix('#') {
+                        return Some(name.to_string());
+                    }
+
+                    if n.name == "slot" {
+                        if let Some(name) = n.value.value() {
+                            return Some(name);
+                        } else {
+                            // TODO:(view:err) Show the error position
+                            panic!("slot name should not have expression");
+                        }
                     }
                 }
             }
@@ -78,12 +90,11 @@ impl Element {
         for child in &self.children {
             if let Node::Element(e) = child {
                 if let Some(name) = e.template_name() {
-                    let name = name.get(1..).unwrap();
                     let mut ts = TokenStream::new();
 
                     child.tokenize(&mut ts, idents, scopes);
 
-                    names.push(LitStr::new(name, Span::call_site()));
+                    names.push(LitStr::new(&name, Span::call_site()));
                     templates.push(ts);
                 }
             }
@@ -238,16 +249,31 @@ impl Element {
 
 impl Parse for Element {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
+        let start = input.cursor;
         let name = input.capture(&tag_name_regex(), 1)?;
+        let lowercase = name.to_lowercase();
+        let mut trim = false;
 
         Ok(Element {
-            name: name.to_lowercase(),
+            // HTML tags are case insensitive, but component tags need to keep
+            // their case so that they can be resolved to the view structs.
+            name: if is_reserved_tag(&lowercase) {
+                lowercase
+            } else {
+                name.clone()
+            },
             attrs: {
                 let mut attrs = vec![];
                 input.skip_spaces()?;
 
                 while !input.peek("/>") && !input.peek(">") {
-                    attrs.push(input.parse()?);
+                    if input.is_match(TRIM_ATTR) {
+                        input.step("!trim")?;
+                        trim = true;
+                    } else {
+                        attrs.push(input.parse()?);
+                    }
+
                     input.skip_spaces()?;
                 }
 
@@ -267,10 +293,20 @@ impl Parse for Element {
                         let closing_tag = format!("</{}", name);
 
                         while !input.peek(&closing_tag) {
+                            if input.peek("</") || input.cursor == input.content.len() {
+                                return Err(input.error(&format!("expected `</{}>`", name)));
+                            }
+
                             let child = input.parse()?;
                             children.push(child);
                         }
 
+                        check_else(&children, input)?;
+
+                        if trim {
+                            trim_children(&mut children);
+                        }
+
                         input.step(&closing_tag)?;
                         input.skip_spaces()?;
                         input.step(">")?;
@@ -279,6 +315,7 @@ impl Parse for Element {
 
                 children
             },
+            span: input.span_from(start),
         })
     }
 }
@@ -291,7 +328,7 @@ impl Tokenize for Element {
 
         // Check for loop to see what variables are defined for this loop (`scopes`)
         if let Some(attr_for) = self.control_attr("for") {
-            if let Code::For(for_) = &attr_for.value {
+            if let Code::For(for_, _) = &attr_for.value {
                 new_scopes.append(for_.declared());
             }
         }
@@ -305,8 +342,20 @@ impl Tokenize for Element {
         } else if self.name == "slot" {
             let name = LitStr::new(&self.slot_name(), Span::call_site());
 
-            quote! {
-                self._slots.render(f, #name)?;
+            if self.children.is_empty() {
+                quote! {
+                    self._slots.render(f, #name)?;
+                }
+            } else {
+                // The content of the slot element is rendered when the slot is not given
+                let children = self.children_tokens(idents, &new_scopes);
+
+                quote! {
+                    self._slots.render_or(f, #name, |f: &mut dyn std::fmt::Write| {
+                        #(#children)*
+                        Ok(())
+                    })?;
+                }
             }
         } else if tag_pieces.len() == 1 && is_reserved_tag(&self.name) {
             let start_tag = LitStr::new(&format!("<{}", &self.name), Span::call_site());
@@ -352,6 +401,18 @@ impl Tokenize for Element {
             let mut for_expr = TokenStream::new();
             r_for.value.tokenize(&mut for_expr, idents, scopes);
 
+            // If condition on a loop is checked for each item, so it can use the loop variables
+            if let Some(r_if) = self.control_attr("if") {
+                let mut if_expr = TokenStream::new();
+                r_if.value.tokenize(&mut if_expr, idents, &new_scopes);
+
+                elem = quote! {
+                    if #if_expr {
+                        #elem
+                    }
+                };
+            }
+
             quote! {
                 for #for_expr {
                     #elem
@@ -392,6 +453,51 @@ impl Tokenize for Element {
     }
 }
 
+// Every `!else` and `!else-if` element needs to follow an `!if` or `!else-if` sibling
+fn check_else(children: &[Node], input: &ParseStream) -> Result<(), Error> {
+    let mut after_if = false;
+
+    for child in children {
+        if let Node::Element(e) = child {
+            if e.template_name().is_some() {
+                continue;
+            }
+
+            let is_else = e.control_attr("else").is_some();
+            let is_else_if = e.control_attr("else-if").is_some();
+
+            if (is_else || is_else_if) && !after_if {
+                return Err(input.error_at(
+                    e.span.start,
+                    "expected `!if` element before `!else` or `!else-if`",
+                ));
+            }
+
+            // A loop with a condition can't be followed by `!else`
+            after_if = is_else_if
+                || (!is_else && e.control_attr("if").is_some() && e.control_attr("for").is_none());
+        }
+    }
+
+    Ok(())
+}
+
+// Drop the whitespace only text between tags, except where whitespace is significant
+fn trim_children(children: &mut Vec<Node>) {
+    children.retain(|child| match child {
+        Node::Text(t) => !t.is_whitespace(),
+        _ => true,
+    });
+
+    for child in children {
+        if let Node::Element(e) = child {
+            if e.name != "pre" && e.name != "textarea" {
+                trim_children(&mut e.children);
+            }
+        }
+    }
+}
+
 fn clean_if_else_group(group: Vec<&Node>, has_else: bool, has_else_if: bool) -> Vec<&Node> {
     if has_else {
         // Clean completely
@@ -445,6 +551,7 @@ fn clean_if_else_group(group: Vec<&Node>, has_else: bool, has_else_if: bool) ->
 fn convert_tag_name(tag: Vec<&str>) -> Vec<Ident> {
     let mut idents: Vec<Ident> = tag
         .into_iter()
+        .filter(|t| !t.is_empty())
         .map(|t| Ident::new(&to_snake_case(t), Span::call_site()))
         .collect();
 
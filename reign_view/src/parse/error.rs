@@ -1,6 +1,8 @@
+use super::Position;
 use std::fmt;
 
-// TODO:(view:err) Filename for error
+/// Error that occurred while parsing the template, along with the source and the byte
+/// offset at which it occurred.
 pub struct Error {
     pub content: String,
     pub cursor: usize,
@@ -8,6 +10,13 @@ pub struct Error {
 }
 
 impl Error {
+    /// Line and column in the template source at which the error occurred.
+    pub fn position(&self) -> Position {
+        let (column, line, _) = self.get_line();
+
+        (line, column + 1)
+    }
+
     fn get_line(&self) -> (usize, usize, String) {
         if self.cursor > self.content.len() {
             panic!(
@@ -76,6 +85,15 @@ impl fmt::Debug for Error {
     }
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (line, column) = self.position();
+
+        write!(f, "line {}, column {}: {}", line, column, self.message)?;
+        self.print(f)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::ParseStream;
@@ -198,6 +216,18 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_display() {
+        let mut ps = ParseStream::new("Hello\nWorld".to_string());
+        ps.step("Hello").unwrap();
+        ps.skip_spaces().unwrap();
+        ps.step("Wor").unwrap();
+        let err = ps.step("or").unwrap_err();
+
+        assert_eq!(err.position(), (2, 4));
+        assert!(format!("{}", err).starts_with("line 2, column 4: expected `or`\n"));
+    }
+
     #[test]
     fn test_fmt_eof() {
         let mut ps = ParseStream::new("Hello".to_string());
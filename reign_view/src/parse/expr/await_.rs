@@ -0,0 +1,35 @@
+use super::{Expr, Tokenize, ViewFields};
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream, Result},
+    token::{Await, Dot},
+    Error,
+};
+
+pub struct ExprAwait {
+    pub base: Box<Expr>,
+    pub dot_token: Dot,
+    pub await_token: Await,
+}
+
+impl Parse for ExprAwait {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut expr: Expr = input.parse()?;
+        loop {
+            match expr {
+                Expr::Await(inner) => return Ok(inner),
+                Expr::Group(next) => expr = *next.expr,
+                _ => return Err(Error::new(Span::call_site(), "expected await expression")),
+            }
+        }
+    }
+}
+
+impl Tokenize for ExprAwait {
+    fn tokenize(&self, tokens: &mut TokenStream, idents: &mut ViewFields, scopes: &ViewFields) {
+        self.base.tokenize(tokens, idents, scopes);
+        self.dot_token.to_tokens(tokens);
+        self.await_token.to_tokens(tokens);
+    }
+}
@@ -13,6 +13,7 @@ use syn::{
 pub const KEYWORDS: [&str; 4] = ["Some", "None", "Ok", "Err"];
 
 mod array;
+mod await_;
 mod binary;
 mod call;
 mod cast;
@@ -31,6 +32,7 @@ mod type_;
 mod unary;
 
 use array::ExprArray;
+use await_::ExprAwait;
 use binary::ExprBinary;
 use call::ExprCall;
 use cast::ExprCast;
@@ -51,6 +53,7 @@ use unary::ExprUnary;
 // TODO:(view:expr) ExprIf
 pub enum Expr {
     Array(ExprArray),
+    Await(ExprAwait),
     Binary(ExprBinary),
     Call(ExprCall),
     Cast(ExprCast),
@@ -149,6 +152,7 @@ impl Tokenize for Expr {
     fn tokenize(&self, tokens: &mut TokenStream, idents: &mut ViewFields, scopes: &ViewFields) {
         match self {
             Expr::Array(e) => e.tokenize(tokens, idents, scopes),
+            Expr::Await(e) => e.tokenize(tokens, idents, scopes),
             Expr::Binary(e) => e.tokenize(tokens, idents, scopes),
             Expr::Call(e) => e.tokenize(tokens, idents, scopes),
             Expr::Cast(e) => e.tokenize(tokens, idents, scopes),
@@ -337,6 +341,12 @@ fn trailer_helper(input: ParseStream, mut e: Expr) -> Result<Expr> {
                 paren_token: parenthesized!(content in input),
                 args: content.parse_terminated(Expr::parse)?,
             });
+        } else if input.peek(Token![.]) && input.peek2(Token![await]) {
+            e = Expr::Await(ExprAwait {
+                base: Box::new(e),
+                dot_token: input.parse()?,
+                await_token: input.parse()?,
+            });
         } else if input.peek(Token![.]) && !input.peek(Token![..]) {
             let dot_token: Token![.] = input.parse()?;
             let member: Member = input.parse()?;
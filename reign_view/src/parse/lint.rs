@@ -0,0 +1,190 @@
+use super::{Node, Position, Tokenize, ViewFields};
+use proc_macro2::TokenStream;
+use std::fmt;
+use syn::Ident;
+
+/// Problem with the fields used in a template that was found by [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub ident: String,
+    pub message: String,
+    pub position: Option<Position>,
+    pub level: Level,
+}
+
+/// How serious a [`Lint`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    /// The template can't be compiled.
+    Error,
+    /// The template is valid but is likely a mistake.
+    Warning,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((line, column)) = self.position {
+            write!(f, "line {}, column {}: {}", line, column, self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+/// Check the fields referenced by a template for likely mistakes.
+///
+/// Since the fields of a view are generated from the identifiers used in its template, a
+/// typo becomes a new field instead of failing. This reports identifiers that are used only
+/// once while looking like another identifier as warnings, and identifiers that are given
+/// conflicting type ascription hints as errors.
+///
+/// # Examples
+///
+/// ```
+/// use reign_view::parse::{lint, parse};
+///
+/// let node = parse("<p>{{ name }} and {{ name }} but {{ nmae }}</p>".to_string()).unwrap();
+/// let lints = lint(&node);
+///
+/// assert_eq!(lints[0].ident, "nmae");
+/// ```
+pub fn lint(node: &Node) -> Vec<Lint> {
+    let mut tokens = TokenStream::new();
+    let mut idents = ViewFields::new();
+
+    node.tokenize(&mut tokens, &mut idents, &ViewFields::new());
+
+    let mut names = idents.uses.keys().collect::<Vec<_>>();
+    names.sort_by_key(|x| x.to_string());
+
+    let mut lints = vec![];
+
+    for ident in &names {
+        let uses = &idents.uses[*ident];
+        let mut hints = uses
+            .iter()
+            .filter_map(|(position, ty)| ty.as_ref().map(|ty| (position, ty)));
+
+        if let Some((_, first)) = hints.next() {
+            for (position, ty) in hints {
+                if ty != first {
+                    lints.push(Lint {
+                        ident: ident.to_string(),
+                        message: format!(
+                            "identifier `{}` has conflicting type ascription hints `{}` and `{}`",
+                            ident, first, ty
+                        ),
+                        position: *position,
+                        level: Level::Error,
+                    });
+                }
+            }
+        }
+    }
+
+    for ident in &names {
+        let uses = &idents.uses[*ident];
+
+        if uses.len() != 1 {
+            continue;
+        }
+
+        let similar = names.iter().find(|other| {
+            other != &ident && idents.uses[**other].len() > 1 && is_similar(ident, other)
+        });
+
+        if let Some(other) = similar {
+            lints.push(Lint {
+                ident: ident.to_string(),
+                message: format!(
+                    "identifier `{}` is used only once and looks like a typo of `{}`",
+                    ident, other
+                ),
+                position: uses[0].0,
+                level: Level::Warning,
+            });
+        }
+    }
+
+    lints
+}
+
+// Identifiers that only differ in case or underscores, or by a single edit
+fn is_similar(one: &Ident, two: &Ident) -> bool {
+    let one = one.to_string();
+    let two = two.to_string();
+
+    if one.len() < 3 || two.len() < 3 {
+        return false;
+    }
+
+    let normalize = |x: &str| x.replace('_', "").to_lowercase();
+
+    normalize(&one) == normalize(&two) || distance(&one, &two) == 1
+}
+
+// Optimal string alignment distance, which counts swapping adjacent characters as one edit
+fn distance(one: &str, two: &str) -> usize {
+    let one = one.chars().collect::<Vec<_>>();
+    let two = two.chars().collect::<Vec<_>>();
+    let mut d = vec![vec![0; two.len() + 1]; one.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=one.len() {
+        for j in 1..=two.len() {
+            let cost = usize::from(one[i - 1] != two[j - 1]);
+
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && one[i - 1] == two[j - 2] && one[i - 2] == two[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[one.len()][two.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::parse;
+    use super::{lint, Level};
+
+    #[test]
+    fn test_lint_typo() {
+        let node = parse("<p>{{ name }}\n{{ name }}\n{{ nmae }}</p>".to_string()).unwrap();
+        let lints = lint(&node);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].ident, "nmae");
+        assert_eq!(lints[0].position, Some((3, 1)));
+        assert_eq!(lints[0].level, Level::Warning);
+    }
+
+    #[test]
+    fn test_lint_conflicting_types() {
+        let node = parse("<p>{{ age: u8 }} {{ age: String }}</p>".to_string()).unwrap();
+        let lints = lint(&node);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].ident, "age");
+        assert_eq!(lints[0].level, Level::Error);
+    }
+
+    #[test]
+    fn test_lint_clean() {
+        let node =
+            parse("<p>{{ age: u8 }} {{ age: u8 }} {{ name }} {{ names }}</p>".to_string()).unwrap();
+
+        assert!(lint(&node).is_empty());
+    }
+}
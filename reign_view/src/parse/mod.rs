@@ -1,3 +1,23 @@
+//! Parser for the template syntax.
+//!
+//! This can be used by tooling like formatters and linters to work with the templates
+//! without going through the `views!` macro. The syntax tree follows the semver guarantees
+//! of this crate.
+//!
+//! # Examples
+//!
+//! ```
+//! use reign_view::parse::{parse, Node};
+//!
+//! let source = "<p class=\"greeting\">Hello {{ name }}</p>";
+//! let node = parse(source.to_string()).unwrap();
+//!
+//! if let Node::Element(element) = &node {
+//!     assert_eq!(element.name, "p");
+//!     assert_eq!(element.attrs[0].span().source(source), "class=\"greeting\"");
+//!     assert_eq!(element.children[0].span().position(source), (1, 21));
+//! }
+//! ```
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{
@@ -6,6 +26,7 @@ use syn::{
 };
 
 mod attribute;
+mod coalesce;
 mod code;
 mod comment;
 mod consts;
@@ -13,25 +34,33 @@ mod doctype;
 mod element;
 mod error;
 mod expr;
+mod lint;
 mod node;
 mod parse_stream;
 mod pat;
+mod span;
 mod string_part;
 mod text;
 mod view_fields;
 
-use attribute::Attribute;
-use code::Code;
-use comment::Comment;
-use doctype::Doctype;
-use element::Element;
-use error::Error;
+pub use attribute::{
+    Attribute, AttributeValue, ControlAttribute, DynamicAttribute, NormalAttribute,
+    VariableAttribute,
+};
+pub use code::{Code, Position};
+pub use comment::Comment;
+pub use doctype::Doctype;
+pub use element::Element;
+pub use error::Error;
+pub use lint::{lint, Level, Lint};
+pub use node::Node;
+pub use span::SourceSpan;
+pub use string_part::StringPart;
+pub use text::Text;
+
 use expr::Expr;
-use node::Node;
 use parse_stream::ParseStream;
 use pat::For;
-use string_part::StringPart;
-use text::Text;
 use view_fields::ViewFields;
 
 fn tag_name_regex() -> String {
@@ -46,6 +75,9 @@ trait Tokenize {
     fn tokenize(&self, tokens: &mut TokenStream, idents: &mut ViewFields, scopes: &ViewFields);
 }
 
+/// Parse the given template source into its syntax tree.
+///
+/// A template needs to have exactly one top-level node.
 pub fn parse(data: String) -> Result<Node, Error> {
     let mut ps = ParseStream::new(data);
     let node: Node = ps.parse()?;
@@ -96,14 +128,36 @@ where
     }
 }
 
-pub fn tokenize(node: Node) -> (TokenStream, Vec<(Ident, bool)>, Vec<TokenStream>) {
+/// Convert the syntax tree of a template into the code that renders it.
+///
+/// Returns the rendering code, the names of the fields used in the template and whether
+/// they are borrowed, their types, their positions in the template and the estimated
+/// capacity of the rendered output.
+#[allow(clippy::type_complexity)]
+pub fn tokenize(
+    node: Node,
+) -> (
+    TokenStream,
+    Vec<(Ident, bool)>,
+    Vec<TokenStream>,
+    Vec<Option<(usize, usize)>>,
+    usize,
+) {
     let mut tokens = TokenStream::new();
     let mut idents = ViewFields::new();
     let scopes = ViewFields::new();
 
     node.tokenize(&mut tokens, &mut idents, &scopes);
 
-    (tokens, idents.keys(), idents.values())
+    let (tokens, capacity) = coalesce::coalesce(tokens);
+
+    (
+        tokens,
+        idents.keys(),
+        idents.values(),
+        idents.positions(),
+        capacity,
+    )
 }
 
 fn is_member_named(member: &Member) -> bool {
@@ -1,7 +1,7 @@
 use super::consts::DOCTYPE;
 use super::{
-    tag_name_regex, Comment, Doctype, Element, Error, Parse, ParseStream, Text, Tokenize,
-    ViewFields,
+    tag_name_regex, Comment, Doctype, Element, Error, Parse, ParseStream, SourceSpan, Text,
+    Tokenize, ViewFields,
 };
 use proc_macro2::TokenStream;
 
@@ -13,6 +13,18 @@ pub enum Node {
     Doctype(Doctype),
 }
 
+impl Node {
+    /// Location of this node in the template source.
+    pub fn span(&self) -> SourceSpan {
+        match self {
+            Node::Element(e) => e.span,
+            Node::Comment(c) => c.span,
+            Node::Text(t) => t.span,
+            Node::Doctype(d) => d.span,
+        }
+    }
+}
+
 impl Parse for Node {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
         if input.cursor == 0 {
@@ -1,4 +1,4 @@
-use super::{Error, Parse, StringPart};
+use super::{Error, Parse, SourceSpan, StringPart};
 use regex::Regex;
 
 #[derive(Debug)]
@@ -25,6 +25,32 @@ impl ParseStream {
         }
     }
 
+    pub(super) fn error_at(&self, cursor: usize, msg: &str) -> Error {
+        Error {
+            content: self.content.clone(),
+            cursor,
+            message: msg.to_string(),
+        }
+    }
+
+    pub(super) fn span_from(&self, start: usize) -> SourceSpan {
+        SourceSpan {
+            start,
+            end: self.cursor,
+        }
+    }
+
+    pub(super) fn position(&self) -> (usize, usize) {
+        self.position_of(self.cursor)
+    }
+
+    pub(super) fn position_of(&self, cursor: usize) -> (usize, usize) {
+        let before = self.content.get(..cursor).unwrap();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        (before.matches('\n').count() + 1, cursor - line_start + 1)
+    }
+
     pub(super) fn parse<T>(&mut self) -> Result<T, Error>
     where
         T: Parse,
@@ -206,14 +232,14 @@ mod test {
         assert_eq!(ps.cursor, 14);
         assert_eq!(parts.len(), 2);
         assert!(
-            if let Some(StringPart::Expr(Code::Expr(Expr::Path(p)))) = parts.get(0) {
+            if let Some(StringPart::Expr(Code::Expr(Expr::Path(p), _))) = parts.get(0) {
                 p.path.is_ident(&Ident::new("text", Span::call_site()))
             } else {
                 false
             }
         );
         assert!(
-            if let Some(StringPart::Expr(Code::Expr(Expr::Path(p)))) = parts.get(1) {
+            if let Some(StringPart::Expr(Code::Expr(Expr::Path(p), _))) = parts.get(1) {
                 p.path.is_ident(&Ident::new("u", Span::call_site()))
             } else {
                 false
@@ -221,6 +247,39 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_parse_text_expr_position() {
+        let mut ps = ParseStream::new("Hello\nWorld {{ text }}".to_string());
+
+        let parts = ps.parse_text().unwrap();
+
+        assert!(
+            if let Some(StringPart::Expr(Code::Expr(_, position))) = parts.get(1) {
+                *position == (2, 7)
+            } else {
+                false
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_text_trim() {
+        let mut ps = ParseStream::new("a \n {{- b -}} \n{{ -c }}".to_string());
+
+        let parts = ps.parse_text().unwrap();
+
+        assert_eq!(parts.len(), 3);
+        assert!(if let Some(StringPart::Normal(s)) = parts.get(0) {
+            s == "a"
+        } else {
+            false
+        });
+        assert!(matches!(
+            parts.get(2),
+            Some(StringPart::Expr(Code::Expr(Expr::Unary(_), _)))
+        ));
+    }
+
     #[test]
     fn test_parse_text_empty_string() {
         let mut ps = ParseStream::new("".to_string());
@@ -0,0 +1,37 @@
+/// Byte range in the template source that a node or attribute was parsed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SourceSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl SourceSpan {
+    /// Line and column (both starting from 1) of the start of this span in the given source.
+    pub fn position(&self, content: &str) -> (usize, usize) {
+        let before = content.get(..self.start).unwrap_or(content);
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        (
+            before.matches('\n').count() + 1,
+            self.start - line_start + 1,
+        )
+    }
+
+    /// Source text covered by this span.
+    pub fn source<'a>(&self, content: &'a str) -> &'a str {
+        content.get(self.start..self.end).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SourceSpan;
+
+    #[test]
+    fn test_position() {
+        let span = SourceSpan { start: 9, end: 12 };
+
+        assert_eq!(span.position("<p>\n  <b>hi</b>\n</p>"), (2, 6));
+        assert_eq!(span.source("<p>\n  <b>hi</b>\n</p>"), "hi<");
+    }
+}
@@ -1,4 +1,6 @@
 use super::{Code, Error, ParseStream, Tokenize, ViewFields};
+use crate::INTERNAL_ERR;
+use once_cell::sync::Lazy;
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt};
 use regex::Regex;
@@ -8,6 +10,7 @@ use syn::LitStr;
 pub enum StringPart {
     Normal(String),
     Expr(Code),
+    Raw(Code),
 }
 
 impl StringPart {
@@ -15,6 +18,7 @@ impl StringPart {
         let mut parts = vec![];
         let start_regex = Regex::new(r"\\\{\{|\{\{|<").unwrap();
         let mut cursor = if !in_attr { input.cursor } else { 0 };
+        let mut trim_next = false;
 
         loop {
             let remaining = data.get(cursor..).unwrap();
@@ -26,7 +30,7 @@ impl StringPart {
             let matches = start_regex.find(remaining);
 
             if matches.is_none() {
-                parts.push(StringPart::Normal(remaining.to_string()));
+                push_normal(&mut parts, remaining, trim_next);
                 cursor += remaining.len();
                 break;
             }
@@ -35,10 +39,12 @@ impl StringPart {
             let sub_string = data.get(cursor..until).unwrap();
 
             if !sub_string.is_empty() {
-                parts.push(StringPart::Normal(sub_string.to_string()));
+                push_normal(&mut parts, sub_string, trim_next);
                 cursor = until;
             }
 
+            trim_next = false;
+
             match data.get(cursor..=cursor).unwrap() {
                 "\\" => {
                     parts.push(StringPart::Normal("\\{{".to_string()));
@@ -53,9 +59,13 @@ impl StringPart {
                     }
                 }
                 "{" => {
-                    cursor += 2;
+                    // Triple braces output the expression without escaping it
+                    let raw = data.get(cursor..).unwrap().starts_with("{{{");
+                    let (open, close) = if raw { (3, "}}}") } else { (2, "}}") };
+
+                    cursor += open;
                     let end_remaining = data.get(cursor..).unwrap();
-                    let end_matches = end_remaining.find("}}");
+                    let end_matches = end_remaining.find(close);
 
                     if end_matches.is_none() {
                         if !in_attr {
@@ -66,13 +76,34 @@ impl StringPart {
                     }
 
                     let expr_until = cursor + end_matches.unwrap();
-                    let expr_string = data.get(cursor..expr_until).unwrap();
+                    let mut expr_string = data.get(cursor..expr_until).unwrap();
+
+                    // `{{-` and `-}}` trim the whitespace around the expression
+                    if TRIM_BEFORE.is_match(expr_string) {
+                        expr_string = &expr_string[1..];
+                        trim_end(&mut parts);
+                    }
+
+                    if TRIM_AFTER.is_match(expr_string) {
+                        expr_string = &expr_string[..expr_string.len() - 1];
+                        trim_next = true;
+                    }
+
+                    // Point the expression position at the opening braces
+                    let position = if !in_attr {
+                        input.position_of(cursor - open)
+                    } else {
+                        input.position()
+                    };
 
-                    parts.push(StringPart::Expr(Code::parse_expr_from_str(
-                        input,
-                        expr_string,
-                    )?));
-                    cursor = expr_until + 2;
+                    let code = Code::parse_expr_at(input, expr_string, position)?;
+
+                    parts.push(if raw {
+                        StringPart::Raw(code)
+                    } else {
+                        StringPart::Expr(code)
+                    });
+                    cursor = expr_until + close.len();
                 }
                 _ => unreachable!(),
             }
@@ -86,6 +117,27 @@ impl StringPart {
     }
 }
 
+static TRIM_BEFORE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^-\s").expect(INTERNAL_ERR));
+static TRIM_AFTER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s-$").expect(INTERNAL_ERR));
+
+fn push_normal(parts: &mut Vec<StringPart>, s: &str, trim: bool) {
+    let s = if trim { s.trim_start() } else { s };
+
+    if !s.is_empty() {
+        parts.push(StringPart::Normal(s.to_string()));
+    }
+}
+
+fn trim_end(parts: &mut Vec<StringPart>) {
+    if let Some(StringPart::Normal(n)) = parts.last_mut() {
+        n.truncate(n.trim_end().len());
+
+        if n.is_empty() {
+            parts.pop();
+        }
+    }
+}
+
 impl Tokenize for StringPart {
     fn tokenize(&self, tokens: &mut TokenStream, idents: &mut ViewFields, scopes: &ViewFields) {
         match self {
@@ -93,8 +145,7 @@ impl Tokenize for StringPart {
                 let lit = LitStr::new(&n, Span::call_site());
                 lit.to_tokens(tokens);
             }
-            // TODO:(view:html-escape) expression
-            StringPart::Expr(e) => e.tokenize(tokens, idents, scopes),
+            StringPart::Expr(e) | StringPart::Raw(e) => e.tokenize(tokens, idents, scopes),
         }
     }
 }
@@ -1,27 +1,77 @@
-use super::{Error, Parse, ParseStream, StringPart, Tokenize, ViewFields};
-use proc_macro2::TokenStream;
+use super::{Error, Parse, ParseStream, SourceSpan, StringPart, Tokenize, ViewFields};
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, TokenStreamExt};
+use syn::LitStr;
 
 #[derive(Debug)]
 pub struct Text {
     pub content: Vec<StringPart>,
+    pub span: SourceSpan,
+}
+
+impl Text {
+    /// Whether the text only consists of whitespace.
+    pub fn is_whitespace(&self) -> bool {
+        self.content.iter().all(|part| match part {
+            StringPart::Normal(n) => n.trim().is_empty(),
+            _ => false,
+        })
+    }
 }
 
 impl Parse for Text {
     fn parse(input: &mut ParseStream) -> Result<Self, Error> {
+        let start = input.cursor;
+
         Ok(Text {
             content: input.parse_text()?,
+            span: input.span_from(start),
         })
     }
 }
 
 impl Tokenize for Text {
     fn tokenize(&self, tokens: &mut TokenStream, idents: &mut ViewFields, scopes: &ViewFields) {
-        let mut ts = TokenStream::new();
-        self.content.tokenize(&mut ts, idents, scopes);
+        let mut text = String::new();
+        let mut format = String::new();
+        let mut args = vec![];
 
-        tokens.append_all(quote! {
-            write!(f, #ts)?;
-        })
+        // Static parts are written into the format string instead of being separate args
+        for part in &self.content {
+            match part {
+                StringPart::Normal(n) => {
+                    text.push_str(n);
+                    format.push_str(&n.replace('{', "{{").replace('}', "}}"));
+                }
+                StringPart::Expr(e) => {
+                    let mut ts = TokenStream::new();
+
+                    e.tokenize(&mut ts, idents, scopes);
+                    format.push_str("{}");
+                    args.push(quote! { ::reign::view::Escape(&(#ts)) });
+                }
+                StringPart::Raw(e) => {
+                    let mut ts = TokenStream::new();
+
+                    e.tokenize(&mut ts, idents, scopes);
+                    format.push_str("{}");
+                    args.push(ts);
+                }
+            }
+        }
+
+        if args.is_empty() {
+            let text_lit = LitStr::new(&text, Span::call_site());
+
+            tokens.append_all(quote! {
+                write!(f, "{}", #text_lit)?;
+            })
+        } else {
+            let format_lit = LitStr::new(&format, Span::call_site());
+
+            tokens.append_all(quote! {
+                write!(f, #format_lit, #(#args),*)?;
+            })
+        }
     }
 }
@@ -1,17 +1,27 @@
+use super::code::Position;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::collections::HashMap;
 use syn::Ident;
 
+// Position and type ascription hint of a field where it is used
+pub type FieldUse = (Option<Position>, Option<String>);
+
 #[derive(Clone, Default)]
 pub struct ViewFields {
     pub fields: HashMap<Ident, Option<TokenStream>>,
+    pub positions: HashMap<Ident, Position>,
+    pub position: Option<Position>,
+    pub uses: HashMap<Ident, Vec<FieldUse>>,
 }
 
 impl ViewFields {
     pub fn new() -> Self {
         ViewFields {
             fields: HashMap::new(),
+            positions: HashMap::new(),
+            position: None,
+            uses: HashMap::new(),
         }
     }
 
@@ -20,12 +30,18 @@ impl ViewFields {
     }
 
     pub fn insert(&mut self, ident: Ident, tokens: Option<TokenStream>) {
+        if let Some(position) = self.position {
+            self.positions.entry(ident.clone()).or_insert(position);
+        }
+
+        // Conflicting type ascription hints are reported by `lint`, the first one is kept here
+        self.uses
+            .entry(ident.clone())
+            .or_default()
+            .push((self.position, tokens.as_ref().map(|x| x.to_string())));
+
         if let Some(ots) = self.fields.get(&ident) {
-            if ots.is_some() && tokens.is_some() {
-                // TODO: Unable to compare the syn::Type or TokenStream here
-                // TODO:(view:err) Show the error position
-                panic!("identifier `{}` has multiple type ascription hints", ident);
-            } else if ots.is_none() {
+            if ots.is_none() {
                 self.fields.insert(ident, tokens);
             }
         } else {
@@ -50,6 +66,13 @@ impl ViewFields {
             .collect()
     }
 
+    pub fn positions(&self) -> Vec<Option<Position>> {
+        self.fields
+            .keys()
+            .map(|k| self.positions.get(k).cloned())
+            .collect()
+    }
+
     pub fn values(&self) -> Vec<TokenStream> {
         self.fields
             .values()
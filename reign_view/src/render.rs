@@ -0,0 +1,19 @@
+use std::fmt::Result;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Asynchronous counterpart of [`Display`](std::fmt::Display) for views.
+///
+/// Views whose template awaits a future in an expression, such as
+/// `{{ (article: Article).author().await }}`, implement this trait instead of `Display`.
+/// Such views can not be used as components or slot content of other views.
+///
+/// The returned future is `Send` so that it can be awaited in a router handler, which
+/// needs the types of the fields of the view to be `Sync`.
+pub trait AsyncRender {
+    /// Render the view into the given buffer.
+    fn render<'b>(
+        &'b self,
+        buf: &'b mut String,
+    ) -> Pin<Box<dyn Future<Output = Result> + Send + 'b>>;
+}
@@ -2,11 +2,11 @@ use std::collections::HashMap;
 use std::fmt::{Result, Write};
 use std::marker::PhantomData;
 
-type SlotRender<'a> = Box<dyn Fn(&mut dyn Write) -> Result + 'a>;
+type SlotRender<'a> = Box<dyn Fn(&mut dyn Write) -> Result + Send + Sync + 'a>;
 
 pub fn slot_render<'a, F>(f: F) -> SlotRender<'a>
 where
-    F: Fn(&mut dyn Write) -> Result + 'a,
+    F: Fn(&mut dyn Write) -> Result + Send + Sync + 'a,
 {
     Box::new(f) as SlotRender
 }
@@ -27,6 +27,28 @@ impl<'a> Slots<'a> {
             Ok(())
         }
     }
+
+    pub fn render_or<F>(&self, f: &mut dyn Write, name: &str, fallback: F) -> Result
+    where
+        F: Fn(&mut dyn Write) -> Result,
+    {
+        if let Some(func) = self.templates.get(name) {
+            func(f)
+        } else if name == "default" {
+            let mut children = String::new();
+
+            // Children are always given for the default slot, even if they are empty
+            (self.children)(&mut children)?;
+
+            if children.trim().is_empty() {
+                fallback(f)
+            } else {
+                f.write_str(&children)
+            }
+        } else {
+            fallback(f)
+        }
+    }
 }
 
 impl<'a> Default for Slots<'a> {
@@ -38,3 +60,45 @@ impl<'a> Default for Slots<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(slots: &Slots, name: &str) -> String {
+        let mut s = String::new();
+
+        slots
+            .render_or(&mut s, name, |f| f.write_str("fallback"))
+            .unwrap();
+        s
+    }
+
+    #[test]
+    fn test_render_or_named() {
+        let mut slots = Slots::default();
+
+        assert_eq!(render(&slots, "footer"), "fallback");
+
+        slots
+            .templates
+            .insert("footer", slot_render(|f| f.write_str("given")));
+
+        assert_eq!(render(&slots, "footer"), "given");
+    }
+
+    #[test]
+    fn test_render_or_default() {
+        let mut slots = Slots::default();
+
+        assert_eq!(render(&slots, "default"), "fallback");
+
+        slots.children = slot_render(|f| f.write_str("\n  "));
+
+        assert_eq!(render(&slots, "default"), "fallback");
+
+        slots.children = slot_render(|f| f.write_str("given"));
+
+        assert_eq!(render(&slots, "default"), "given");
+    }
+}
@@ -1,9 +1,8 @@
-write!(f, "{}", "<div")? ;
+f.write_str("<div")? ;
 write!(f, " {}=\"{}\"", "@s", "1")? ;
 write!(f, " {}=\"{}\"", "src", "example.png")? ;
 write!(f, " {}=\"{}\"", "disabled", "")? ;
 write!(f, " {}=\"{}\"", "width", "200")? ;
 write!(f, " {}=\"{}\"", "height", "10\"0")? ;
 write!(f, " {}=\"{}\"", "<s", "1")? ;
-write!(f, ">")? ;
-write!(f, "{}", "</div>")? ;
+f.write_str("></div>")? ;
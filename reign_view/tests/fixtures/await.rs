@@ -0,0 +1,3 @@
+f.write_str("<p>")? ;
+write!(f, "{}", ::reign::view::Escape(&((self.user).name().await)))? ;
+f.write_str("</p>")? ;
@@ -1,20 +1 @@
-write!(f, "{}", "<div")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<hr")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<h1")? ;
-write!(f, ">")? ;
-write!(f, "{}", "Hello")? ;
-write!(f, "{}", "</h1>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<br")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<p")? ;
-write!(f, ">")? ;
-write!(f, "{}", "Lorem Ipsum")? ;
-write!(f, "{}", "</p>")? ;
-write!(f, "{}", "\n")? ;
-write!(f, "{}", "</div>")? ;
+f.write_str("<div>\n  <hr>\n  <h1>Hello</h1>\n  <br>\n  <p>Lorem Ipsum</p>\n</div>")? ;
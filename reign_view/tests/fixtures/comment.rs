@@ -1 +1 @@
-write!(f, "{}", "<!-- This is\n  a comment -->")? ;
+f.write_str("<!-- This is\n  a comment -->")? ;
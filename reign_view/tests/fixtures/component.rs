@@ -5,23 +5,16 @@ write!(
         _slots: ::reign::view::Slots {
             templates: ::reign::view::maplit::hashmap! {
                 "header" => ::reign::view::slot_render(|f: &mut dyn std::fmt::Write| {
-                    write!(f, "{}", "Hello")? ;
+                    f.write_str("Hello")? ;
                     Ok(())
                 }),
                 "footer" => ::reign::view::slot_render(|f: &mut dyn std::fmt::Write| {
-                    write!(f, "{}", "Bye")? ;
+                    f.write_str("Bye")? ;
                     Ok(())
                 })
             },
             children: ::reign::view::slot_render(|f: &mut dyn std::fmt::Write| {
-                write!(f, "{}", "\n  ")? ;
-                write!(f, "{}", "\n  ")? ;
-                write!(f, "{}", "<h1")? ;
-                write!(f, ">")? ;
-                write!(f, "{}", "Title")? ;
-                write!(f, "{}", "</h1>")? ;
-                write!(f, "{}", "\n  ")? ;
-                write!(f, "{}", "\n")? ;
+                f.write_str("\n  \n  <h1>Title</h1>\n  \n")? ;
                 Ok(())
             }),
             phantom: ::std::marker::PhantomData,
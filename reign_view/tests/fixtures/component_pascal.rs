@@ -0,0 +1,15 @@
+write!(
+    f,
+    "{}",
+    crate::views::shared::UserCard {
+        _slots: ::reign::view::Slots {
+            templates: ::reign::view::maplit::hashmap! {},
+            children: ::reign::view::slot_render(|f: &mut dyn std::fmt::Write| {
+                Ok(())
+            }),
+            phantom: ::std::marker::PhantomData,
+        },
+        user: self.user,
+        title: "Profile"
+    }
+)? ;
@@ -0,0 +1,19 @@
+write!(
+    f,
+    "{}",
+    crate::views::Card {
+        _slots: ::reign::view::Slots {
+            templates: ::reign::view::maplit::hashmap! {
+                "footer" => ::reign::view::slot_render(|f: &mut dyn std::fmt::Write| {
+                    f.write_str("Bye")? ;
+                    Ok(())
+                })
+            },
+            children: ::reign::view::slot_render(|f: &mut dyn std::fmt::Write| {
+                f.write_str("\n  \n  inner\n")? ;
+                Ok(())
+            }),
+            phantom: ::std::marker::PhantomData,
+        },
+    }
+)? ;
@@ -1,13 +1 @@
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<!DocType html>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<html")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n    ")? ;
-write!(f, "{}", "<body")? ;
-write!(f, ">")? ;
-write!(f, "{}", "Text")? ;
-write!(f, "{}", "</body>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "</html>")? ;
-write!(f, "{}", "\n")? ;
+f.write_str("\n  <!DocType html>\n  <html>\n    <body>Text</body>\n  </html>\n")? ;
@@ -1,4 +1,3 @@
-write!(f, "{}", "<div")? ;
+f.write_str("<div")? ;
 write!(f, " {}{}{}=\"{}\"", "dy", self.a["b"], "ic", format!("{}_b", a))? ;
-write!(f, ">")? ;
-write!(f, "{}", "</div>")? ;
+f.write_str("></div>")? ;
@@ -1,42 +1,25 @@
-write!(f, "{}", "<ul")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n  ")? ;
+f.write_str("<ul>\n  ")? ;
 for (i, j, _) in self.users {
-    write!(f, "{}", "<li")? ;
-    write!(f, ">")? ;
-    write!(f, "{}{}{}", i, j, self.k)? ;
-    write!(f, "{}", "</li>")? ;
+    f.write_str("<li>")? ;
+    write!(f, "{}{}{}", ::reign::view::Escape(&(i)), ::reign::view::Escape(&(j)), ::reign::view::Escape(&(self.k)))? ;
+    f.write_str("</li>")? ;
 }
-write!(f, "{}", "\n  ")? ;
+f.write_str("\n  ")? ;
 for User { i, b: j, ref k, d: &l, .. } in self.users {
-    write!(f, "{}", "<li")? ;
-    write!(f, ">")? ;
-    write!(f, "{}{}{}{}", i, j, k, l)? ;
-    write!(f, "{}", "</li>")? ;
+    f.write_str("<li>")? ;
+    write!(f, "{}{}{}{}", ::reign::view::Escape(&(i)), ::reign::view::Escape(&(j)), ::reign::view::Escape(&(k)), ::reign::view::Escape(&(l)))? ;
+    f.write_str("</li>")? ;
 }
-write!(f, "{}", "\n  ")? ;
+f.write_str("\n  ")? ;
 for i in self.users {
-    write!(f, "{}", "<li")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "\n    ")? ;
-    write!(f, "{}", "<h1")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", i)? ;
-    write!(f, "{}", "</h1>")? ;
-    write!(f, "{}", "\n    ")? ;
-    write!(f, "{}", "<ul")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "\n      ")? ;
+    f.write_str("<li>\n    <h1>")? ;
+    write!(f, "{}", ::reign::view::Escape(&(i)))? ;
+    f.write_str("</h1>\n    <ul>\n      ")? ;
     for j in i {
-        write!(f, "{}", "<li")? ;
-        write!(f, ">")? ;
-        write!(f, "{}{}", i, j)? ;
-        write!(f, "{}", "</li>")? ;
+        f.write_str("<li>")? ;
+        write!(f, "{}{}", ::reign::view::Escape(&(i)), ::reign::view::Escape(&(j)))? ;
+        f.write_str("</li>")? ;
     }
-    write!(f, "{}", "\n    ")? ;
-    write!(f, "{}", "</ul>")? ;
-    write!(f, "{}", "\n  ")? ;
-    write!(f, "{}", "</li>")? ;
+    f.write_str("\n    </ul>\n  </li>")? ;
 }
-write!(f, "{}", "\n")? ;
-write!(f, "{}", "</ul>")? ;
+f.write_str("\n</ul>")? ;
@@ -0,0 +1,13 @@
+f.write_str("<ul>\n  ")? ;
+for item in self.items {
+    if item.visible {
+        f.write_str("<li")? ;
+        write!(f, " {}=\"{}\"", "class", item.kind)? ;
+        f.write_str("><a")? ;
+        write!(f, " {}=\"{}\"", "href", item.url)? ;
+        f.write_str(">")? ;
+        write!(f, "{}", ::reign::view::Escape(&(item.name)))? ;
+        f.write_str("</a></li>")? ;
+    }
+}
+f.write_str("\n</ul>")? ;
@@ -1,47 +1,23 @@
-write!(f, "{}", "<div")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n  ")? ;
+f.write_str("<div>\n  ")? ;
 if true {
-    write!(f, "{}", "<div")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "First True")? ;
-    write!(f, "{}", "</div>")? ;
+    f.write_str("<div>First True</div>")? ;
 }
-write!(f, "{}", "\n  ")? ;
+f.write_str("\n  ")? ;
 if self.a == "true" || self.a {
-    write!(f, "{}", "<div")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "(Else) True")? ;
-    write!(f, "{}", "</div>")? ;
+    f.write_str("<div>(Else) True</div>")? ;
 } else if self.a == "false" || !self.a {
-    write!(f, "{}", "<div")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "(Else) False")? ;
-    write!(f, "{}", "</div>")? ;
+    f.write_str("<div>(Else) False</div>")? ;
 } else {
-    write!(f, "{}", "<div")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "Unknown")? ;
-    write!(f, "{}", "</div>")? ;
+    f.write_str("<div>Unknown</div>")? ;
 }
-write!(f, "{}", "\n  ")? ;
+f.write_str("\n  ")? ;
 if self.a {
-    write!(f, "{}", "<div")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "(ElseIf) True")? ;
-    write!(f, "{}", "</div>")? ;
+    f.write_str("<div>(ElseIf) True</div>")? ;
 } else if !self.a {
-    write!(f, "{}", "<div")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "(ElseIf) False")? ;
-    write!(f, "{}", "</div>")? ;
+    f.write_str("<div>(ElseIf) False</div>")? ;
 }
-write!(f, "{}", "\n  ")? ;
+f.write_str("\n  ")? ;
 if true {
-    write!(f, "{}", "<div")? ;
-    write!(f, ">")? ;
-    write!(f, "{}", "Last True")? ;
-    write!(f, "{}", "</div>")? ;
+    f.write_str("<div>Last True</div>")? ;
 }
-write!(f, "{}", "\n")? ;
-write!(f, "{}", "</div>")? ;
+f.write_str("\n</div>")? ;
@@ -0,0 +1,34 @@
+f.write_str("<div>\n  ")? ;
+write!(
+    f,
+    "{}",
+    crate::views::shared::Header {
+        _slots: ::reign::view::Slots {
+            templates: ::reign::view::maplit::hashmap! {},
+            children: ::reign::view::slot_render(|f: &mut dyn std::fmt::Write| {
+                Ok(())
+            }),
+            phantom: ::std::marker::PhantomData,
+        },
+        title: self.title,
+        subtitle: "Home"
+    }
+)? ;
+f.write_str("\n  <p>")? ;
+write!(f, "{}", ::reign::view::Escape(&(self.body)))? ;
+f.write_str("</p>\n  ")? ;
+write!(
+    f,
+    "{}",
+    crate::views::shared::Footer {
+        _slots: ::reign::view::Slots {
+            templates: ::reign::view::maplit::hashmap! {},
+            children: ::reign::view::slot_render(|f: &mut dyn std::fmt::Write| {
+                Ok(())
+            }),
+            phantom: ::std::marker::PhantomData,
+        },
+        year: "2020"
+    }
+)? ;
+f.write_str("\n</div>")? ;
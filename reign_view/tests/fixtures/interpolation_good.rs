@@ -1,94 +1,35 @@
-write!(f, "{}", "<div")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}{}{}{}{}{}{}", "Ident ", self.title, " and ", "user", b"user", b'u', 'u', 10)? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}{}", "Escaped ", "\\{{", " title }}")? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}{}", "With &gt; and &lt; inside ", "<", ">")? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Array ", [self.a, self.b])? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Binary ", self.a + self.b)? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Call ", self.a(self.b, self.c))? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Cast ", self.a as i32)? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}{}{}", "Field ", self.a.b, self.a.0, self.a.b.c)? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Index ", self.a[self.b])? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Method Call ", self.x.y:: <T>(self.a, self.b))? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Paren ", (self.a + self.b))? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Range ", self.a..self.b)? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Repeat ", [self.a; self.b])? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Struct ", A { a: self.a, b: self.b, ..self.c })? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Tuple ", (self.a, self.b))? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Type ", self.a)? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Unary ", !self.a)? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<span")? ;
-write!(f, ">")? ;
-write!(f, "{}{}", "Reference ", &self.a)? ;
-write!(f, "{}", "</span>")? ;
-write!(f, "{}", "\n")? ;
-write!(f, "{}", "</div>")? ;
+f.write_str("<div>\n  <span>")? ;
+write!(f, "Ident {} and {}{}{}{}{}", ::reign::view::Escape(&(self.title)), ::reign::view::Escape(&("user")), ::reign::view::Escape(&(b"user")), ::reign::view::Escape(&(b'u')), ::reign::view::Escape(&('u')), ::reign::view::Escape(&(10)))? ;
+f.write_str("</span>\n  <span>Escaped \\{{ title }}</span>\n  <span>")? ;
+write!(f, "With &gt; and &lt; inside {}{}", ::reign::view::Escape(&("<")), ::reign::view::Escape(&(">")))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Array {}", ::reign::view::Escape(&([self.a, self.b])))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Binary {}", ::reign::view::Escape(&(self.a + self.b)))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Call {}", ::reign::view::Escape(&(self.a(self.b, self.c))))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Cast {}", ::reign::view::Escape(&(self.a as i32)))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Field {}{}{}", ::reign::view::Escape(&(self.a.b)), ::reign::view::Escape(&(self.a.0)), ::reign::view::Escape(&(self.a.b.c)))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Index {}", ::reign::view::Escape(&(self.a[self.b])))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Method Call {}", ::reign::view::Escape(&(self.x.y:: <T>(self.a, self.b))))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Paren {}", ::reign::view::Escape(&((self.a + self.b))))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Range {}", ::reign::view::Escape(&(self.a..self.b)))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Repeat {}", ::reign::view::Escape(&([self.a; self.b])))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Struct {}", ::reign::view::Escape(&(A { a: self.a, b: self.b, ..self.c })))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Tuple {}", ::reign::view::Escape(&((self.a, self.b))))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Type {}", ::reign::view::Escape(&(self.a)))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Unary {}", ::reign::view::Escape(&(!self.a)))? ;
+f.write_str("</span>\n  <span>")? ;
+write!(f, "Reference {}", ::reign::view::Escape(&(&self.a)))? ;
+f.write_str("</span>\n</div>")? ;
@@ -1,6 +1,5 @@
-write!(f, "{}", "<div")? ;
-write!(f, " {}=\"{}\"", "x", format!("{}{}{}", "a", self.b, "c"))? ;
-write!(f, " {}=\"{}\"", "y", format!("{}{}{}", "a", "b", "c"))? ;
-write!(f, " {}=\"{}\"", "z", format!("{}{}{}", "a", self.b, "c"))? ;
-write!(f, ">")? ;
-write!(f, "{}", "</div>")? ;
+f.write_str("<div")? ;
+write!(f, " {}=\"{}{}{}\"", "x", "a", ::reign::view::EscapeAttr(&(self.b)), "c")? ;
+write!(f, " {}=\"{}{}{}\"", "y", "a", ::reign::view::EscapeAttr(&("b")), "c")? ;
+write!(f, " {}=\"{}{}{}\"", "z", "a", ::reign::view::EscapeAttr(&(self.b)), "c")? ;
+f.write_str("></div>")? ;
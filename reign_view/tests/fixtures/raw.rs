@@ -0,0 +1,7 @@
+f.write_str("<div>\n  <p>")? ;
+write!(f, "{}", ::reign::view::Escape(&(self.comment)))? ;
+f.write_str("</p>\n  <p>")? ;
+write!(f, "{}", self.comment)? ;
+f.write_str("</p>\n  <p>")? ;
+write!(f, "Mixed {} and {}", ::reign::view::Escape(&("<b>")), "<b>")? ;
+f.write_str("</p>\n</div>")? ;
@@ -0,0 +1 @@
+f.write_str("<div>\n  \n  <!-- Rendered -->\n</div>")? ;
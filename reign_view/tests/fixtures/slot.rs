@@ -1,13 +1,5 @@
-write!(f, "{}", "<div")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "<h1")? ;
-write!(f, ">")? ;
-write!(f, "{}", "\n    ")? ;
+f.write_str("<div>\n  <h1>\n    ")? ;
 self._slots.render(f, "title")? ;
-write!(f, "{}", "\n  ")? ;
-write!(f, "{}", "</h1>")? ;
-write!(f, "{}", "\n  ")? ;
+f.write_str("\n  </h1>\n  ")? ;
 self._slots.render(f, "default")? ;
-write!(f, "{}", "\n")? ;
-write!(f, "{}", "</div>")? ;
+f.write_str("\n</div>")? ;
@@ -0,0 +1,6 @@
+f.write_str("<footer>\n  ")? ;
+self._slots.render_or(f, "footer", |f: &mut dyn std::fmt::Write| {
+    f.write_str("Default footer")? ;
+    Ok(())
+})? ;
+f.write_str("\n</footer>")? ;
@@ -0,0 +1,7 @@
+f.write_str("<ul>\n  ")? ;
+for item in self.items {
+    f.write_str("<li>")? ;
+    write!(f, "\n    {}\n  ", ::reign::view::Escape(&(item)))? ;
+    f.write_str("</li>")? ;
+}
+f.write_str("\n</ul>")? ;
@@ -0,0 +1,7 @@
+f.write_str("<ul>")? ;
+for item in self.items {
+    f.write_str("<li>")? ;
+    write!(f, "{}", ::reign::view::Escape(&(item)))? ;
+    f.write_str("</li>")? ;
+}
+f.write_str("</ul>")? ;
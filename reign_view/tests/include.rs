@@ -0,0 +1,17 @@
+use reign::prelude::*;
+
+views!("tests", "views");
+
+#[test]
+fn test_include() {
+    let view = views::Include {
+        _slots: Default::default(),
+        title: "Reign",
+        body: "Hello",
+    };
+
+    assert_eq!(
+        view.to_string(),
+        "<div>\n  <header><h1>Reign</h1><h2>Home</h2></header>\n  <p>Hello</p>\n  <footer>2020</footer>\n</div>"
+    );
+}
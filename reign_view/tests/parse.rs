@@ -5,6 +5,11 @@ fn test_comment() {
     common::parse_pass("comment");
 }
 
+#[test]
+fn test_server_comment() {
+    common::parse_pass("server_comment");
+}
+
 #[test]
 fn test_basic() {
     common::parse_pass("basic");
@@ -40,11 +45,36 @@ fn test_interpolation_bad() {
     common::parse_fail("interpolation_bad");
 }
 
+#[test]
+fn test_raw() {
+    common::parse_pass("raw");
+}
+
+#[test]
+fn test_await() {
+    common::parse_pass("await");
+}
+
+#[test]
+fn test_whitespace() {
+    common::parse_pass("whitespace");
+}
+
+#[test]
+fn test_whitespace_trim() {
+    common::parse_pass("whitespace_trim");
+}
+
 #[test]
 fn test_for() {
     common::parse_pass("for");
 }
 
+#[test]
+fn test_for_if() {
+    common::parse_pass("for_if");
+}
+
 #[test]
 fn test_if() {
     common::parse_pass("if");
@@ -70,17 +100,42 @@ fn test_component() {
     common::parse_pass("component");
 }
 
+#[test]
+fn test_component_pascal() {
+    common::parse_pass("component_pascal");
+}
+
+#[test]
+fn test_component_slot() {
+    common::parse_pass("component_slot");
+}
+
 #[test]
 fn test_component_if() {
     common::parse_pass("component_if");
 }
 
+#[test]
+fn test_include() {
+    common::parse_pass("include");
+}
+
 #[test]
 fn test_slot() {
     common::parse_pass("slot");
 }
 
-// #[test]
-// fn test_else_without_if() {
-//     common::parse_fail("else_without_if");
-// }
+#[test]
+fn test_slot_fallback() {
+    common::parse_pass("slot_fallback");
+}
+
+#[test]
+fn test_else_without_if() {
+    common::parse_fail("else_without_if");
+}
+
+#[test]
+fn test_unclosed_tag() {
+    common::parse_fail("unclosed_tag");
+}
@@ -0,0 +1,22 @@
+use proc_macro2::Span;
+use reign_view::common::tokenize_view;
+use std::path::PathBuf;
+
+#[test]
+fn test_warnings() {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    path.push("tests");
+    path.push("warnings");
+    path.push("typo.html");
+
+    let (tokens, idents) = tokenize_view(&path, "typo", Span::call_site());
+    let tokens = tokens.to_string();
+
+    // Warnings are reported by the compiler as the use of a deprecated item
+    assert_eq!(idents.len(), 2);
+    assert!(tokens.contains("deprecated"));
+    assert!(tokens.contains(
+        "line 1, column 26: identifier `nmae` is used only once and looks like a typo of `name`"
+    ));
+}
@@ -45,6 +45,7 @@ fn parse_all_views(views: &Path) -> Result<Manifest, Error> {
 
             Ok(vec![])
         },
+        Span::call_site(),
     )?;
 
     Ok(manifest)
@@ -1,3 +1,16 @@
+//! Commonly used items that can be imported with `use reign::prelude::*`.
+//!
+//! Every item is only exported when the feature it belongs to is enabled, so the prelude can
+//! be imported irrespective of the features used.
+//!
+//! | Feature | Items |
+//! |---------|-------|
+//! | `router` | `Request`, `Response`, `Error`, `OptionExt`, `ResponseExt`, `redirect`, `render`, `#[params]`, `#[action]` |
+//! | `json` | `json` |
+//! | `view` | `views!`, `render!` |
+//! | `model-postgres` | `Identifiable`, `#[derive(Model)]` |
+//! | `framework` | `Config`, `#[derive(Config)]` |
+
 #[cfg(any(
     feature = "framework",
     feature = "view",
@@ -16,5 +29,5 @@ pub use reign_router::helpers::json;
 #[cfg(feature = "router")]
 pub use reign_router::{
     helpers::{redirect, render},
-    Error, OptionExt, Request, Response,
+    Error, OptionExt, Request, Response, ResponseExt,
 };